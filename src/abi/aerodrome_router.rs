@@ -0,0 +1,771 @@
+#![allow(clippy::all)]
+pub use aerodrome_router::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod aerodrome_router {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("defaultFactory"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("defaultFactory"),
+                        inputs: ::std::vec![],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("getAmountsOut"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("getAmountsOut"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("routes"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
+                                    ::std::boxed::Box::new(
+                                        ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                            ::ethers::core::abi::ethabi::ParamType::Bool,
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                        ],),
+                                    ),
+                                ),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("struct IRouter.Route[]"),
+                                ),
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("amounts"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
+                                ::std::boxed::Box::new(
+                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                ),
+                            ),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("removeLiquidity"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("removeLiquidity"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("tokenA"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("tokenB"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("stable"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Bool,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountAMin"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountBMin"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("to"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("deadline"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        outputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountA"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountB"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("swapExactETHForTokens"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("swapExactETHForTokens",),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountOutMin"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("routes"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
+                                    ::std::boxed::Box::new(
+                                        ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                            ::ethers::core::abi::ethabi::ParamType::Bool,
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                        ],),
+                                    ),
+                                ),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("struct IRouter.Route[]"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("to"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("deadline"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("amounts"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
+                                ::std::boxed::Box::new(
+                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                ),
+                            ),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Payable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("swapExactTokensForTokens"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("swapExactTokensForTokens",),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountOutMin"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("routes"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
+                                    ::std::boxed::Box::new(
+                                        ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                            ::ethers::core::abi::ethabi::ParamType::Bool,
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                        ],),
+                                    ),
+                                ),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("struct IRouter.Route[]"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("to"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("deadline"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("amounts"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
+                                ::std::boxed::Box::new(
+                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                ),
+                            ),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned(
+                        "swapExactTokensForTokensSupportingFeeOnTransferTokens",
+                    ),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned(
+                            "swapExactTokensForTokensSupportingFeeOnTransferTokens",
+                        ),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountIn"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amountOutMin"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("routes"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Array(
+                                    ::std::boxed::Box::new(
+                                        ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                            ::ethers::core::abi::ethabi::ParamType::Bool,
+                                            ::ethers::core::abi::ethabi::ParamType::Address,
+                                        ],),
+                                    ),
+                                ),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("struct IRouter.Route[]"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("to"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("deadline"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        outputs: ::std::vec![],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                    },],
+                ),
+            ]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static AERODROME_ROUTER_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    pub struct AERODROME_ROUTER<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for AERODROME_ROUTER<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for AERODROME_ROUTER<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for AERODROME_ROUTER<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for AERODROME_ROUTER<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(AERODROME_ROUTER))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> AERODROME_ROUTER<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                AERODROME_ROUTER_ABI.clone(),
+                client,
+            ))
+        }
+        ///Calls the contract's `defaultFactory` (0xd4b6846d) function
+        pub fn default_factory(
+            &self,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
+            self.0
+                .method_hash([212, 182, 132, 109], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `getAmountsOut` (0x5509a1ac) function
+        pub fn get_amounts_out(
+            &self,
+            amount_in: ::ethers::core::types::U256,
+            routes: ::std::vec::Vec<Route>,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::ethers::core::types::U256>,
+        > {
+            self.0
+                .method_hash([85, 9, 161, 172], (amount_in, routes))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `removeLiquidity` (0x0dede6c4) function
+        pub fn remove_liquidity(
+            &self,
+            token_a: ::ethers::core::types::Address,
+            token_b: ::ethers::core::types::Address,
+            stable: bool,
+            liquidity: ::ethers::core::types::U256,
+            amount_a_min: ::ethers::core::types::U256,
+            amount_b_min: ::ethers::core::types::U256,
+            to: ::ethers::core::types::Address,
+            deadline: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (::ethers::core::types::U256, ::ethers::core::types::U256),
+        > {
+            self.0
+                .method_hash(
+                    [13, 237, 230, 196],
+                    (
+                        token_a,
+                        token_b,
+                        stable,
+                        liquidity,
+                        amount_a_min,
+                        amount_b_min,
+                        to,
+                        deadline,
+                    ),
+                )
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `swapExactETHForTokens` (0x903638a4) function
+        pub fn swap_exact_eth_for_tokens(
+            &self,
+            amount_out_min: ::ethers::core::types::U256,
+            routes: ::std::vec::Vec<Route>,
+            to: ::ethers::core::types::Address,
+            deadline: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::ethers::core::types::U256>,
+        > {
+            self.0
+                .method_hash([144, 54, 56, 164], (amount_out_min, routes, to, deadline))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `swapExactTokensForTokens` (0xcac88ea9) function
+        pub fn swap_exact_tokens_for_tokens(
+            &self,
+            amount_in: ::ethers::core::types::U256,
+            amount_out_min: ::ethers::core::types::U256,
+            routes: ::std::vec::Vec<Route>,
+            to: ::ethers::core::types::Address,
+            deadline: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::ethers::core::types::U256>,
+        > {
+            self.0
+                .method_hash(
+                    [202, 200, 142, 169],
+                    (amount_in, amount_out_min, routes, to, deadline),
+                )
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `swapExactTokensForTokensSupportingFeeOnTransferTokens` (0x88cd821e) function
+        pub fn swap_exact_tokens_for_tokens_supporting_fee_on_transfer_tokens(
+            &self,
+            amount_in: ::ethers::core::types::U256,
+            amount_out_min: ::ethers::core::types::U256,
+            routes: ::std::vec::Vec<Route>,
+            to: ::ethers::core::types::Address,
+            deadline: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash(
+                    [136, 205, 130, 30],
+                    (amount_in, amount_out_min, routes, to, deadline),
+                )
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+        for AERODROME_ROUTER<M>
+    {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `defaultFactory` function with signature `defaultFactory()` and selector `0xd4b6846d`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "defaultFactory", abi = "defaultFactory()")]
+    pub struct DefaultFactoryCall;
+    ///Container type for all input parameters for the `getAmountsOut` function with signature `getAmountsOut(uint256,(address,address,bool,address)[])` and selector `0x5509a1ac`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "getAmountsOut",
+        abi = "getAmountsOut(uint256,(address,address,bool,address)[])"
+    )]
+    pub struct GetAmountsOutCall {
+        pub amount_in: ::ethers::core::types::U256,
+        pub routes: ::std::vec::Vec<Route>,
+    }
+    ///Container type for all input parameters for the `removeLiquidity` function with signature `removeLiquidity(address,address,bool,uint256,uint256,uint256,address,uint256)` and selector `0x0dede6c4`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "removeLiquidity",
+        abi = "removeLiquidity(address,address,bool,uint256,uint256,uint256,address,uint256)"
+    )]
+    pub struct RemoveLiquidityCall {
+        pub token_a: ::ethers::core::types::Address,
+        pub token_b: ::ethers::core::types::Address,
+        pub stable: bool,
+        pub liquidity: ::ethers::core::types::U256,
+        pub amount_a_min: ::ethers::core::types::U256,
+        pub amount_b_min: ::ethers::core::types::U256,
+        pub to: ::ethers::core::types::Address,
+        pub deadline: ::ethers::core::types::U256,
+    }
+    ///Container type for all input parameters for the `swapExactETHForTokens` function with signature `swapExactETHForTokens(uint256,(address,address,bool,address)[],address,uint256)` and selector `0x903638a4`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "swapExactETHForTokens",
+        abi = "swapExactETHForTokens(uint256,(address,address,bool,address)[],address,uint256)"
+    )]
+    pub struct SwapExactETHForTokensCall {
+        pub amount_out_min: ::ethers::core::types::U256,
+        pub routes: ::std::vec::Vec<Route>,
+        pub to: ::ethers::core::types::Address,
+        pub deadline: ::ethers::core::types::U256,
+    }
+    ///Container type for all input parameters for the `swapExactTokensForTokens` function with signature `swapExactTokensForTokens(uint256,uint256,(address,address,bool,address)[],address,uint256)` and selector `0xcac88ea9`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "swapExactTokensForTokens",
+        abi = "swapExactTokensForTokens(uint256,uint256,(address,address,bool,address)[],address,uint256)"
+    )]
+    pub struct SwapExactTokensForTokensCall {
+        pub amount_in: ::ethers::core::types::U256,
+        pub amount_out_min: ::ethers::core::types::U256,
+        pub routes: ::std::vec::Vec<Route>,
+        pub to: ::ethers::core::types::Address,
+        pub deadline: ::ethers::core::types::U256,
+    }
+    ///Container type for all input parameters for the `swapExactTokensForTokensSupportingFeeOnTransferTokens` function with signature `swapExactTokensForTokensSupportingFeeOnTransferTokens(uint256,uint256,(address,address,bool,address)[],address,uint256)` and selector `0x88cd821e`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "swapExactTokensForTokensSupportingFeeOnTransferTokens",
+        abi = "swapExactTokensForTokensSupportingFeeOnTransferTokens(uint256,uint256,(address,address,bool,address)[],address,uint256)"
+    )]
+    pub struct SwapExactTokensForTokensSupportingFeeOnTransferTokensCall {
+        pub amount_in: ::ethers::core::types::U256,
+        pub amount_out_min: ::ethers::core::types::U256,
+        pub routes: ::std::vec::Vec<Route>,
+        pub to: ::ethers::core::types::Address,
+        pub deadline: ::ethers::core::types::U256,
+    }
+    ///Container type for all of the contract's call
+    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
+    pub enum AERODROME_ROUTERCalls {
+        DefaultFactory(DefaultFactoryCall),
+        GetAmountsOut(GetAmountsOutCall),
+        RemoveLiquidity(RemoveLiquidityCall),
+        SwapExactETHForTokens(SwapExactETHForTokensCall),
+        SwapExactTokensForTokens(SwapExactTokensForTokensCall),
+        SwapExactTokensForTokensSupportingFeeOnTransferTokens(
+            SwapExactTokensForTokensSupportingFeeOnTransferTokensCall,
+        ),
+    }
+    impl ::ethers::core::abi::AbiDecode for AERODROME_ROUTERCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) =
+                <DefaultFactoryCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::DefaultFactory(decoded));
+            }
+            if let Ok(decoded) = <GetAmountsOutCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::GetAmountsOut(decoded));
+            }
+            if let Ok(decoded) =
+                <RemoveLiquidityCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::RemoveLiquidity(decoded));
+            }
+            if let Ok(decoded) =
+                <SwapExactETHForTokensCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::SwapExactETHForTokens(decoded));
+            }
+            if let Ok(decoded) =
+                <SwapExactTokensForTokensCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::SwapExactTokensForTokens(decoded));
+            }
+            if let Ok(decoded) = <SwapExactTokensForTokensSupportingFeeOnTransferTokensCall as ::ethers::core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(
+                    Self::SwapExactTokensForTokensSupportingFeeOnTransferTokens(decoded),
+                );
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for AERODROME_ROUTERCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::DefaultFactory(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::GetAmountsOut(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::RemoveLiquidity(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::SwapExactETHForTokens(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::SwapExactTokensForTokens(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::SwapExactTokensForTokensSupportingFeeOnTransferTokens(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+            }
+        }
+    }
+    impl ::core::fmt::Display for AERODROME_ROUTERCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::DefaultFactory(element) => ::core::fmt::Display::fmt(element, f),
+                Self::GetAmountsOut(element) => ::core::fmt::Display::fmt(element, f),
+                Self::RemoveLiquidity(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SwapExactETHForTokens(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SwapExactTokensForTokens(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SwapExactTokensForTokensSupportingFeeOnTransferTokens(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+            }
+        }
+    }
+    impl ::core::convert::From<DefaultFactoryCall> for AERODROME_ROUTERCalls {
+        fn from(value: DefaultFactoryCall) -> Self {
+            Self::DefaultFactory(value)
+        }
+    }
+    impl ::core::convert::From<GetAmountsOutCall> for AERODROME_ROUTERCalls {
+        fn from(value: GetAmountsOutCall) -> Self {
+            Self::GetAmountsOut(value)
+        }
+    }
+    impl ::core::convert::From<RemoveLiquidityCall> for AERODROME_ROUTERCalls {
+        fn from(value: RemoveLiquidityCall) -> Self {
+            Self::RemoveLiquidity(value)
+        }
+    }
+    impl ::core::convert::From<SwapExactETHForTokensCall> for AERODROME_ROUTERCalls {
+        fn from(value: SwapExactETHForTokensCall) -> Self {
+            Self::SwapExactETHForTokens(value)
+        }
+    }
+    impl ::core::convert::From<SwapExactTokensForTokensCall> for AERODROME_ROUTERCalls {
+        fn from(value: SwapExactTokensForTokensCall) -> Self {
+            Self::SwapExactTokensForTokens(value)
+        }
+    }
+    impl ::core::convert::From<SwapExactTokensForTokensSupportingFeeOnTransferTokensCall>
+        for AERODROME_ROUTERCalls
+    {
+        fn from(value: SwapExactTokensForTokensSupportingFeeOnTransferTokensCall) -> Self {
+            Self::SwapExactTokensForTokensSupportingFeeOnTransferTokens(value)
+        }
+    }
+    ///Container type for all return fields from the `defaultFactory` function with signature `defaultFactory()` and selector `0xd4b6846d`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct DefaultFactoryReturn(pub ::ethers::core::types::Address);
+    ///Container type for all return fields from the `getAmountsOut` function with signature `getAmountsOut(uint256,(address,address,bool,address)[])` and selector `0x5509a1ac`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct GetAmountsOutReturn {
+        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
+    }
+    ///Container type for all return fields from the `removeLiquidity` function with signature `removeLiquidity(address,address,bool,uint256,uint256,uint256,address,uint256)` and selector `0x0dede6c4`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct RemoveLiquidityReturn {
+        pub amount_a: ::ethers::core::types::U256,
+        pub amount_b: ::ethers::core::types::U256,
+    }
+    ///Container type for all return fields from the `swapExactETHForTokens` function with signature `swapExactETHForTokens(uint256,(address,address,bool,address)[],address,uint256)` and selector `0x903638a4`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct SwapExactETHForTokensReturn {
+        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
+    }
+    ///Container type for all return fields from the `swapExactTokensForTokens` function with signature `swapExactTokensForTokens(uint256,uint256,(address,address,bool,address)[],address,uint256)` and selector `0xcac88ea9`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct SwapExactTokensForTokensReturn {
+        pub amounts: ::std::vec::Vec<::ethers::core::types::U256>,
+    }
+    ///`Route(address,address,bool,address)`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct Route {
+        pub from: ::ethers::core::types::Address,
+        pub to: ::ethers::core::types::Address,
+        pub stable: bool,
+        pub factory: ::ethers::core::types::Address,
+    }
+}
@@ -0,0 +1,299 @@
+#![allow(clippy::all)]
+pub use pinklock::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod pinklock {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("getLocksForToken"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("getLocksForToken"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("token"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("start"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("end"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
+                                ::std::boxed::Box::new(
+                                    ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                                        ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                        ::ethers::core::abi::ethabi::ParamType::Address,
+                                        ::ethers::core::abi::ethabi::ParamType::Address,
+                                        ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                        ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                        ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                        ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                        ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                        ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                        ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                        ::ethers::core::abi::ethabi::ParamType::String,
+                                    ],),
+                                ),
+                            ),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("totalLockCountForToken"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("totalLockCountForToken",),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("token"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+            ]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static PINKLOCK_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    pub struct PINKLOCK<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for PINKLOCK<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for PINKLOCK<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for PINKLOCK<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for PINKLOCK<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(PINKLOCK))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> PINKLOCK<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                PINKLOCK_ABI.clone(),
+                client,
+            ))
+        }
+        ///Calls the contract's `getLocksForToken` (0x332f26d7) function
+        pub fn get_locks_for_token(
+            &self,
+            token: ::ethers::core::types::Address,
+            start: ::ethers::core::types::U256,
+            end: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<(
+                ::ethers::core::types::U256,
+                ::ethers::core::types::Address,
+                ::ethers::core::types::Address,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::std::string::String,
+            )>,
+        > {
+            self.0
+                .method_hash([51, 47, 38, 215], (token, start, end))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `totalLockCountForToken` (0xe3676f88) function
+        pub fn total_lock_count_for_token(
+            &self,
+            token: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([227, 103, 111, 136], token)
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>> for PINKLOCK<M> {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `getLocksForToken` function with signature `getLocksForToken(address,uint256,uint256)` and selector `0x332f26d7`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "getLocksForToken",
+        abi = "getLocksForToken(address,uint256,uint256)"
+    )]
+    pub struct GetLocksForTokenCall {
+        pub token: ::ethers::core::types::Address,
+        pub start: ::ethers::core::types::U256,
+        pub end: ::ethers::core::types::U256,
+    }
+    ///Container type for all input parameters for the `totalLockCountForToken` function with signature `totalLockCountForToken(address)` and selector `0xe3676f88`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "totalLockCountForToken",
+        abi = "totalLockCountForToken(address)"
+    )]
+    pub struct TotalLockCountForTokenCall {
+        pub token: ::ethers::core::types::Address,
+    }
+    ///Container type for all of the contract's call
+    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
+    pub enum PINKLOCKCalls {
+        GetLocksForToken(GetLocksForTokenCall),
+        TotalLockCountForToken(TotalLockCountForTokenCall),
+    }
+    impl ::ethers::core::abi::AbiDecode for PINKLOCKCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) =
+                <GetLocksForTokenCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::GetLocksForToken(decoded));
+            }
+            if let Ok(decoded) =
+                <TotalLockCountForTokenCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::TotalLockCountForToken(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for PINKLOCKCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::GetLocksForToken(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::TotalLockCountForToken(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+            }
+        }
+    }
+    impl ::core::fmt::Display for PINKLOCKCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::GetLocksForToken(element) => ::core::fmt::Display::fmt(element, f),
+                Self::TotalLockCountForToken(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<GetLocksForTokenCall> for PINKLOCKCalls {
+        fn from(value: GetLocksForTokenCall) -> Self {
+            Self::GetLocksForToken(value)
+        }
+    }
+    impl ::core::convert::From<TotalLockCountForTokenCall> for PINKLOCKCalls {
+        fn from(value: TotalLockCountForTokenCall) -> Self {
+            Self::TotalLockCountForToken(value)
+        }
+    }
+    ///Container type for all return fields from the `getLocksForToken` function with signature `getLocksForToken(address,uint256,uint256)` and selector `0x332f26d7`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct GetLocksForTokenReturn(
+        pub  ::std::vec::Vec<(
+            ::ethers::core::types::U256,
+            ::ethers::core::types::Address,
+            ::ethers::core::types::Address,
+            ::ethers::core::types::U256,
+            ::ethers::core::types::U256,
+            ::ethers::core::types::U256,
+            ::ethers::core::types::U256,
+            ::ethers::core::types::U256,
+            ::ethers::core::types::U256,
+            ::ethers::core::types::U256,
+            ::std::string::String,
+        )>,
+    );
+    ///Container type for all return fields from the `totalLockCountForToken` function with signature `totalLockCountForToken(address)` and selector `0xe3676f88`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct TotalLockCountForTokenReturn(pub ::ethers::core::types::U256);
+}
@@ -0,0 +1,187 @@
+#![allow(clippy::all)]
+pub use slipstream_quoter::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod slipstream_quoter {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([(
+                ::std::borrow::ToOwned::to_owned("quoteExactInputSingle"),
+                ::std::vec![::ethers::core::abi::ethabi::Function {
+                    name: ::std::borrow::ToOwned::to_owned("quoteExactInputSingle",),
+                    inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("params"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                            ::ethers::core::abi::ethabi::ParamType::Address,
+                            ::ethers::core::abi::ethabi::ParamType::Address,
+                            ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                            ::ethers::core::abi::ethabi::ParamType::Int(24usize),
+                            ::ethers::core::abi::ethabi::ParamType::Uint(160usize),
+                        ],),
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned(
+                                "struct IQuoterV2.QuoteExactInputSingleParams",
+                            ),
+                        ),
+                    },],
+                    outputs: ::std::vec![
+                        ::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("amountOut"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::None,
+                        },
+                        ::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("sqrtPriceX96After"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(160usize,),
+                            internal_type: ::core::option::Option::None,
+                        },
+                        ::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("initializedTicksCrossed",),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(32usize),
+                            internal_type: ::core::option::Option::None,
+                        },
+                        ::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("gasEstimate"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::None,
+                        },
+                    ],
+                    constant: ::core::option::Option::None,
+                    state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                },],
+            )]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static SLIPSTREAM_QUOTER_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    pub struct SLIPSTREAM_QUOTER<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for SLIPSTREAM_QUOTER<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for SLIPSTREAM_QUOTER<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for SLIPSTREAM_QUOTER<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for SLIPSTREAM_QUOTER<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(SLIPSTREAM_QUOTER))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> SLIPSTREAM_QUOTER<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                SLIPSTREAM_QUOTER_ABI.clone(),
+                client,
+            ))
+        }
+        ///Calls the contract's `quoteExactInputSingle` (0x9e7defe6) function
+        pub fn quote_exact_input_single(
+            &self,
+            params: QuoteExactInputSingleParams,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                u32,
+                ::ethers::core::types::U256,
+            ),
+        > {
+            self.0
+                .method_hash([158, 125, 239, 230], (params,))
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+        for SLIPSTREAM_QUOTER<M>
+    {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `quoteExactInputSingle` function with signature `quoteExactInputSingle((address,address,uint256,int24,uint160))` and selector `0x9e7defe6`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "quoteExactInputSingle",
+        abi = "quoteExactInputSingle((address,address,uint256,int24,uint160))"
+    )]
+    pub struct QuoteExactInputSingleCall {
+        pub params: QuoteExactInputSingleParams,
+    }
+    ///Container type for all return fields from the `quoteExactInputSingle` function with signature `quoteExactInputSingle((address,address,uint256,int24,uint160))` and selector `0x9e7defe6`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct QuoteExactInputSingleReturn {
+        pub amount_out: ::ethers::core::types::U256,
+        pub sqrt_price_x96_after: ::ethers::core::types::U256,
+        pub initialized_ticks_crossed: u32,
+        pub gas_estimate: ::ethers::core::types::U256,
+    }
+    ///`QuoteExactInputSingleParams(address,address,uint256,int24,uint160)`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct QuoteExactInputSingleParams {
+        pub token_in: ::ethers::core::types::Address,
+        pub token_out: ::ethers::core::types::Address,
+        pub amount_in: ::ethers::core::types::U256,
+        pub tick_spacing: i32,
+        pub sqrt_price_limit_x96: ::ethers::core::types::U256,
+    }
+}
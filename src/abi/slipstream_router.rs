@@ -0,0 +1,165 @@
+#![allow(clippy::all)]
+pub use slipstream_router::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod slipstream_router {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([(
+                ::std::borrow::ToOwned::to_owned("exactInputSingle"),
+                ::std::vec![::ethers::core::abi::ethabi::Function {
+                    name: ::std::borrow::ToOwned::to_owned("exactInputSingle"),
+                    inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("params"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                            ::ethers::core::abi::ethabi::ParamType::Address,
+                            ::ethers::core::abi::ethabi::ParamType::Address,
+                            ::ethers::core::abi::ethabi::ParamType::Int(24usize),
+                            ::ethers::core::abi::ethabi::ParamType::Address,
+                            ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                            ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                            ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                            ::ethers::core::abi::ethabi::ParamType::Uint(160usize),
+                        ],),
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned(
+                                "struct ISwapRouter.ExactInputSingleParams",
+                            ),
+                        ),
+                    },],
+                    outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("amountOut"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                        internal_type: ::core::option::Option::None,
+                    },],
+                    constant: ::core::option::Option::None,
+                    state_mutability: ::ethers::core::abi::ethabi::StateMutability::Payable,
+                },],
+            )]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static SLIPSTREAM_ROUTER_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    pub struct SLIPSTREAM_ROUTER<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for SLIPSTREAM_ROUTER<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for SLIPSTREAM_ROUTER<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for SLIPSTREAM_ROUTER<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for SLIPSTREAM_ROUTER<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(SLIPSTREAM_ROUTER))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> SLIPSTREAM_ROUTER<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                SLIPSTREAM_ROUTER_ABI.clone(),
+                client,
+            ))
+        }
+        ///Calls the contract's `exactInputSingle` (0xa026383e) function
+        pub fn exact_input_single(
+            &self,
+            params: ExactInputSingleParams,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([160, 38, 56, 62], (params,))
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+        for SLIPSTREAM_ROUTER<M>
+    {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `exactInputSingle` function with signature `exactInputSingle((address,address,int24,address,uint256,uint256,uint256,uint160))` and selector `0xa026383e`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "exactInputSingle",
+        abi = "exactInputSingle((address,address,int24,address,uint256,uint256,uint256,uint160))"
+    )]
+    pub struct ExactInputSingleCall {
+        pub params: ExactInputSingleParams,
+    }
+    ///Container type for all return fields from the `exactInputSingle` function with signature `exactInputSingle((address,address,int24,address,uint256,uint256,uint256,uint160))` and selector `0xa026383e`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct ExactInputSingleReturn {
+        pub amount_out: ::ethers::core::types::U256,
+    }
+    ///`ExactInputSingleParams(address,address,int24,address,uint256,uint256,uint256,uint160)`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct ExactInputSingleParams {
+        pub token_in: ::ethers::core::types::Address,
+        pub token_out: ::ethers::core::types::Address,
+        pub tick_spacing: i32,
+        pub recipient: ::ethers::core::types::Address,
+        pub deadline: ::ethers::core::types::U256,
+        pub amount_in: ::ethers::core::types::U256,
+        pub amount_out_minimum: ::ethers::core::types::U256,
+        pub sqrt_price_limit_x96: ::ethers::core::types::U256,
+    }
+}
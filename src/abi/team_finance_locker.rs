@@ -0,0 +1,277 @@
+#![allow(clippy::all)]
+pub use team_finance_locker::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod team_finance_locker {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("getDepositsByTokenAddress"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("getDepositsByTokenAddress",),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("_tokenAddress"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Array(
+                                ::std::boxed::Box::new(
+                                    ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                ),
+                            ),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("lockedToken"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("lockedToken"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        outputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("tokenAddress"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("withdrawalAddress"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("tokenAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("unlockTime"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("withdrawn"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Bool,
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+            ]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static TEAM_FINANCE_LOCKER_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    pub struct TEAM_FINANCE_LOCKER<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for TEAM_FINANCE_LOCKER<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for TEAM_FINANCE_LOCKER<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for TEAM_FINANCE_LOCKER<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for TEAM_FINANCE_LOCKER<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(TEAM_FINANCE_LOCKER))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> TEAM_FINANCE_LOCKER<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                TEAM_FINANCE_LOCKER_ABI.clone(),
+                client,
+            ))
+        }
+        ///Calls the contract's `getDepositsByTokenAddress` (0x86f65a22) function
+        pub fn get_deposits_by_token_address(
+            &self,
+            token_address: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::ethers::core::types::U256>,
+        > {
+            self.0
+                .method_hash([134, 246, 90, 34], token_address)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `lockedToken` (0xbb941cff) function
+        pub fn locked_token(
+            &self,
+            p0: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (
+                ::ethers::core::types::Address,
+                ::ethers::core::types::Address,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                bool,
+            ),
+        > {
+            self.0
+                .method_hash([187, 148, 28, 255], p0)
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+        for TEAM_FINANCE_LOCKER<M>
+    {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `getDepositsByTokenAddress` function with signature `getDepositsByTokenAddress(address)` and selector `0x86f65a22`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "getDepositsByTokenAddress",
+        abi = "getDepositsByTokenAddress(address)"
+    )]
+    pub struct GetDepositsByTokenAddressCall {
+        pub token_address: ::ethers::core::types::Address,
+    }
+    ///Container type for all input parameters for the `lockedToken` function with signature `lockedToken(uint256)` and selector `0xbb941cff`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "lockedToken", abi = "lockedToken(uint256)")]
+    pub struct LockedTokenCall(pub ::ethers::core::types::U256);
+    ///Container type for all of the contract's call
+    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
+    pub enum TEAM_FINANCE_LOCKERCalls {
+        GetDepositsByTokenAddress(GetDepositsByTokenAddressCall),
+        LockedToken(LockedTokenCall),
+    }
+    impl ::ethers::core::abi::AbiDecode for TEAM_FINANCE_LOCKERCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) =
+                <GetDepositsByTokenAddressCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::GetDepositsByTokenAddress(decoded));
+            }
+            if let Ok(decoded) = <LockedTokenCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::LockedToken(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for TEAM_FINANCE_LOCKERCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::GetDepositsByTokenAddress(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::LockedToken(element) => ::ethers::core::abi::AbiEncode::encode(element),
+            }
+        }
+    }
+    impl ::core::fmt::Display for TEAM_FINANCE_LOCKERCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::GetDepositsByTokenAddress(element) => ::core::fmt::Display::fmt(element, f),
+                Self::LockedToken(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<GetDepositsByTokenAddressCall> for TEAM_FINANCE_LOCKERCalls {
+        fn from(value: GetDepositsByTokenAddressCall) -> Self {
+            Self::GetDepositsByTokenAddress(value)
+        }
+    }
+    impl ::core::convert::From<LockedTokenCall> for TEAM_FINANCE_LOCKERCalls {
+        fn from(value: LockedTokenCall) -> Self {
+            Self::LockedToken(value)
+        }
+    }
+    ///Container type for all return fields from the `getDepositsByTokenAddress` function with signature `getDepositsByTokenAddress(address)` and selector `0x86f65a22`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct GetDepositsByTokenAddressReturn(pub ::std::vec::Vec<::ethers::core::types::U256>);
+    ///Container type for all return fields from the `lockedToken` function with signature `lockedToken(uint256)` and selector `0xbb941cff`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct LockedTokenReturn {
+        pub token_address: ::ethers::core::types::Address,
+        pub withdrawal_address: ::ethers::core::types::Address,
+        pub token_amount: ::ethers::core::types::U256,
+        pub unlock_time: ::ethers::core::types::U256,
+        pub withdrawn: bool,
+    }
+}
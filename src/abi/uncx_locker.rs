@@ -0,0 +1,283 @@
+#![allow(clippy::all)]
+pub use uncx_locker::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod uncx_locker {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("getNumLocksForToken"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("getNumLocksForToken",),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("_lpToken"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("tokenLocks"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("tokenLocks"),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::string::String::new(),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::string::String::new(),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        outputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("lockDate"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("initialAmount"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("unlockDate"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("lockID"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("owner"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+            ]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static UNCX_LOCKER_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    pub struct UNCX_LOCKER<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for UNCX_LOCKER<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for UNCX_LOCKER<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for UNCX_LOCKER<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for UNCX_LOCKER<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(UNCX_LOCKER))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> UNCX_LOCKER<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                UNCX_LOCKER_ABI.clone(),
+                client,
+            ))
+        }
+        ///Calls the contract's `getNumLocksForToken` (0x1f2a1d2f) function
+        pub fn get_num_locks_for_token(
+            &self,
+            lp_token: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([31, 42, 29, 47], lp_token)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `tokenLocks` (0xccebfa3f) function
+        pub fn token_locks(
+            &self,
+            p0: ::ethers::core::types::Address,
+            p1: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::Address,
+            ),
+        > {
+            self.0
+                .method_hash([204, 235, 250, 63], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>> for UNCX_LOCKER<M> {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `getNumLocksForToken` function with signature `getNumLocksForToken(address)` and selector `0x1f2a1d2f`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "getNumLocksForToken", abi = "getNumLocksForToken(address)")]
+    pub struct GetNumLocksForTokenCall {
+        pub lp_token: ::ethers::core::types::Address,
+    }
+    ///Container type for all input parameters for the `tokenLocks` function with signature `tokenLocks(address,uint256)` and selector `0xccebfa3f`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "tokenLocks", abi = "tokenLocks(address,uint256)")]
+    pub struct TokenLocksCall(
+        pub ::ethers::core::types::Address,
+        pub ::ethers::core::types::U256,
+    );
+    ///Container type for all of the contract's call
+    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
+    pub enum UNCX_LOCKERCalls {
+        GetNumLocksForToken(GetNumLocksForTokenCall),
+        TokenLocks(TokenLocksCall),
+    }
+    impl ::ethers::core::abi::AbiDecode for UNCX_LOCKERCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) =
+                <GetNumLocksForTokenCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::GetNumLocksForToken(decoded));
+            }
+            if let Ok(decoded) = <TokenLocksCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::TokenLocks(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for UNCX_LOCKERCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::GetNumLocksForToken(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+                Self::TokenLocks(element) => ::ethers::core::abi::AbiEncode::encode(element),
+            }
+        }
+    }
+    impl ::core::fmt::Display for UNCX_LOCKERCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::GetNumLocksForToken(element) => ::core::fmt::Display::fmt(element, f),
+                Self::TokenLocks(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<GetNumLocksForTokenCall> for UNCX_LOCKERCalls {
+        fn from(value: GetNumLocksForTokenCall) -> Self {
+            Self::GetNumLocksForToken(value)
+        }
+    }
+    impl ::core::convert::From<TokenLocksCall> for UNCX_LOCKERCalls {
+        fn from(value: TokenLocksCall) -> Self {
+            Self::TokenLocks(value)
+        }
+    }
+    ///Container type for all return fields from the `getNumLocksForToken` function with signature `getNumLocksForToken(address)` and selector `0x1f2a1d2f`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct GetNumLocksForTokenReturn(pub ::ethers::core::types::U256);
+    ///Container type for all return fields from the `tokenLocks` function with signature `tokenLocks(address,uint256)` and selector `0xccebfa3f`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct TokenLocksReturn {
+        pub lock_date: ::ethers::core::types::U256,
+        pub amount: ::ethers::core::types::U256,
+        pub initial_amount: ::ethers::core::types::U256,
+        pub unlock_date: ::ethers::core::types::U256,
+        pub lock_id: ::ethers::core::types::U256,
+        pub owner: ::ethers::core::types::Address,
+    }
+}
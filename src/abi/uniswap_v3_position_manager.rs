@@ -0,0 +1,675 @@
+#![allow(clippy::all)]
+pub use uniswap_v3_position_manager::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod uniswap_v3_position_manager {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("balanceOf"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("balanceOf"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("owner"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("collect"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("collect"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("params"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                                ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                ::ethers::core::abi::ethabi::ParamType::Address,
+                                ::ethers::core::abi::ethabi::ParamType::Uint(128usize),
+                                ::ethers::core::abi::ethabi::ParamType::Uint(128usize),
+                            ],),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned(
+                                    "struct INonfungiblePositionManager.CollectParams",
+                                ),
+                            ),
+                        },],
+                        outputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amount0"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amount1"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Payable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("decreaseLiquidity"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("decreaseLiquidity"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("params"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                                ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                ::ethers::core::abi::ethabi::ParamType::Uint(128usize),
+                                ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                                ::ethers::core::abi::ethabi::ParamType::Uint(256usize),
+                            ],),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned(
+                                    "struct INonfungiblePositionManager.DecreaseLiquidityParams",
+                                ),
+                            ),
+                        },],
+                        outputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amount0"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("amount1"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::Payable,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("ownerOf"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("ownerOf"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("tokenId"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("positions"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("positions"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("tokenId"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        outputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("nonce"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(96usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("operator"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("token0"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("token1"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("fee"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(24usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("tickLower"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Int(24usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("tickUpper"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Int(24usize),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("liquidity"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(128usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("feeGrowthInside0LastX128",),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("feeGrowthInside1LastX128",),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("tokensOwed0"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(128usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("tokensOwed1"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(128usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("tokenOfOwnerByIndex"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("tokenOfOwnerByIndex",),
+                        inputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("owner"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Address,
+                                internal_type: ::core::option::Option::None,
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("index"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                                internal_type: ::core::option::Option::None,
+                            },
+                        ],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::string::String::new(),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::None,
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+            ]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static UNISWAP_V3_POSITION_MANAGER_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    pub struct UNISWAP_V3_POSITION_MANAGER<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for UNISWAP_V3_POSITION_MANAGER<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for UNISWAP_V3_POSITION_MANAGER<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for UNISWAP_V3_POSITION_MANAGER<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for UNISWAP_V3_POSITION_MANAGER<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(UNISWAP_V3_POSITION_MANAGER))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> UNISWAP_V3_POSITION_MANAGER<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                UNISWAP_V3_POSITION_MANAGER_ABI.clone(),
+                client,
+            ))
+        }
+        ///Calls the contract's `balanceOf` (0x70a08231) function
+        pub fn balance_of(
+            &self,
+            owner: ::ethers::core::types::Address,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([112, 160, 130, 49], owner)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `collect` (0xfc6f7865) function
+        pub fn collect(
+            &self,
+            params: CollectParams,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (::ethers::core::types::U256, ::ethers::core::types::U256),
+        > {
+            self.0
+                .method_hash([252, 111, 120, 101], (params,))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `decreaseLiquidity` (0x0c49ccbe) function
+        pub fn decrease_liquidity(
+            &self,
+            params: DecreaseLiquidityParams,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (::ethers::core::types::U256, ::ethers::core::types::U256),
+        > {
+            self.0
+                .method_hash([12, 73, 204, 190], (params,))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `ownerOf` (0x6352211e) function
+        pub fn owner_of(
+            &self,
+            token_id: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::Address> {
+            self.0
+                .method_hash([99, 82, 33, 30], token_id)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `positions` (0x99fbab88) function
+        pub fn positions(
+            &self,
+            token_id: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (
+                u128,
+                ::ethers::core::types::Address,
+                ::ethers::core::types::Address,
+                ::ethers::core::types::Address,
+                u32,
+                i32,
+                i32,
+                u128,
+                ::ethers::core::types::U256,
+                ::ethers::core::types::U256,
+                u128,
+                u128,
+            ),
+        > {
+            self.0
+                .method_hash([153, 251, 171, 136], token_id)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `tokenOfOwnerByIndex` (0x2f745c59) function
+        pub fn token_of_owner_by_index(
+            &self,
+            owner: ::ethers::core::types::Address,
+            index: ::ethers::core::types::U256,
+        ) -> ::ethers::contract::builders::ContractCall<M, ::ethers::core::types::U256> {
+            self.0
+                .method_hash([47, 116, 92, 89], (owner, index))
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+        for UNISWAP_V3_POSITION_MANAGER<M>
+    {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `balanceOf` function with signature `balanceOf(address)` and selector `0x70a08231`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "balanceOf", abi = "balanceOf(address)")]
+    pub struct BalanceOfCall {
+        pub owner: ::ethers::core::types::Address,
+    }
+    ///Container type for all input parameters for the `collect` function with signature `collect((uint256,address,uint128,uint128))` and selector `0xfc6f7865`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "collect", abi = "collect((uint256,address,uint128,uint128))")]
+    pub struct CollectCall {
+        pub params: CollectParams,
+    }
+    ///Container type for all input parameters for the `decreaseLiquidity` function with signature `decreaseLiquidity((uint256,uint128,uint256,uint256,uint256))` and selector `0x0c49ccbe`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "decreaseLiquidity",
+        abi = "decreaseLiquidity((uint256,uint128,uint256,uint256,uint256))"
+    )]
+    pub struct DecreaseLiquidityCall {
+        pub params: DecreaseLiquidityParams,
+    }
+    ///Container type for all input parameters for the `ownerOf` function with signature `ownerOf(uint256)` and selector `0x6352211e`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "ownerOf", abi = "ownerOf(uint256)")]
+    pub struct OwnerOfCall {
+        pub token_id: ::ethers::core::types::U256,
+    }
+    ///Container type for all input parameters for the `positions` function with signature `positions(uint256)` and selector `0x99fbab88`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "positions", abi = "positions(uint256)")]
+    pub struct PositionsCall {
+        pub token_id: ::ethers::core::types::U256,
+    }
+    ///Container type for all input parameters for the `tokenOfOwnerByIndex` function with signature `tokenOfOwnerByIndex(address,uint256)` and selector `0x2f745c59`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "tokenOfOwnerByIndex",
+        abi = "tokenOfOwnerByIndex(address,uint256)"
+    )]
+    pub struct TokenOfOwnerByIndexCall {
+        pub owner: ::ethers::core::types::Address,
+        pub index: ::ethers::core::types::U256,
+    }
+    ///Container type for all of the contract's call
+    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
+    pub enum UNISWAP_V3_POSITION_MANAGERCalls {
+        BalanceOf(BalanceOfCall),
+        Collect(CollectCall),
+        DecreaseLiquidity(DecreaseLiquidityCall),
+        OwnerOf(OwnerOfCall),
+        Positions(PositionsCall),
+        TokenOfOwnerByIndex(TokenOfOwnerByIndexCall),
+    }
+    impl ::ethers::core::abi::AbiDecode for UNISWAP_V3_POSITION_MANAGERCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) = <BalanceOfCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::BalanceOf(decoded));
+            }
+            if let Ok(decoded) = <CollectCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Collect(decoded));
+            }
+            if let Ok(decoded) =
+                <DecreaseLiquidityCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::DecreaseLiquidity(decoded));
+            }
+            if let Ok(decoded) = <OwnerOfCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::OwnerOf(decoded));
+            }
+            if let Ok(decoded) = <PositionsCall as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::Positions(decoded));
+            }
+            if let Ok(decoded) =
+                <TokenOfOwnerByIndexCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::TokenOfOwnerByIndex(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for UNISWAP_V3_POSITION_MANAGERCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::BalanceOf(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Collect(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::DecreaseLiquidity(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::OwnerOf(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::Positions(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::TokenOfOwnerByIndex(element) => {
+                    ::ethers::core::abi::AbiEncode::encode(element)
+                }
+            }
+        }
+    }
+    impl ::core::fmt::Display for UNISWAP_V3_POSITION_MANAGERCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::BalanceOf(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Collect(element) => ::core::fmt::Display::fmt(element, f),
+                Self::DecreaseLiquidity(element) => ::core::fmt::Display::fmt(element, f),
+                Self::OwnerOf(element) => ::core::fmt::Display::fmt(element, f),
+                Self::Positions(element) => ::core::fmt::Display::fmt(element, f),
+                Self::TokenOfOwnerByIndex(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<BalanceOfCall> for UNISWAP_V3_POSITION_MANAGERCalls {
+        fn from(value: BalanceOfCall) -> Self {
+            Self::BalanceOf(value)
+        }
+    }
+    impl ::core::convert::From<CollectCall> for UNISWAP_V3_POSITION_MANAGERCalls {
+        fn from(value: CollectCall) -> Self {
+            Self::Collect(value)
+        }
+    }
+    impl ::core::convert::From<DecreaseLiquidityCall> for UNISWAP_V3_POSITION_MANAGERCalls {
+        fn from(value: DecreaseLiquidityCall) -> Self {
+            Self::DecreaseLiquidity(value)
+        }
+    }
+    impl ::core::convert::From<OwnerOfCall> for UNISWAP_V3_POSITION_MANAGERCalls {
+        fn from(value: OwnerOfCall) -> Self {
+            Self::OwnerOf(value)
+        }
+    }
+    impl ::core::convert::From<PositionsCall> for UNISWAP_V3_POSITION_MANAGERCalls {
+        fn from(value: PositionsCall) -> Self {
+            Self::Positions(value)
+        }
+    }
+    impl ::core::convert::From<TokenOfOwnerByIndexCall> for UNISWAP_V3_POSITION_MANAGERCalls {
+        fn from(value: TokenOfOwnerByIndexCall) -> Self {
+            Self::TokenOfOwnerByIndex(value)
+        }
+    }
+    ///Container type for all return fields from the `balanceOf` function with signature `balanceOf(address)` and selector `0x70a08231`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct BalanceOfReturn(pub ::ethers::core::types::U256);
+    ///Container type for all return fields from the `collect` function with signature `collect((uint256,address,uint128,uint128))` and selector `0xfc6f7865`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct CollectReturn {
+        pub amount_0: ::ethers::core::types::U256,
+        pub amount_1: ::ethers::core::types::U256,
+    }
+    ///Container type for all return fields from the `decreaseLiquidity` function with signature `decreaseLiquidity((uint256,uint128,uint256,uint256,uint256))` and selector `0x0c49ccbe`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct DecreaseLiquidityReturn {
+        pub amount_0: ::ethers::core::types::U256,
+        pub amount_1: ::ethers::core::types::U256,
+    }
+    ///Container type for all return fields from the `ownerOf` function with signature `ownerOf(uint256)` and selector `0x6352211e`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct OwnerOfReturn(pub ::ethers::core::types::Address);
+    ///Container type for all return fields from the `positions` function with signature `positions(uint256)` and selector `0x99fbab88`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct PositionsReturn {
+        pub nonce: u128,
+        pub operator: ::ethers::core::types::Address,
+        pub token_0: ::ethers::core::types::Address,
+        pub token_1: ::ethers::core::types::Address,
+        pub fee: u32,
+        pub tick_lower: i32,
+        pub tick_upper: i32,
+        pub liquidity: u128,
+        pub fee_growth_inside_0_last_x128: ::ethers::core::types::U256,
+        pub fee_growth_inside_1_last_x128: ::ethers::core::types::U256,
+        pub tokens_owed_0: u128,
+        pub tokens_owed_1: u128,
+    }
+    ///Container type for all return fields from the `tokenOfOwnerByIndex` function with signature `tokenOfOwnerByIndex(address,uint256)` and selector `0x2f745c59`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct TokenOfOwnerByIndexReturn(pub ::ethers::core::types::U256);
+    ///`CollectParams(uint256,address,uint128,uint128)`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct CollectParams {
+        pub token_id: ::ethers::core::types::U256,
+        pub recipient: ::ethers::core::types::Address,
+        pub amount_0_max: u128,
+        pub amount_1_max: u128,
+    }
+    ///`DecreaseLiquidityParams(uint256,uint128,uint256,uint256,uint256)`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct DecreaseLiquidityParams {
+        pub token_id: ::ethers::core::types::U256,
+        pub liquidity: u128,
+        pub amount_0_min: ::ethers::core::types::U256,
+        pub amount_1_min: ::ethers::core::types::U256,
+        pub deadline: ::ethers::core::types::U256,
+    }
+}
@@ -0,0 +1,196 @@
+#![allow(clippy::all)]
+pub use uniswap_v4_quoter::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod uniswap_v4_quoter {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([(
+                ::std::borrow::ToOwned::to_owned("quoteExactInputSingle"),
+                ::std::vec![::ethers::core::abi::ethabi::Function {
+                    name: ::std::borrow::ToOwned::to_owned("quoteExactInputSingle",),
+                    inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                        name: ::std::borrow::ToOwned::to_owned("params"),
+                        kind: ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                            ::ethers::core::abi::ethabi::ParamType::Tuple(::std::vec![
+                                ::ethers::core::abi::ethabi::ParamType::Address,
+                                ::ethers::core::abi::ethabi::ParamType::Address,
+                                ::ethers::core::abi::ethabi::ParamType::Uint(24usize),
+                                ::ethers::core::abi::ethabi::ParamType::Int(24usize),
+                                ::ethers::core::abi::ethabi::ParamType::Address,
+                            ],),
+                            ::ethers::core::abi::ethabi::ParamType::Bool,
+                            ::ethers::core::abi::ethabi::ParamType::Uint(128usize),
+                            ::ethers::core::abi::ethabi::ParamType::Bytes,
+                        ],),
+                        internal_type: ::core::option::Option::Some(
+                            ::std::borrow::ToOwned::to_owned(
+                                "struct IV4Quoter.QuoteExactSingleParams",
+                            ),
+                        ),
+                    },],
+                    outputs: ::std::vec![
+                        ::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("amountOut"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },
+                        ::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("gasEstimate"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(256usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint256"),
+                            ),
+                        },
+                    ],
+                    constant: ::core::option::Option::None,
+                    state_mutability: ::ethers::core::abi::ethabi::StateMutability::NonPayable,
+                },],
+            )]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static UNISWAP_V4_QUOTER_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    pub struct UNISWAP_V4_QUOTER<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for UNISWAP_V4_QUOTER<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for UNISWAP_V4_QUOTER<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for UNISWAP_V4_QUOTER<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for UNISWAP_V4_QUOTER<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(UNISWAP_V4_QUOTER))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> UNISWAP_V4_QUOTER<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                UNISWAP_V4_QUOTER_ABI.clone(),
+                client,
+            ))
+        }
+        ///Calls the contract's `quoteExactInputSingle` (0xaa9d21cb) function
+        pub fn quote_exact_input_single(
+            &self,
+            params: QuoteExactSingleParams,
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (::ethers::core::types::U256, ::ethers::core::types::U256),
+        > {
+            self.0
+                .method_hash([170, 157, 33, 203], (params,))
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+        for UNISWAP_V4_QUOTER<M>
+    {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `quoteExactInputSingle` function with signature `quoteExactInputSingle(((address,address,uint24,int24,address),bool,uint128,bytes))` and selector `0xaa9d21cb`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(
+        name = "quoteExactInputSingle",
+        abi = "quoteExactInputSingle(((address,address,uint24,int24,address),bool,uint128,bytes))"
+    )]
+    pub struct QuoteExactInputSingleCall {
+        pub params: QuoteExactSingleParams,
+    }
+    ///Container type for all return fields from the `quoteExactInputSingle` function with signature `quoteExactInputSingle(((address,address,uint24,int24,address),bool,uint128,bytes))` and selector `0xaa9d21cb`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct QuoteExactInputSingleReturn {
+        pub amount_out: ::ethers::core::types::U256,
+        pub gas_estimate: ::ethers::core::types::U256,
+    }
+    ///`QuoteExactSingleParams((address,address,uint24,int24,address),bool,uint128,bytes)`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct QuoteExactSingleParams {
+        pub pool_key: PoolKey,
+        pub zero_for_one: bool,
+        pub exact_amount: u128,
+        pub hook_data: ::ethers::core::types::Bytes,
+    }
+    ///`PoolKey(address,address,uint24,int24,address)`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct PoolKey {
+        pub currency_0: ::ethers::core::types::Address,
+        pub currency_1: ::ethers::core::types::Address,
+        pub fee: u32,
+        pub tick_spacing: i32,
+        pub hooks: ::ethers::core::types::Address,
+    }
+}
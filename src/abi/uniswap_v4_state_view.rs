@@ -0,0 +1,270 @@
+#![allow(clippy::all)]
+pub use uniswap_v4_state_view::*;
+/// This module was auto-generated with ethers-rs Abigen.
+/// More information at: <https://github.com/gakonst/ethers-rs>
+#[allow(
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms,
+    clippy::type_complexity,
+    dead_code,
+    non_camel_case_types
+)]
+pub mod uniswap_v4_state_view {
+    #[allow(deprecated)]
+    fn __abi() -> ::ethers::core::abi::Abi {
+        ::ethers::core::abi::ethabi::Contract {
+            constructor: ::core::option::Option::None,
+            functions: ::core::convert::From::from([
+                (
+                    ::std::borrow::ToOwned::to_owned("getLiquidity"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("getLiquidity"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("poolId"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("PoolId"),
+                            ),
+                        },],
+                        outputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("liquidity"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::Uint(128usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("uint128"),
+                            ),
+                        },],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+                (
+                    ::std::borrow::ToOwned::to_owned("getSlot0"),
+                    ::std::vec![::ethers::core::abi::ethabi::Function {
+                        name: ::std::borrow::ToOwned::to_owned("getSlot0"),
+                        inputs: ::std::vec![::ethers::core::abi::ethabi::Param {
+                            name: ::std::borrow::ToOwned::to_owned("poolId"),
+                            kind: ::ethers::core::abi::ethabi::ParamType::FixedBytes(32usize,),
+                            internal_type: ::core::option::Option::Some(
+                                ::std::borrow::ToOwned::to_owned("PoolId"),
+                            ),
+                        },],
+                        outputs: ::std::vec![
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("sqrtPriceX96"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(160usize,),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint160"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("tick"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Int(24usize),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("int24"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("protocolFee"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(24usize),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint24"),
+                                ),
+                            },
+                            ::ethers::core::abi::ethabi::Param {
+                                name: ::std::borrow::ToOwned::to_owned("lpFee"),
+                                kind: ::ethers::core::abi::ethabi::ParamType::Uint(24usize),
+                                internal_type: ::core::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned("uint24"),
+                                ),
+                            },
+                        ],
+                        constant: ::core::option::Option::None,
+                        state_mutability: ::ethers::core::abi::ethabi::StateMutability::View,
+                    },],
+                ),
+            ]),
+            events: ::std::collections::BTreeMap::new(),
+            errors: ::std::collections::BTreeMap::new(),
+            receive: false,
+            fallback: false,
+        }
+    }
+    ///The parsed JSON ABI of the contract.
+    pub static UNISWAP_V4_STATE_VIEW_ABI: ::ethers::contract::Lazy<::ethers::core::abi::Abi> =
+        ::ethers::contract::Lazy::new(__abi);
+    pub struct UNISWAP_V4_STATE_VIEW<M>(::ethers::contract::Contract<M>);
+    impl<M> ::core::clone::Clone for UNISWAP_V4_STATE_VIEW<M> {
+        fn clone(&self) -> Self {
+            Self(::core::clone::Clone::clone(&self.0))
+        }
+    }
+    impl<M> ::core::ops::Deref for UNISWAP_V4_STATE_VIEW<M> {
+        type Target = ::ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M> ::core::ops::DerefMut for UNISWAP_V4_STATE_VIEW<M> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+    impl<M> ::core::fmt::Debug for UNISWAP_V4_STATE_VIEW<M> {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_tuple(::core::stringify!(UNISWAP_V4_STATE_VIEW))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> UNISWAP_V4_STATE_VIEW<M> {
+        /// Creates a new contract instance with the specified `ethers` client at
+        /// `address`. The contract derefs to a `ethers::Contract` object.
+        pub fn new<T: Into<::ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            Self(::ethers::contract::Contract::new(
+                address.into(),
+                UNISWAP_V4_STATE_VIEW_ABI.clone(),
+                client,
+            ))
+        }
+        ///Calls the contract's `getLiquidity` (0xfa6793d5) function
+        pub fn get_liquidity(
+            &self,
+            pool_id: [u8; 32],
+        ) -> ::ethers::contract::builders::ContractCall<M, u128> {
+            self.0
+                .method_hash([250, 103, 147, 213], pool_id)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `getSlot0` (0xc815641c) function
+        pub fn get_slot_0(
+            &self,
+            pool_id: [u8; 32],
+        ) -> ::ethers::contract::builders::ContractCall<
+            M,
+            (::ethers::core::types::U256, i32, u32, u32),
+        > {
+            self.0
+                .method_hash([200, 21, 100, 28], pool_id)
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::ethers::providers::Middleware> From<::ethers::contract::Contract<M>>
+        for UNISWAP_V4_STATE_VIEW<M>
+    {
+        fn from(contract: ::ethers::contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `getLiquidity` function with signature `getLiquidity(bytes32)` and selector `0xfa6793d5`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "getLiquidity", abi = "getLiquidity(bytes32)")]
+    pub struct GetLiquidityCall {
+        pub pool_id: [u8; 32],
+    }
+    ///Container type for all input parameters for the `getSlot0` function with signature `getSlot0(bytes32)` and selector `0xc815641c`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthCall,
+        ::ethers::contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    #[ethcall(name = "getSlot0", abi = "getSlot0(bytes32)")]
+    pub struct GetSlot0Call {
+        pub pool_id: [u8; 32],
+    }
+    ///Container type for all of the contract's call
+    #[derive(Clone, ::ethers::contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
+    pub enum UNISWAP_V4_STATE_VIEWCalls {
+        GetLiquidity(GetLiquidityCall),
+        GetSlot0(GetSlot0Call),
+    }
+    impl ::ethers::core::abi::AbiDecode for UNISWAP_V4_STATE_VIEWCalls {
+        fn decode(
+            data: impl AsRef<[u8]>,
+        ) -> ::core::result::Result<Self, ::ethers::core::abi::AbiError> {
+            let data = data.as_ref();
+            if let Ok(decoded) = <GetLiquidityCall as ::ethers::core::abi::AbiDecode>::decode(data)
+            {
+                return Ok(Self::GetLiquidity(decoded));
+            }
+            if let Ok(decoded) = <GetSlot0Call as ::ethers::core::abi::AbiDecode>::decode(data) {
+                return Ok(Self::GetSlot0(decoded));
+            }
+            Err(::ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ::ethers::core::abi::AbiEncode for UNISWAP_V4_STATE_VIEWCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Self::GetLiquidity(element) => ::ethers::core::abi::AbiEncode::encode(element),
+                Self::GetSlot0(element) => ::ethers::core::abi::AbiEncode::encode(element),
+            }
+        }
+    }
+    impl ::core::fmt::Display for UNISWAP_V4_STATE_VIEWCalls {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            match self {
+                Self::GetLiquidity(element) => ::core::fmt::Display::fmt(element, f),
+                Self::GetSlot0(element) => ::core::fmt::Display::fmt(element, f),
+            }
+        }
+    }
+    impl ::core::convert::From<GetLiquidityCall> for UNISWAP_V4_STATE_VIEWCalls {
+        fn from(value: GetLiquidityCall) -> Self {
+            Self::GetLiquidity(value)
+        }
+    }
+    impl ::core::convert::From<GetSlot0Call> for UNISWAP_V4_STATE_VIEWCalls {
+        fn from(value: GetSlot0Call) -> Self {
+            Self::GetSlot0(value)
+        }
+    }
+    ///Container type for all return fields from the `getLiquidity` function with signature `getLiquidity(bytes32)` and selector `0xfa6793d5`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct GetLiquidityReturn {
+        pub liquidity: u128,
+    }
+    ///Container type for all return fields from the `getSlot0` function with signature `getSlot0(bytes32)` and selector `0xc815641c`
+    #[derive(
+        Clone,
+        ::ethers::contract::EthAbiType,
+        ::ethers::contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+    )]
+    pub struct GetSlot0Return {
+        pub sqrt_price_x96: ::ethers::core::types::U256,
+        pub tick: i32,
+        pub protocol_fee: u32,
+        pub lp_fee: u32,
+    }
+}
@@ -13,8 +13,14 @@ use ethers::types::Chain;
 /// Chains this app supports
 pub const CHAINS: [Chain; 2] = [Chain::Mainnet, Chain::Base];
 
-/// dexes supported by app
-pub const DEXES: [Dex; 2] = [Dex::UniswapV2, Dex::UniswapV3];
+/// dexes supported by app, each must have a swap venue (see `dex::swap_venue`)
+pub const DEXES: [Dex; 5] = [
+    Dex::UniswapV2,
+    Dex::UniswapV3,
+    Dex::UniswapV4,
+    Dex::Aerodrome,
+    Dex::AerodromeSlipstream,
+];
 
 /// Specifies the blockchain chain where the application operates.
 /// Adjust this value to target a different chain (e.g., Mainnet, Testnet).
@@ -44,25 +50,103 @@ pub const LIQUIDITY_PERCENTAGE_LOCKED: f64 = 90.0;
 /// Threshold percentage for token holders, used to detect concentrated ownership.
 pub const TOKEN_HOLDER_THRESHOLD_PERCENTAGE: f64 = 10.0;
 
-/// List of addresses considered as token lockers on the mainnet.
-///
-/// These addresses often represent team finances, burn addresses, or mechanisms to lock tokens.
-pub const TOKEN_LOCKERS_MAINNET: [&str; 6] = [
-    "0xe2fe530c047f2d85298b07d9333c05737f1435fb", // team finance (lowercased)
-    "0x663a5c229c09b049e36dcc11a9b0d4a8eb9db214", // UNCX (lowercased)
-    "0xadb2437e6f65682b85f814fbc12fec0508a7b1d0", // Holds UNCX tokens, governance role
-    "0x7baf06a99f4934b61a1d129af587b9e9746c77f5", // Holds SWAP tokens, potential locker
-    "0x000000000000000000000000000000000000dead", // token burn (lowercased)
-    "0x0000000000000000000000000000000000000000", // token burn
+/// Maximum percentage of the total supply the 10 largest circulating holders together should hold.
+pub const TOP_10_HOLDERS_THRESHOLD_PERCENTAGE: f64 = 50.0;
+
+/// Number of largest holders the holder distribution is computed from.
+pub const HOLDER_DISTRIBUTION_TOP_HOLDERS: usize = 100;
+
+/// Maximum buy, sell or transfer tax (expressed as a percentage) a legit token is expected to charge.
+pub const TOKEN_TAX_THRESHOLD_PERCENTAGE: f64 = 10.0;
+
+/// Buy, sell or transfer tax (expressed as a percentage) at which a token is effectively a honeypot.
+pub const HONEYPOT_TAX_PERCENTAGE: f64 = 50.0;
+
+/// Points in time (label, seconds after purchase) at which the simulation re-checks the token's balance
+/// and sellability, to detect tokens that turn into honeypots after a delay or cooldown.
+pub const TIME_BOMB_HORIZONS: [(&str, u64); 7] = [
+    ("immediately", 0),
+    ("5 minutes", 300),
+    ("1 hour", 3_600),
+    ("6 hours", 21_600),
+    ("1 day", 86_400),
+    ("3 days", 259_200),
+    ("7 days", 604_800),
 ];
 
-/// List of addresses considered as base token lockers.
-///
-/// These addresses are typically used for non-trading purposes such as token burns or long-term holds.
-pub const TOKEN_LOCKERS_BASE: [&str; 3] = [
-    "0xc4e637d37113192f4f1f060daebd7758de7f4131", // UNCX (lowercased)
-    "0x000000000000000000000000000000000000dead", // token burn (lowercased)
-    "0x0000000000000000000000000000000000000000", // token burn
+/// Number of binary search steps used when probing a token's max buy, sell and transfer sizes.
+pub const TRADE_LIMIT_PROBE_STEPS: u32 = 8;
+
+/// The largest buy probed, as a multiple of the ETH spent on the test purchase.
+pub const MAX_BUY_PROBE_MULTIPLIER: u64 = 100;
+
+/// A sell limit below this fraction of the largest buy means holders can buy far more than they can sell.
+pub const SELL_TO_BUY_LIMIT_RATIO_THRESHOLD: f64 = 0.1;
+
+/// Seconds after a sell at which a second sell is retried, when probing for a sell cooldown.
+pub const COOLDOWN_PROBE_SECONDS: [u64; 6] = [15, 60, 300, 900, 3_600, 86_400];
+
+/// Default size (in USD) of the simulated purchase made when auditing a token.
+pub const SIMULATION_TRADE_SIZE_USD: f64 = 100.0;
+
+/// Maximum number of forked anvil instances running simulations at the same time, per chain.
+pub const ANVIL_POOL_MAX_INSTANCES_PER_CHAIN: usize = 4;
+
+/// Age (in seconds) after which a pooled anvil fork is reset to the latest block before being reused.
+pub const ANVIL_FORK_MAX_AGE_SECONDS: u64 = 300;
+
+/// Fees the owner's fee setters are called with when simulating owner privileges, tried in order until
+/// one is accepted. Contracts express fees as percentages, basis points or other precisions.
+pub const PRIVILEGED_FEE_PROBE_VALUES: [u64; 6] = [10_000, 1_000, 100, 99, 50, 25];
+
+/// Trade sizes (in USD) quoted to measure the price impact of buying and selling the token.
+pub const DEPTH_LADDER_TRADE_SIZES_USD: [f64; 7] = [
+    100.0, 500.0, 1_000.0, 5_000.0, 10_000.0, 50_000.0, 100_000.0,
+];
+
+/// Size (in USD) of the reference trade the price impact of the ladder's trades is measured against.
+pub const DEPTH_REFERENCE_TRADE_SIZE_USD: f64 = 1.0;
+
+/// Price impact (expressed as a percentage) below which a sell is considered a realistic exit.
+pub const DEPTH_MAX_EXIT_PRICE_IMPACT_PERCENTAGE: f64 = 10.0;
+
+/// Maximum number of unlocked LP holders impersonated to pull their liquidity when simulating a rug pull.
+pub const RUG_PULL_MAX_LP_HOLDERS: usize = 10;
+
+/// Seconds a Chainlink round may be older than its feed's heartbeat before its price is considered
+/// stale, allowing for the delay of the heartbeat update itself.
+pub const CHAINLINK_STALENESS_GRACE_SECONDS: u64 = 600;
+
+/// Horizons (in days) the share of LP tokens still locked is reported for: a lock expiring tomorrow
+/// protects holders far less than a two-year lock.
+pub const LOCK_EXPIRY_HORIZONS_DAYS: [f64; 4] = [30.0, 90.0, 180.0, 365.0];
+
+/// Blocks covered by a single `eth_getLogs` query: log scans are paged in windows of this size, as
+/// providers cap the block range and number of results of a query.
+pub const LOG_QUERY_BLOCK_WINDOW: u64 = 10_000;
+
+/// Blocks scanned back from the latest block for a pool's liquidity positions when the block the pool
+/// was created at is unknown.
+pub const POSITIONS_LOOKBACK_BLOCKS: u64 = 500_000;
+
+/// Maximum number of position NFTs read from a position manager per pool, the ones to which the most
+/// liquidity was added first.
+pub const POSITIONS_MAX_READ: usize = 200;
+
+/// Liquidity (in USD) below which a pool is considered drained.
+pub const DRAINED_POOL_MAX_LIQUIDITY_USD: f64 = 100.0;
+
+/// Age (in days) below which a pool is considered brand new.
+pub const NEW_POOL_MAX_AGE_DAYS: f64 = 1.0;
+
+/// Path of the TOML file holding the labelled addresses (burn addresses, lockers, exchange wallets,
+/// bridges, routers) of each chain.
+pub const ADDRESS_LABELS_FILE: &str = "address_labels.toml";
+
+/// Addresses ownership (or an upgrade authority) is handed to when it is renounced.
+pub const RENOUNCED_OWNER_ADDRESSES: [&str; 2] = [
+    "0x0000000000000000000000000000000000000000", // zero address
+    "0x000000000000000000000000000000000000dead", // dead address (lowercased)
 ];
 
 /// Maximum allowed size (in bytes) for a token contract's source code.
@@ -110,18 +194,64 @@ This provided assessment will have the following field:
     // 2 to 3 sentences as to why (or why not) suspicious could be justified
     - reason_could_or_couldnt_justify_suspicious_code
 
+    // proxy pattern of the token (NotProxy if token is a regular contract), its implementation address,
+    // proxy admin and upgrade authority. if is_upgradeable is true the token logic (and the code
+    // reviewed above) can be replaced at any time by the upgrade authority
+    - proxy { proxy_type, implementation_address, proxy_admin, beacon_address, upgrade_authority, is_upgradeable }
+
+    // who controls the token contract. owner_type is one of NoOwner (no owner or admin role found),
+    // Renounced, Eoa (single private key), Multisig, Timelock or Contract. admin_role_holders are the
+    // AccessControl DEFAULT_ADMIN_ROLE holders. owner_percentage_tokens_held is the share of the supply
+    // the owner holds (0.0 to 100.0)
+    - ownership { owner_address, owner_type, is_renounced, admin_role_holders, multisig_owner_count, multisig_threshold, timelock_min_delay_seconds, owner_percentage_tokens_held }
+
+    // the wallet that deployed the token. previous_contracts_deployed is how many other contracts it
+    // created, previous_tokens_audited how many of those we have audited before and previous_scam_count
+    // how many of those we found to be a scam. deployer_percentage_tokens_held is 0.0 to 100.0
+    - deployer { deployer_address, deployer_percentage_tokens_held, previous_contracts_deployed, previous_tokens_audited, previous_scam_count, previous_scam_addresses }
+
     // what percentage of tokens does top token holder own?
     - top_holder_percentage_tokens_held (0.0 to 100.0)
 
     // percentage of total tokens minted that are locked or burned (ie not available for circulation)
     - percentage_of_tokens_locked_or_burned (0.0 to 100.0)
 
+    // how the circulating supply (leaving out burn addresses, lockers, exchange wallets, bridges, routers and the pool)
+    // is distributed across holders, as percentages of the total supply. holder_count is None if unknown.
+    // gini_coefficient goes from 0.0 (equal balances) to 1.0 (one holder has everything), herfindahl_index from near 0
+    // (dispersed) to 10000 (one holder), above 2500 is highly concentrated. a high contracts_percentage can hide team
+    // wallets behind contracts, a high deployer_and_owner_percentage means the team can dump on holders
+    - holder_distribution { holder_count, top_10_percentage, top_50_percentage, gini_coefficient, herfindahl_index, contracts_percentage, eoas_percentage, deployer_and_owner_percentage }
+
     // what percentage of LP (liquidity tokens) is locked (in 3rd party locker) or burned (pointing to zero/dead address)
     - percentage_liquidity_locked_or_burned Some(0.0 to 100.0) // wrapped in some because its Option<f64> (rust), if value is None then could not determine value
 
-    // the amount of liquidity (in usd) the token has on a major exchange (uniswap, etc)
+    // the locks of the pool's LP tokens decoded from the locker contracts (UNCX, Team Finance, PinkLock), earliest
+    // unlock first. percentage_locked_by_horizon is the percentage of LP tokens still locked 30, 90, 180 and 365 days
+    // from now: liquidity whose lock expires soon can be pulled soon, so it protects holders far less than a long
+    // lock. an earliest_unlock_timestamp in the past (days_until_earliest_unlock 0.0) means an expired lock was not
+    // withdrawn yet and can be pulled at any time. empty for pools without an ERC20 LP token (concentrated liquidity)
+    - lp_lock_expiry { locks: [{ locker, amount, unlock_timestamp }], percentage_locked, percentage_locked_by_horizon: [{ horizon_days, percentage_locked }], earliest_unlock_timestamp, days_until_earliest_unlock }
+
+    // the amount of liquidity (in usd) the token has on a major exchange (uniswap, etc), valued from the pool's on-chain
+    // reserves with chainlink prices
     - liquidity_in_usd
 
+    // true if the pool could not be valued on-chain (e.g. a stale chainlink price): liquidity_in_usd is then 0 whatever
+    // the pool's actual liquidity, and neither it nor its gap to subgraph_liquidity_in_usd says anything
+    - onchain_valuation_failed
+
+    // the same liquidity as reported by TheGraph, None if the pool was not found on a subgraph. subgraph figures can be
+    // stale or spoofed: a subgraph figure far above liquidity_in_usd is a red flag
+    - subgraph_liquidity_in_usd
+
+    // every pool of the token across the supported dexes, highest liquidity first, with the percentage of each pool's
+    // liquidity locked or burned and its age (None if the dex does not record it). total_liquidity_in_usd sums all pools,
+    // fragmentation goes from 0.0 (all liquidity in one pool) towards 1.0 (spread across many pools).
+    // drained_older_pools are pools created before the top pool that now hold (almost) no liquidity: if
+    // top_pool_is_tiny_or_new is also true, the liquidity was likely pulled and a new pool opened in its place
+    - pools { pools: [{ dex, pair_address, base_token_symbol, liquidity_in_usd, onchain_valuation_failed, percentage_locked_or_burned, created_at_block, age_in_days, is_drained }], total_liquidity_in_usd, top_pool_share_percentage, fragmentation, top_pool_is_tiny_or_new, drained_older_pools }
+
     // does token have a website?
     - has_website (boolean)
 
@@ -131,6 +261,56 @@ This provided assessment will have the following field:
     // Is token sellable or transferable when simulating swap with foundry anvil?
     - is_token_sellable: Some(true or false), if None then could not run simulation, and result is indetermined
 
+    // tax taken when buying, selling and transferring the token during the simulation, measured by comparing the
+    // quoted amount with the amount actually received. if None then that step of the simulation did not run
+    - buy_tax_percentage: Some(0.0 to 100.0)
+    - sell_tax_percentage: Some(0.0 to 100.0)
+    - transfer_tax_percentage: Some(0.0 to 100.0)
+
+    // after the simulated buy, time was moved forward (minutes to days) and the balance and sellability
+    // re-checked at each horizon. behaviour_changed_at is the first horizon at which the token stopped
+    // (or started) being tradable, None if it behaved the same throughout
+    - time_bomb { horizons: [{ horizon, seconds_after_purchase, balance_intact, can_sell, sell_tax }], behaviour_changed_at }
+
+    // effective limits found by probing buys, sells and transfers of increasing size, as a percentage of the
+    // total supply (None if no limit was hit). sell_cooldown_seconds is the wait needed between two sells.
+    // sell_limit_far_below_buy_limit is true if holders can buy far more than they can ever sell
+    - trade_limits { max_buy_percentage_of_supply, max_sell_percentage_of_supply, max_transfer_percentage_of_supply, max_wallet_percentage_of_supply, sell_cooldown_seconds, sell_limit_far_below_buy_limit }
+
+    // the owner (or admin) was impersonated during the simulation and called each privileged function found in
+    // the ABI (action is SetFees, Blacklist, PauseTrading or LimitTransactions) against our wallet, then we tried
+    // to sell again. an action with owner_call_succeeded true and sell_succeeded_after false proves the owner can
+    // trap holders. privileged_account is None if the token has no active owner, so nothing was simulated
+    - owner_privileges { privileged_account, actions: [{ function_signature, action, owner_call_succeeded, sell_succeeded_after, sell_tax_after }] }
+
+    // the largest holders of unlocked liquidity were impersonated during the simulation and their liquidity
+    // removed. liquidity_removed_percentage is how much of the pool's base token reserve they could pull, and
+    // sell_price_impact_percentage how much less our sell returned afterwards: the worst case if the team rugs.
+    // lp_holders_removed is empty if no unlocked liquidity could be pulled
+    - rug_pull { lp_holders_removed, liquidity_removed_percentage, sell_amount_out_before, sell_amount_out_after, sell_price_impact_percentage }
+
+    // call traces of the simulated buy, transfer and sell. for a step that reverted, revert_location is the innermost
+    // call that failed and revert_reason the decoded reason or custom error (e.g. a sell reverting in transfer with
+    // "blacklisted"). events are the decoded Transfer, Swap and Sync events the step emitted
+    - simulation_traces [{ operation, reverted, revert_location, revert_reason, calls, events }]
+
+    // addresses other than the pair and our wallets that received tokens during the simulated trades, i.e. where the
+    // token's taxes go. share_percentage is the receiver's cut of the tokens traded. fees going to an EOA
+    // (is_contract false), especially one with is_owner_related true, can be dumped on holders at any time
+    - fee_receivers [{ address, is_contract, is_token_contract, is_owner_related, amount_received, share_percentage }]
+
+    // price impact of buying and selling the token through its top pool, for trade sizes from $100 to $100k, quoted on
+    // the pool (for concentrated liquidity pools, across the ticks the trade crosses). an impact is None if the trade
+    // could not be quoted, e.g. the pool cannot fill it. max_exit_size_usd is the largest size that can be sold with
+    // less than 10% price impact, None if not even $100 can: holders cannot realistically exit larger positions
+    - depth { levels: [{ trade_size_usd, buy_price_impact_percentage, sell_price_impact_percentage }], max_exit_size_usd }
+
+    // the hook contract of the token's Uniswap V4 pool, None if the pool is not a V4 pool or has no hook. a hook
+    // runs its own code on every swap: can_block_swaps means it can revert (e.g. only sells), can_take_swap_deltas
+    // that it can take part of each trade, has_dynamic_fee that it sets the pool fee, can_block_liquidity_removal
+    // that it runs on withdrawals. possible_malicious_hook is the review of its source, None if it is not verified
+    - uniswap_v4_hook { hook_address, enabled_permissions, can_block_swaps, can_take_swap_deltas, has_dynamic_fee, can_block_liquidity_removal, is_verified, possible_malicious_hook, reason }
+
 Based on these inputs, please make a holistic determination on the legitimacy of the token and return one of the following scores:
 
 4 - Legit,
@@ -222,6 +402,31 @@ FOLLOWED BY the solidity source code which will be in a String called "source_co
 Return **only** valid JSON. Do NOT include triple backticks or any other formatting around the JSON.
 "#;
 
+/// Prompt provided to a Solidity security reviewer to assess the hook contract of a Uniswap V4 pool.
+///
+/// A hook runs arbitrary code around the pool's swaps and liquidity changes, so it can turn an
+/// otherwise clean token into a honeypot. The expected output is strictly valid JSON.
+pub const HOOK_CHECK_PROMPT: &str = r#"You are an expert Solidity security reviewer. I will provide you with the source code of a Uniswap V4 hook contract, attached to the liquidity pool a token trades in. You need to check whether this hook could be used to trap or rob the pool's traders or liquidity providers.
+
+Pay special attention to:
+1. `beforeSwap` / `afterSwap` logic that reverts, or only allows some addresses to sell (whitelists, blacklists, trading switches, cooldowns).
+2. Swap deltas returned by `beforeSwap` / `afterSwap` (`BeforeSwapDelta`, `int128` returned from `afterSwap`) that take part of the trade, and who receives that amount.
+3. Dynamic fees: whether the hook sets the LP fee, how high it can go, and who controls it.
+4. `beforeRemoveLiquidity` / `afterRemoveLiquidity` logic that prevents providers from withdrawing, or lets a privileged account withdraw liquidity it does not own.
+5. Owner or admin functions that change any of the above after deployment, and upgradeability of the hook.
+
+After analyzing these points, respond **strictly** in the following JSON format (no additional text). The `reason` should not exceed 2 to 3 sentences:
+
+{
+  "possible_malicious_hook": <true_or_false>,
+  "reason": "<2_or_3_sentences_describing_rationale>"
+}
+
+FOLLOWED BY the solidity source code which will be in a String called "source_code".
+
+Return **only** valid JSON. Do NOT include triple backticks or any other formatting around the JSON.
+"#;
+
 /// Prompt provided to a web credibility expert to assess a token project's website.
 ///
 /// This prompt instructs the reviewer to look for scam signals, inconsistencies,
@@ -54,3 +54,26 @@ pub async fn get_chainlink_price_feed_for_token_(
         ))
     }
 }
+
+/// Heartbeat (in seconds) assumed for feeds not listed in [`get_chainlink_heartbeat_seconds`], the
+/// longest heartbeat of Chainlink's standard USD feeds.
+pub const CHAINLINK_DEFAULT_HEARTBEAT_SECONDS: u64 = 86_400;
+
+/// Returns the heartbeat of a token's Chainlink USD feed: the longest the feed goes without a new
+/// round when the price does not move past its deviation threshold.
+///
+/// # Arguments
+///
+/// * `token_symbol` - The feed's token symbol, e.g. `ETH` or `USDC`.
+/// * `chain` - The chain the feed is on.
+///
+/// # Returns
+///
+/// * `u64` - The heartbeat in seconds, [`CHAINLINK_DEFAULT_HEARTBEAT_SECONDS`] for unlisted feeds.
+pub fn get_chainlink_heartbeat_seconds(token_symbol: &str, chain: &Chain) -> u64 {
+    match (chain, token_symbol.to_uppercase().as_str()) {
+        (Chain::Mainnet, "ETH" | "BTC" | "LINK" | "DAI") => 3_600,
+        (Chain::Base, "ETH" | "BTC" | "LINK") => 1_200,
+        _ => CHAINLINK_DEFAULT_HEARTBEAT_SECONDS,
+    }
+}
@@ -0,0 +1,130 @@
+/*!
+    This module provides a registry of labelled addresses (burn addresses, lockers, exchange
+    wallets, bridges, routers) per chain. The labels are read from a TOML file
+    (address_labels.toml) and made available via a global, lazily-initialized instance, so new
+    lockers or exchange wallets can be added without code changes.
+*/
+
+use anyhow::{anyhow, Result};
+use ethers::types::{Address, Chain};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::{collections::HashMap, fs, str::FromStr};
+
+use crate::app_config::ADDRESS_LABELS_FILE;
+
+/// What a labelled address is.
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressCategory {
+    /// Tokens sent here are gone for good (dead / zero address).
+    Burn,
+    /// Tokens, LP tokens or position NFTs sent here are locked by a third party.
+    Locker,
+    /// A centralized exchange wallet holding its users' tokens.
+    Exchange,
+    /// A bridge escrowing tokens moved to another chain.
+    Bridge,
+    /// A DEX router, which only holds tokens in transit.
+    Router,
+}
+
+impl AddressCategory {
+    /// Returns `true` if tokens held by the address are burned or locked, i.e. out of circulation.
+    pub fn is_burned_or_locked(&self) -> bool {
+        matches!(self, Self::Burn | Self::Locker)
+    }
+
+    /// Returns `true` if the address holds tokens on behalf of many users, so a large balance is not
+    /// a single whale that could dump the token.
+    pub fn holds_for_others(&self) -> bool {
+        matches!(self, Self::Exchange | Self::Bridge | Self::Router)
+    }
+}
+
+/// A labelled address.
+#[derive(Clone, Deserialize, Debug)]
+pub struct AddressLabel {
+    /// The labelled address.
+    pub address: Address,
+    /// Who the address belongs to (e.g. "UNCX V2 locker", "Binance 14").
+    pub name: String,
+    /// What the address is.
+    pub category: AddressCategory,
+}
+
+/// Holds the labelled addresses of each chain.
+#[derive(Debug, Default)]
+pub struct AddressLabelRegistry {
+    /// A mapping from blockchain chain identifiers to their labelled addresses.
+    labels: HashMap<Chain, HashMap<Address, AddressLabel>>,
+}
+
+impl AddressLabelRegistry {
+    /// Parses the registry from TOML, with one array of labels per chain named after the chain
+    /// (e.g. `[[mainnet]]`, `[[base]]`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML is invalid or names an unknown chain.
+    pub fn from_toml_str(config: &str) -> Result<Self> {
+        let chains: HashMap<String, Vec<AddressLabel>> = toml::from_str(config)?;
+
+        let mut labels = HashMap::new();
+        for (chain_name, chain_labels) in chains {
+            let chain = Chain::from_str(&chain_name)
+                .map_err(|_| anyhow!("unknown chain in address labels: {}", chain_name))?;
+            let labels_by_address: &mut HashMap<Address, AddressLabel> =
+                labels.entry(chain).or_default();
+            for label in chain_labels {
+                labels_by_address.insert(label.address, label);
+            }
+        }
+
+        Ok(Self { labels })
+    }
+
+    /// Loads the registry from a TOML file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn load(path: &str) -> Result<Self> {
+        let config = fs::read_to_string(path)?;
+        Self::from_toml_str(&config)
+    }
+
+    /// Returns the label of an address on a chain, `None` if the address is not labelled.
+    pub fn get(&self, chain: &Chain, address: Address) -> Option<&AddressLabel> {
+        self.labels.get(chain)?.get(&address)
+    }
+
+    /// Returns the label of a holder given as a hex string (as returned by the holder APIs), `None`
+    /// if the holder is not labelled or is not a valid address.
+    pub fn get_holder(&self, chain: &Chain, holder: &str) -> Option<&AddressLabel> {
+        self.get(chain, holder.parse().ok()?)
+    }
+
+    /// Returns `true` if tokens held by the holder are burned or locked.
+    pub fn is_burned_or_locked(&self, chain: &Chain, holder: &str) -> bool {
+        self.get_holder(chain, holder)
+            .is_some_and(|label| label.category.is_burned_or_locked())
+    }
+
+    /// Returns `true` if the holder holds tokens on behalf of many users (exchange, bridge, router).
+    pub fn holds_for_others(&self, chain: &Chain, holder: &str) -> bool {
+        self.get_holder(chain, holder)
+            .is_some_and(|label| label.category.holds_for_others())
+    }
+}
+
+/// Lazily initialized global registry of labelled addresses.
+///
+/// The instance is created when first accessed from the labels defined in `address_labels.toml`.
+///
+/// # Panics
+///
+/// Panics if the file cannot be read or parsed.
+pub static ADDRESS_LABELS: Lazy<AddressLabelRegistry> = Lazy::new(|| {
+    AddressLabelRegistry::load(ADDRESS_LABELS_FILE).expect("failed to load address labels")
+});
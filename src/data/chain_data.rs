@@ -29,10 +29,30 @@ pub struct ContractAddresses {
     pub uniswap_v2_router: String,
     /// Uniswap V2 Factory contract address.
     pub uniswap_v2_factory: String,
+    /// The block the Uniswap V2 Factory was deployed at, where the search for `PairCreated` events
+    /// starts.
+    pub uniswap_v2_factory_deploy_block: u64,
     /// Uniswap Factory contract address.
     pub uniswap_v3_factory: String,
+    /// The block the Uniswap V3 Factory was deployed at, where the search for `PoolCreated` events
+    /// starts.
+    pub uniswap_v3_factory_deploy_block: u64,
     /// Uniswap Quoter contract address.
     pub uniswap_v3_quoter: String,
+    /// Uniswap V3 NonfungiblePositionManager contract address.
+    pub uniswap_v3_position_manager: String,
+    /// USD Coin (USDC) contract address.
+    pub usdc: String,
+    /// Tether USD (USDT) contract address.
+    pub usdt: String,
+    /// Dai Stablecoin (DAI) contract address.
+    pub dai: String,
+    /// Aerodrome contract addresses, `None` on chains Aerodrome is not deployed on.
+    pub aerodrome: Option<AerodromeContracts>,
+    /// Uniswap V4 contract addresses, `None` on chains Uniswap V4 is not deployed on.
+    pub uniswap_v4: Option<UniswapV4Contracts>,
+    /// LP token locker contract addresses, `None` on chains without supported lockers.
+    pub lockers: Option<LockerContracts>,
     /// WebSocket endpoint URL for blockchain access.
     pub ws_url: String,
     /// HTTP endpoint URL for blockchain access.
@@ -41,6 +61,56 @@ pub struct ContractAddresses {
     pub alchemy_url: String,
 }
 
+/// Holds the Aerodrome contract addresses: the classic (volatile / stable) pools and the Slipstream
+/// concentrated liquidity pools.
+#[derive(Clone, Deserialize, Debug)]
+pub struct AerodromeContracts {
+    /// Router for the volatile and stable pools.
+    pub router: String,
+    /// Factory of the volatile and stable pools.
+    pub pool_factory: String,
+    /// Factory of the Slipstream pools.
+    pub slipstream_factory: String,
+    /// Swap router for the Slipstream pools.
+    pub slipstream_router: String,
+    /// Quoter for the Slipstream pools.
+    pub slipstream_quoter: String,
+    /// NonfungiblePositionManager of the Slipstream pools.
+    pub slipstream_position_manager: String,
+}
+
+/// Holds the Uniswap V4 contract addresses. V4 pools all live in the singleton `PoolManager` and are
+/// identified by the hash of their pool key rather than by a contract address.
+#[derive(Clone, Deserialize, Debug)]
+pub struct UniswapV4Contracts {
+    /// The singleton holding every V4 pool and its tokens.
+    pub pool_manager: String,
+    /// The block the pool manager was deployed at, where the search for `Initialize` events starts.
+    pub pool_manager_deploy_block: u64,
+    /// Read-only view of the pool manager's state (slot0, liquidity).
+    pub state_view: String,
+    /// Quoter for V4 pools.
+    pub quoter: String,
+    /// Universal Router, through which V4 swaps are sent.
+    pub universal_router: String,
+    /// PositionManager of the V4 liquidity positions.
+    pub position_manager: String,
+    /// Permit2, which the Universal Router pulls ERC20 tokens through.
+    pub permit2: String,
+}
+
+/// Holds the addresses of the LP token locker contracts whose locks can be decoded. Each locker is
+/// `None` on chains it is not deployed on.
+#[derive(Clone, Deserialize, Debug)]
+pub struct LockerContracts {
+    /// UNCX (Unicrypt) Uniswap V2 locker.
+    pub uncx: Option<String>,
+    /// Team Finance token locker.
+    pub team_finance: Option<String>,
+    /// PinkSale's PinkLock.
+    pub pinklock: Option<String>,
+}
+
 /// Maps blockchain chains to their corresponding contract addresses.
 #[derive(Debug)]
 pub struct ContractAddressMap {
@@ -61,10 +131,19 @@ impl ContractAddressMap {
             Chain::Base,
             ContractAddresses {
                 uniswap_v3_factory: chains.base.uniswap_factory,
+                uniswap_v3_factory_deploy_block: chains.base.uniswap_factory_deploy_block,
                 uniswap_v3_router: chains.base.uniswap_swap_router,
                 uniswap_v2_factory: chains.base.uniswap_v2_factory,
+                uniswap_v2_factory_deploy_block: chains.base.uniswap_v2_factory_deploy_block,
                 uniswap_v2_router: chains.base.uniswap_v2_router,
                 uniswap_v3_quoter: chains.base.uniswap_quoter,
+                uniswap_v3_position_manager: chains.base.uniswap_position_manager,
+                usdc: chains.base.usdc,
+                usdt: chains.base.usdt,
+                dai: chains.base.dai,
+                aerodrome: chains.base.aerodrome,
+                uniswap_v4: chains.base.uniswap_v4,
+                lockers: chains.base.lockers,
                 weth: chains.base.weth,
                 link: chains.base.link,
                 ws_url: chains.base.ws_url,
@@ -78,9 +157,18 @@ impl ContractAddressMap {
             Chain::Mainnet,
             ContractAddresses {
                 uniswap_v3_factory: chains.mainnet.uniswap_factory,
+                uniswap_v3_factory_deploy_block: chains.mainnet.uniswap_factory_deploy_block,
                 uniswap_v3_router: chains.mainnet.uniswap_swap_router,
                 uniswap_v3_quoter: chains.mainnet.uniswap_quoter,
+                uniswap_v3_position_manager: chains.mainnet.uniswap_position_manager,
+                usdc: chains.mainnet.usdc,
+                usdt: chains.mainnet.usdt,
+                dai: chains.mainnet.dai,
+                aerodrome: chains.mainnet.aerodrome,
+                uniswap_v4: chains.mainnet.uniswap_v4,
+                lockers: chains.mainnet.lockers,
                 uniswap_v2_factory: chains.mainnet.uniswap_v2_factory,
+                uniswap_v2_factory_deploy_block: chains.mainnet.uniswap_v2_factory_deploy_block,
                 uniswap_v2_router: chains.mainnet.uniswap_v2_router,
                 weth: chains.mainnet.weth,
                 link: chains.mainnet.link,
@@ -116,16 +204,34 @@ struct ChainContracts {
     uniswap_swap_router: String,
     /// Address for the Uniswap Factory.
     uniswap_factory: String,
+    /// Block the Uniswap Factory was deployed at.
+    uniswap_factory_deploy_block: u64,
     /// Address for the Uniswap Quoter.
     uniswap_quoter: String,
+    /// Address for the Uniswap V3 NonfungiblePositionManager.
+    uniswap_position_manager: String,
     /// Address for the Uniswap V2 Router.
     uniswap_v2_router: String,
     /// Address for the Uniswap V2 Factory.
     uniswap_v2_factory: String,
+    /// Block the Uniswap V2 Factory was deployed at.
+    uniswap_v2_factory_deploy_block: u64,
     /// Wrapped Ether (WETH) contract address.
     weth: String,
     /// Chainlink (LINK) token contract address.
     link: String,
+    /// USD Coin (USDC) contract address.
+    usdc: String,
+    /// Tether USD (USDT) contract address.
+    usdt: String,
+    /// Dai Stablecoin (DAI) contract address.
+    dai: String,
+    /// Aerodrome contract addresses, absent on chains Aerodrome is not deployed on.
+    aerodrome: Option<AerodromeContracts>,
+    /// Uniswap V4 contract addresses, absent on chains Uniswap V4 is not deployed on.
+    uniswap_v4: Option<UniswapV4Contracts>,
+    /// LP token locker contract addresses, absent on chains without supported lockers.
+    lockers: Option<LockerContracts>,
     /// WebSocket URL for accessing the blockchain node.
     ws_url: String,
     /// HTTP URL for accessing the blockchain node.
@@ -13,8 +13,10 @@ pub enum Dex {
     UniswapV3,
     /// Uniswap V4 - Hooks-enabled AMM
     UniswapV4,
-    /// Aerodrome - Base chain AMM
+    /// Aerodrome - Base chain AMM with volatile and stable pools
     Aerodrome,
+    /// Aerodrome Slipstream - Aerodrome's concentrated liquidity pools
+    AerodromeSlipstream,
     /// Sushiswap - Fork of Uniswap with additional features
     Sushiswap,
     /// Balancer - Multi-token pools with customizable weights
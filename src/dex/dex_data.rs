@@ -5,10 +5,7 @@ use ethers::{abi::Address, types::Chain};
 
 use crate::{app_config::DEXES, data::dex::Dex};
 
-use super::thegraph::{
-    uniswap_v2::get_top_uniswap_v2_pair_by_token_and_chain,
-    uniswap_v3::get_top_uniswap_v3_pool_by_token_and_chain,
-};
+use super::swap_venue::swap_venue;
 
 #[derive(Clone, Default, Debug)]
 pub struct TokenDexData {
@@ -23,7 +20,22 @@ pub struct TokenDexData {
     pub base_token_symbol: String, // address of base token provides the pair liquidity, typically WETH,
     // USDC , DAI, or WBTC. the liquidity pair is token / base_token
     pub fee: u32,
+    /// Whether an Aerodrome pool is a stable (correlated assets) pool rather than a volatile one.
+    pub is_stable: bool,
+    /// The tick spacing identifying an Aerodrome Slipstream or Uniswap V4 pool, 0 for other pools.
+    pub tick_spacing: i32,
+    /// The hook contract of a Uniswap V4 pool, the zero address for pools without hooks and other
+    /// DEXes. A V4 pool's `pair_address` is the pool manager, which holds the tokens of every pool.
+    pub hooks: Address,
+    /// The pool's liquidity in USD, valued from its on-chain reserves with the base token's Chainlink
+    /// price, 0 if the pool could not be valued on-chain.
     pub liquidity_in_usd: f64,
+    /// Whether the pool could not be valued on-chain, e.g. the base token's Chainlink price is stale,
+    /// so `liquidity_in_usd` is 0 regardless of the pool's actual liquidity.
+    pub onchain_valuation_failed: bool,
+    /// The pool's liquidity in USD as reported by TheGraph, `None` for pools not found on a subgraph.
+    pub subgraph_liquidity_in_usd: Option<f64>,
+    /// The block the pool was created at, 0 if the venue does not record it.
     pub create_at_block_number: u64,
 }
 
@@ -37,14 +49,20 @@ pub async fn find_top_dex_for_token(
     let mut full_dex_data = Vec::<TokenDexData>::new();
 
     for dex in DEXES {
-        let dex_data = match dex {
-            Dex::UniswapV3 => {
-                get_top_uniswap_v3_pool_by_token_and_chain(token_address, chain).await?
+        // A venue that cannot be queried (e.g. a provider error) is skipped, not fatal.
+        let dex_data = match swap_venue(&dex)
+            .ok_or_else(|| anyhow!("get_all_dex_data_for_token: dex not found"))?
+            .find_top_pool(token_address, chain)
+            .await
+        {
+            Ok(dex_data) => dex_data,
+            Err(e) => {
+                println!(
+                    "could not find {:?} pool for {:?}: {}",
+                    dex, token_address, e
+                );
+                continue;
             }
-            Dex::UniswapV2 => {
-                get_top_uniswap_v2_pair_by_token_and_chain(token_address, chain).await?
-            }
-            _ => return Err(anyhow!("get_all_dex_data_for_token: dex not found")),
         };
 
         if let Some(data) = dex_data {
@@ -66,6 +84,41 @@ pub async fn find_top_dex_for_token(
     Ok(Some(top_dex.clone()))
 }
 
+/// Lists the token's pools on every supported DEX, including drained ones. A DEX whose pools cannot
+/// be queried is skipped.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `anyhow::Result<Vec<TokenDexData>>` - The pools, highest liquidity first.
+pub async fn find_all_dex_pools_for_token(
+    token_address: Address,
+    chain: &Chain,
+) -> anyhow::Result<Vec<TokenDexData>> {
+    let mut full_dex_data = Vec::<TokenDexData>::new();
+
+    for dex in DEXES {
+        // A venue that cannot be queried (e.g. a provider error) is skipped, not fatal.
+        match swap_venue(&dex)
+            .ok_or_else(|| anyhow!("find_all_dex_pools_for_token: dex not found"))?
+            .find_pools(token_address, chain)
+            .await
+        {
+            Ok(pools) => full_dex_data.extend(pools),
+            Err(e) => println!(
+                "could not find {:?} pools for {:?}: {}",
+                dex, token_address, e
+            ),
+        }
+    }
+
+    Ok(sort_token_dex_data_vec(full_dex_data))
+}
+
 pub fn sort_token_dex_data_vec(mut dex_data_array: Vec<TokenDexData>) -> Vec<TokenDexData> {
     dex_data_array.sort_by(|a, b| {
         b.liquidity_in_usd
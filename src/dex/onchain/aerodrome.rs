@@ -0,0 +1,193 @@
+//! This module finds a token's Aerodrome pools by querying the Aerodrome factories on-chain.
+//!
+//! Aerodrome has two kinds of pools: the classic volatile and stable pools, created by the pool
+//! factory and traded through the router, and the Slipstream concentrated liquidity pools, created
+//! per tick spacing by the Slipstream factory. The token is looked up against each base token (WETH,
+//! USDC, USDT, DAI), and the pool holding the most liquidity is returned, or all of them when the
+//! token's liquidity is compared across pools.
+
+use anyhow::Result;
+use ethers::types::{Address, Chain};
+
+use crate::abi::aerodrome_pool_factory::AERODROME_POOL_FACTORY;
+use crate::abi::slipstream_factory::SLIPSTREAM_FACTORY;
+use crate::abi::uniswap_pool::UNISWAP_V3_POOL;
+use crate::data::chain_data::CHAIN_DATA;
+use crate::data::dex::Dex;
+use crate::data::provider_manager::get_chain_provider;
+use crate::dex::dex_data::{sort_token_dex_data_vec, TokenDexData};
+
+use super::shared::{get_base_tokens, get_pool_valuation, new_dex_data};
+
+/// Tick spacings of the Slipstream pools searched.
+pub const AERODROME_SLIPSTREAM_TICK_SPACINGS: [i32; 5] = [1, 50, 100, 200, 2000];
+
+/// Default fee of Aerodrome volatile pools (0.3%), in the same units as Uniswap fees.
+pub const AERODROME_VOLATILE_FEE: u32 = 3000;
+/// Default fee of Aerodrome stable pools (0.05%), in the same units as Uniswap fees.
+pub const AERODROME_STABLE_FEE: u32 = 500;
+
+/// Finds the token's volatile or stable Aerodrome pool with the most liquidity.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Option<TokenDexData>>` - The pool, `None` if the token has no pool or Aerodrome is not
+///   deployed on the chain.
+pub async fn get_top_aerodrome_pool_by_token_and_chain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Option<TokenDexData>> {
+    let pools = get_aerodrome_pools_by_token_and_chain(token_address, chain).await?;
+    Ok(sort_token_dex_data_vec(pools).into_iter().next())
+}
+
+/// Lists all of the token's volatile and stable Aerodrome pools against the base tokens, including
+/// drained pools. A pool that cannot be valued is left out.
+///
+/// The pools are looked up with the factory's `getPool`, which does not return when a pool was
+/// created. Their creation block (and so their age) is left unknown, as finding it from the
+/// factory's `PoolCreated` events would mean scanning the factory's whole history.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Vec<TokenDexData>>` - The pools, empty if Aerodrome is not deployed on the chain.
+pub async fn get_aerodrome_pools_by_token_and_chain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Vec<TokenDexData>> {
+    let aerodrome = match &CHAIN_DATA.get_address(chain).aerodrome {
+        Some(aerodrome) => aerodrome,
+        None => return Ok(Vec::new()),
+    };
+    let client = get_chain_provider(chain).await?;
+    let factory =
+        AERODROME_POOL_FACTORY::new(aerodrome.pool_factory.parse::<Address>()?, client.clone());
+
+    let mut pools = Vec::new();
+    for base_token_address in get_base_tokens(chain)? {
+        for stable in [false, true] {
+            let pool_address = factory
+                .get_pool(token_address, base_token_address, stable)
+                .call()
+                .await?;
+            if pool_address.is_zero() {
+                continue;
+            }
+
+            let valuation =
+                match get_pool_valuation(pool_address, base_token_address, chain, &client).await {
+                    Ok(valuation) => valuation,
+                    Err(e) => {
+                        println!("could not value pool {:?}: {}", pool_address, e);
+                        continue;
+                    }
+                };
+            let fee = if stable {
+                AERODROME_STABLE_FEE
+            } else {
+                AERODROME_VOLATILE_FEE
+            };
+            let mut dex_data =
+                new_dex_data(Dex::Aerodrome, pool_address, token_address, valuation, fee);
+            dex_data.is_stable = stable;
+
+            pools.push(dex_data);
+        }
+    }
+
+    Ok(pools)
+}
+
+/// Finds the token's Aerodrome Slipstream pool with the most liquidity, across the tick spacings.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Option<TokenDexData>>` - The pool, `None` if the token has no pool or Aerodrome is not
+///   deployed on the chain.
+pub async fn get_top_aerodrome_slipstream_pool_by_token_and_chain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Option<TokenDexData>> {
+    let pools = get_aerodrome_slipstream_pools_by_token_and_chain(token_address, chain).await?;
+    Ok(sort_token_dex_data_vec(pools).into_iter().next())
+}
+
+/// Lists all of the token's Aerodrome Slipstream pools against the base tokens, across the tick
+/// spacings, including drained pools. A pool that cannot be valued is left out, and the pools' age
+/// is unknown, as for the classic pools.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Vec<TokenDexData>>` - The pools, empty if Aerodrome is not deployed on the chain.
+pub async fn get_aerodrome_slipstream_pools_by_token_and_chain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Vec<TokenDexData>> {
+    let aerodrome = match &CHAIN_DATA.get_address(chain).aerodrome {
+        Some(aerodrome) => aerodrome,
+        None => return Ok(Vec::new()),
+    };
+    let client = get_chain_provider(chain).await?;
+    let factory = SLIPSTREAM_FACTORY::new(
+        aerodrome.slipstream_factory.parse::<Address>()?,
+        client.clone(),
+    );
+
+    let mut pools = Vec::new();
+    for base_token_address in get_base_tokens(chain)? {
+        for tick_spacing in AERODROME_SLIPSTREAM_TICK_SPACINGS {
+            let pool_address = factory
+                .get_pool(token_address, base_token_address, tick_spacing)
+                .call()
+                .await?;
+            if pool_address.is_zero() {
+                continue;
+            }
+
+            let valuation =
+                match get_pool_valuation(pool_address, base_token_address, chain, &client).await {
+                    Ok(valuation) => valuation,
+                    Err(e) => {
+                        println!("could not value pool {:?}: {}", pool_address, e);
+                        continue;
+                    }
+                };
+            let fee = UNISWAP_V3_POOL::new(pool_address, client.clone())
+                .fee()
+                .call()
+                .await?;
+            let mut dex_data = new_dex_data(
+                Dex::AerodromeSlipstream,
+                pool_address,
+                token_address,
+                valuation,
+                fee,
+            );
+            dex_data.tick_spacing = tick_spacing;
+
+            pools.push(dex_data);
+        }
+    }
+
+    Ok(pools)
+}
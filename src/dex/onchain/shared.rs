@@ -0,0 +1,334 @@
+//! Helpers shared by the pool discovery that reads factories directly on-chain, rather than through
+//! a subgraph, and by the on-chain valuation of the pools found on a subgraph.
+//!
+//! Scanning a factory's events from its deployment takes thousands of log queries, so the events
+//! found are cached per token along with the last block scanned. Pools cannot be un-created, so
+//! later lookups (within the same audit or a later one) only scan the new blocks.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use ethers::providers::{Middleware, Provider, Ws};
+use ethers::types::{Address, Chain, Filter, Log, H256};
+use ethers::utils::format_units;
+use once_cell::sync::Lazy;
+use tokio::sync::Mutex;
+
+use crate::abi::erc20::ERC20;
+use crate::app_config::LOG_QUERY_BLOCK_WINDOW;
+use crate::data::chain_data::CHAIN_DATA;
+use crate::data::dex::Dex;
+use crate::data::provider_manager::get_chain_provider;
+use crate::dex::dex_data::TokenDexData;
+use crate::utils::tx::get_token_price_usd;
+use crate::utils::type_conversion::address_to_string;
+
+/// A pool's base token, and the pool's liquidity valued from it.
+///
+/// # Fields
+///
+/// * `base_token_address` - The base token the pool pairs the token against.
+/// * `base_token_symbol` - The base token's symbol.
+/// * `liquidity_in_usd` - The pool's liquidity in USD, 0 if the base token could not be priced.
+/// * `price_unavailable` - Whether the base token had no fresh Chainlink price.
+#[derive(Clone, Debug, Default)]
+pub struct PoolValuation {
+    pub base_token_address: Address,
+    pub base_token_symbol: String,
+    pub liquidity_in_usd: f64,
+    pub price_unavailable: bool,
+}
+
+/// Returns the base tokens pools are searched against on a chain: WETH, USDC, USDT and DAI.
+///
+/// # Arguments
+///
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Vec<Address>>` - The base token addresses.
+pub fn get_base_tokens(chain: &Chain) -> Result<Vec<Address>> {
+    let addresses = CHAIN_DATA.get_address(chain);
+    Ok(vec![
+        addresses.weth.parse()?,
+        addresses.usdc.parse()?,
+        addresses.usdt.parse()?,
+        addresses.dai.parse()?,
+    ])
+}
+
+/// Splits an inclusive block range into consecutive windows of at most `window` blocks, to page
+/// `eth_getLogs` queries.
+///
+/// # Arguments
+///
+/// * `from_block` - The first block of the range.
+/// * `to_block` - The last block of the range.
+/// * `window` - The maximum number of blocks per window.
+///
+/// # Returns
+///
+/// * `Vec<(u64, u64)>` - The first and last block of each window, empty if the range is empty.
+pub fn block_windows(from_block: u64, to_block: u64, window: u64) -> Vec<(u64, u64)> {
+    let window = window.max(1);
+    let mut windows = Vec::new();
+    let mut window_start = from_block;
+    while window_start <= to_block {
+        let window_end = window_start.saturating_add(window - 1).min(to_block);
+        windows.push((window_start, window_end));
+        if window_end == u64::MAX {
+            break;
+        }
+        window_start = window_end + 1;
+    }
+
+    windows
+}
+
+/// The logs of a contract's event found so far for a token, and the last block scanned for them.
+#[derive(Clone, Debug, Default)]
+struct TokenLogScan {
+    scanned_to_block: u64,
+    logs: Vec<Log>,
+}
+
+/// Key of a token event scan: chain, contract, event signature and token.
+type TokenLogScanKey = (Chain, Address, H256, Address);
+
+/// Cache of the token event scans.
+static TOKEN_LOG_SCAN_CACHE: Lazy<Mutex<HashMap<TokenLogScanKey, TokenLogScan>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Returns the logs of a contract's event that have the token in one of the given indexed topics,
+/// e.g. a factory's pool creation events for the pools the token is part of.
+///
+/// This function performs the following steps:
+/// 1. Starts from the cached scan of the token, or from `from_block`.
+/// 2. Scans the contract's events up to the latest block in [`LOG_QUERY_BLOCK_WINDOW`] windows, once
+///    per topic.
+/// 3. Caches the logs found, unless a window could not be scanned, so the next lookup retries it.
+///
+/// # Arguments
+///
+/// * `contract_address` - The contract emitting the event.
+/// * `event_signature` - The event's signature hash (first topic).
+/// * `token_topics` - The indexed topics (1 to 3) the token can appear in.
+/// * `token_address` - The token to look up.
+/// * `from_block` - The block the scan starts at, typically the contract's deployment block.
+/// * `chain` - The chain the contract is on.
+/// * `client` - The provider used for the log queries.
+///
+/// # Returns
+///
+/// * `Result<Vec<Log>>` - The logs, oldest scan first. A window that could not be scanned is logged
+///   and skipped.
+pub async fn get_token_event_logs(
+    contract_address: Address,
+    event_signature: H256,
+    token_topics: &[usize],
+    token_address: Address,
+    from_block: u64,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> Result<Vec<Log>> {
+    let cache_key = (*chain, contract_address, event_signature, token_address);
+    let cached_scan = TOKEN_LOG_SCAN_CACHE.lock().await.get(&cache_key).cloned();
+    let (from_block, mut logs) = match cached_scan {
+        Some(scan) => (scan.scanned_to_block + 1, scan.logs),
+        None => (from_block, Vec::new()),
+    };
+
+    let token_topic = H256::from(token_address);
+    let latest_block = client.get_block_number().await?.as_u64();
+    let mut scan_complete = true;
+    for (window_start, window_end) in
+        block_windows(from_block, latest_block, LOG_QUERY_BLOCK_WINDOW)
+    {
+        for token_topic_index in token_topics {
+            let filter = Filter::new()
+                .address(contract_address)
+                .topic0(event_signature)
+                .from_block(window_start)
+                .to_block(window_end);
+            let filter = match token_topic_index {
+                1 => filter.topic1(token_topic),
+                2 => filter.topic2(token_topic),
+                _ => filter.topic3(token_topic),
+            };
+
+            match client.get_logs(&filter).await {
+                Ok(window_logs) => logs.extend(window_logs),
+                Err(e) => {
+                    println!(
+                        "could not scan {:?} events of {:?} from block {} to {}: {}",
+                        contract_address, token_address, window_start, window_end, e
+                    );
+                    scan_complete = false;
+                }
+            }
+        }
+    }
+
+    if scan_complete {
+        TOKEN_LOG_SCAN_CACHE.lock().await.insert(
+            cache_key,
+            TokenLogScan {
+                scanned_to_block: latest_block,
+                logs: logs.clone(),
+            },
+        );
+    }
+
+    Ok(logs)
+}
+
+/// Prices a pool's base token in USD with its Chainlink feed.
+///
+/// This is the single stale-feed policy of the pool valuations: a base token without a fresh price
+/// is logged and values its pools at 0, with the pools flagged rather than left out or valued from
+/// another source.
+///
+/// # Arguments
+///
+/// * `base_token_symbol` - The base token's symbol.
+/// * `chain` - The chain the pool is on.
+/// * `client` - The provider used for the on-chain lookups.
+///
+/// # Returns
+///
+/// * `Option<f64>` - The base token's price, `None` if its feed is stale or cannot be read.
+pub async fn get_base_token_price_usd(
+    base_token_symbol: &str,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> Option<f64> {
+    match get_token_price_usd(base_token_symbol, chain, client).await {
+        Ok(price) => Some(price),
+        Err(e) => {
+            println!(
+                "could not price {} to value its pools: {}",
+                base_token_symbol, e
+            );
+            None
+        }
+    }
+}
+
+/// Values a pool's liquidity in USD as twice the value of the base token it holds, the base token
+/// being priced with [`get_base_token_price_usd`]. The pool is valued at 0 and flagged if the base
+/// token has no fresh price.
+///
+/// # Arguments
+///
+/// * `pool_address` - The pair / pool.
+/// * `base_token_address` - The base token the pool pairs the token against.
+/// * `chain` - The chain the pool is on.
+/// * `client` - The provider used for the on-chain lookups.
+///
+/// # Returns
+///
+/// * `Result<PoolValuation>` - The base token and the pool's liquidity in USD, or an error if the
+///   base token or its reserve could not be read.
+pub async fn get_pool_valuation(
+    pool_address: Address,
+    base_token_address: Address,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> Result<PoolValuation> {
+    let base_token = ERC20::new(base_token_address, client.clone());
+    let base_token_symbol = base_token.symbol().call().await?;
+    let decimals = base_token.decimals().call().await?;
+    let base_token_reserve = base_token.balance_of(pool_address).call().await?;
+
+    let base_token_reserve = format_units(base_token_reserve, decimals as u32)?.parse::<f64>()?;
+    let base_token_price = get_base_token_price_usd(&base_token_symbol, chain, client).await;
+
+    Ok(PoolValuation {
+        base_token_address,
+        base_token_symbol,
+        liquidity_in_usd: 2_f64 * base_token_reserve * base_token_price.unwrap_or_default(),
+        price_unavailable: base_token_price.is_none(),
+    })
+}
+
+/// Values a pool found on a subgraph from its on-chain reserves, as the subgraph's USD figures are
+/// easy to spoof and often stale. The subgraph's figure is kept in `subgraph_liquidity_in_usd` only:
+/// a pool that cannot be valued on-chain follows the same policy as the pools found on-chain, its
+/// liquidity is 0 and `onchain_valuation_failed` is set.
+///
+/// # Arguments
+///
+/// * `dex_data` - The pool, as found on the subgraph.
+/// * `chain` - The chain the pool is on.
+///
+/// # Returns
+///
+/// * `Result<TokenDexData>` - The pool, with its liquidity valued on-chain.
+pub async fn value_subgraph_pool_onchain(
+    mut dex_data: TokenDexData,
+    chain: &Chain,
+) -> Result<TokenDexData> {
+    let client = get_chain_provider(chain).await?;
+    let base_token_address: Address = dex_data.base_token_address.parse()?;
+
+    match get_pool_valuation(dex_data.pair_address, base_token_address, chain, &client).await {
+        Ok(valuation) => {
+            dex_data.liquidity_in_usd = valuation.liquidity_in_usd;
+            dex_data.onchain_valuation_failed = valuation.price_unavailable;
+        }
+        Err(e) => {
+            println!(
+                "could not value pool {:?} on-chain: {}",
+                dex_data.pair_address, e
+            );
+            dex_data.liquidity_in_usd = 0.0;
+            dex_data.onchain_valuation_failed = true;
+        }
+    }
+
+    Ok(dex_data)
+}
+
+/// Builds the dex data of a pool found on-chain. Pools sort their tokens by address, like Uniswap.
+///
+/// # Arguments
+///
+/// * `dex` - The DEX the pool belongs to.
+/// * `pool_address` - The pair / pool.
+/// * `token_address` - The token looked up.
+/// * `valuation` - The pool's base token and liquidity.
+/// * `fee` - The pool's fee, in Uniswap fee units.
+///
+/// # Returns
+///
+/// * `TokenDexData` - The pool's dex data.
+pub fn new_dex_data(
+    dex: Dex,
+    pool_address: Address,
+    token_address: Address,
+    valuation: PoolValuation,
+    fee: u32,
+) -> TokenDexData {
+    let is_token_0 = token_address < valuation.base_token_address;
+    let (token_0, token_1) = if is_token_0 {
+        (token_address, valuation.base_token_address)
+    } else {
+        (valuation.base_token_address, token_address)
+    };
+
+    TokenDexData {
+        dex,
+        pair_address: pool_address,
+        token_0,
+        token_1,
+        is_token_0,
+        base_token_address: address_to_string(valuation.base_token_address),
+        base_token_symbol: valuation.base_token_symbol,
+        fee,
+        liquidity_in_usd: valuation.liquidity_in_usd,
+        onchain_valuation_failed: valuation.price_unavailable,
+        ..Default::default()
+    }
+}
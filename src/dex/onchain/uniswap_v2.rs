@@ -0,0 +1,161 @@
+//! This module finds a token's Uniswap V2 pair by querying the Uniswap V2 factory on-chain.
+//!
+//! It is the fallback of the TheGraph lookup in [`crate::dex::thegraph::uniswap_v2`], for when the
+//! gateway is down or a brand-new pair is not indexed yet. The token is looked up against each base
+//! token (WETH, USDC, USDT, DAI), and the pair holding the most liquidity is returned.
+//!
+//! Every pair of the token, including drained ones, is also listed from the factory's `PairCreated`
+//! events, which give each pair's creation block. A pair that cannot be valued is left out rather
+//! than failing the lookup.
+
+use anyhow::Result;
+use ethers::contract::{parse_log, EthEvent};
+use ethers::types::{Address, Chain};
+
+use crate::abi::uniswap_factory_v2::{PairCreatedFilter, UNISWAP_V2_FACTORY};
+use crate::abi::uniswap_pair::UNISWAP_PAIR;
+use crate::data::chain_data::CHAIN_DATA;
+use crate::data::dex::Dex;
+use crate::data::provider_manager::get_chain_provider;
+use crate::dex::dex_data::TokenDexData;
+
+use super::shared::{get_base_tokens, get_pool_valuation, get_token_event_logs, new_dex_data};
+
+/// Fee of Uniswap V2 pairs (0.3%), in the same units as Uniswap V3 fees.
+pub const UNISWAP_V2_FEE: u32 = 3000;
+
+/// Finds the token's Uniswap V2 pair with the most liquidity, across the base tokens.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Option<TokenDexData>>` - The pair, `None` if the token has no pair with reserves.
+pub async fn get_top_uniswap_v2_pair_by_token_and_chain_onchain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Option<TokenDexData>> {
+    let client = get_chain_provider(chain).await?;
+    let factory = UNISWAP_V2_FACTORY::new(
+        CHAIN_DATA
+            .get_address(chain)
+            .uniswap_v2_factory
+            .parse::<Address>()?,
+        client.clone(),
+    );
+
+    let mut top_pair: Option<TokenDexData> = None;
+    for base_token_address in get_base_tokens(chain)? {
+        let pair_address = factory
+            .get_pair(token_address, base_token_address)
+            .call()
+            .await?;
+        if pair_address.is_zero() {
+            continue;
+        }
+
+        // A pair without reserves on both sides cannot be traded.
+        let (reserve_0, reserve_1, _) = UNISWAP_PAIR::new(pair_address, client.clone())
+            .get_reserves()
+            .call()
+            .await?;
+        if reserve_0 == 0 || reserve_1 == 0 {
+            continue;
+        }
+
+        let valuation =
+            match get_pool_valuation(pair_address, base_token_address, chain, &client).await {
+                Ok(valuation) => valuation,
+                Err(e) => {
+                    println!("could not value pair {:?}: {}", pair_address, e);
+                    continue;
+                }
+            };
+        let dex_data = new_dex_data(
+            Dex::UniswapV2,
+            pair_address,
+            token_address,
+            valuation,
+            UNISWAP_V2_FEE,
+        );
+
+        if top_pair
+            .as_ref()
+            .is_none_or(|top| dex_data.liquidity_in_usd > top.liquidity_in_usd)
+        {
+            top_pair = Some(dex_data);
+        }
+    }
+
+    Ok(top_pair)
+}
+
+/// Lists all of the token's Uniswap V2 pairs against the base tokens from the factory's
+/// `PairCreated` events, including drained pairs, with the block each pair was created at.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Vec<TokenDexData>>` - The pairs, in the order they were created.
+pub async fn get_uniswap_v2_pairs_by_token_and_chain_onchain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Vec<TokenDexData>> {
+    let client = get_chain_provider(chain).await?;
+    let addresses = CHAIN_DATA.get_address(chain);
+    let base_tokens = get_base_tokens(chain)?;
+
+    // The token is either token 0 (first topic) or token 1 (second topic) of the pair.
+    let pairs_created = get_token_event_logs(
+        addresses.uniswap_v2_factory.parse::<Address>()?,
+        PairCreatedFilter::signature(),
+        &[1, 2],
+        token_address,
+        addresses.uniswap_v2_factory_deploy_block,
+        chain,
+        &client,
+    )
+    .await?;
+
+    let mut pairs = Vec::new();
+    for log in pairs_created {
+        let block_number = log.block_number.unwrap_or_default().as_u64();
+        let pair: PairCreatedFilter = parse_log(log)?;
+        let base_token_address = if pair.token_0 == token_address {
+            pair.token_1
+        } else {
+            pair.token_0
+        };
+        if !base_tokens.contains(&base_token_address) {
+            continue;
+        }
+
+        let valuation =
+            match get_pool_valuation(pair.pair, base_token_address, chain, &client).await {
+                Ok(valuation) => valuation,
+                Err(e) => {
+                    println!("could not value pair {:?}: {}", pair.pair, e);
+                    continue;
+                }
+            };
+        let mut dex_data = new_dex_data(
+            Dex::UniswapV2,
+            pair.pair,
+            token_address,
+            valuation,
+            UNISWAP_V2_FEE,
+        );
+        dex_data.create_at_block_number = block_number;
+        pairs.push(dex_data);
+    }
+    pairs.sort_by_key(|pair| pair.create_at_block_number);
+
+    Ok(pairs)
+}
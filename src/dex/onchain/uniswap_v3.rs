@@ -0,0 +1,158 @@
+//! This module finds a token's Uniswap V3 pool by querying the Uniswap V3 factory on-chain.
+//!
+//! It is the fallback of the TheGraph lookup in [`crate::dex::thegraph::uniswap_v3`], for when the
+//! gateway is down or a brand-new pool is not indexed yet. The token is looked up against each base
+//! token (WETH, USDC, USDT, DAI) in every fee tier, and the pool holding the most liquidity is
+//! returned.
+//!
+//! Every pool of the token, including drained ones, is also listed from the factory's `PoolCreated`
+//! events, which give each pool's creation block. A pool that cannot be valued is left out rather
+//! than failing the lookup.
+
+use anyhow::Result;
+use ethers::contract::{parse_log, EthEvent};
+use ethers::types::{Address, Chain};
+
+use crate::abi::uniswap_pool::UNISWAP_V3_POOL;
+use crate::abi::uniswap_v3_factory::{PoolCreatedFilter, UNISWAP_V3_FACTORY};
+use crate::data::chain_data::CHAIN_DATA;
+use crate::data::dex::Dex;
+use crate::data::provider_manager::get_chain_provider;
+use crate::dex::dex_data::TokenDexData;
+use crate::token_check::anvil::base_token_route::UNISWAP_V3_FEE_TIERS;
+
+use super::shared::{get_base_tokens, get_pool_valuation, get_token_event_logs, new_dex_data};
+
+/// Finds the token's Uniswap V3 pool with the most liquidity, across the base tokens and fee tiers.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Option<TokenDexData>>` - The pool, `None` if the token has no pool with in-range
+///   liquidity.
+pub async fn get_top_uniswap_v3_pool_by_token_and_chain_onchain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Option<TokenDexData>> {
+    let client = get_chain_provider(chain).await?;
+    let factory = UNISWAP_V3_FACTORY::new(
+        CHAIN_DATA
+            .get_address(chain)
+            .uniswap_v3_factory
+            .parse::<Address>()?,
+        client.clone(),
+    );
+
+    let mut top_pool: Option<TokenDexData> = None;
+    for base_token_address in get_base_tokens(chain)? {
+        for fee in UNISWAP_V3_FEE_TIERS {
+            let pool_address = factory
+                .get_pool(token_address, base_token_address, fee)
+                .call()
+                .await?;
+            if pool_address.is_zero() {
+                continue;
+            }
+
+            // A pool without in-range liquidity cannot be traded at its current price.
+            let liquidity = UNISWAP_V3_POOL::new(pool_address, client.clone())
+                .liquidity()
+                .call()
+                .await?;
+            if liquidity == 0 {
+                continue;
+            }
+
+            let valuation =
+                match get_pool_valuation(pool_address, base_token_address, chain, &client).await {
+                    Ok(valuation) => valuation,
+                    Err(e) => {
+                        println!("could not value pool {:?}: {}", pool_address, e);
+                        continue;
+                    }
+                };
+            let dex_data =
+                new_dex_data(Dex::UniswapV3, pool_address, token_address, valuation, fee);
+
+            if top_pool
+                .as_ref()
+                .is_none_or(|top| dex_data.liquidity_in_usd > top.liquidity_in_usd)
+            {
+                top_pool = Some(dex_data);
+            }
+        }
+    }
+
+    Ok(top_pool)
+}
+
+/// Lists all of the token's Uniswap V3 pools against the base tokens from the factory's
+/// `PoolCreated` events, including drained pools, with the block each pool was created at.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Vec<TokenDexData>>` - The pools, in the order they were created.
+pub async fn get_uniswap_v3_pools_by_token_and_chain_onchain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Vec<TokenDexData>> {
+    let client = get_chain_provider(chain).await?;
+    let addresses = CHAIN_DATA.get_address(chain);
+    let base_tokens = get_base_tokens(chain)?;
+
+    // The token is either token 0 (first topic) or token 1 (second topic) of the pool.
+    let pools_created = get_token_event_logs(
+        addresses.uniswap_v3_factory.parse::<Address>()?,
+        PoolCreatedFilter::signature(),
+        &[1, 2],
+        token_address,
+        addresses.uniswap_v3_factory_deploy_block,
+        chain,
+        &client,
+    )
+    .await?;
+
+    let mut pools = Vec::new();
+    for log in pools_created {
+        let block_number = log.block_number.unwrap_or_default().as_u64();
+        let pool: PoolCreatedFilter = parse_log(log)?;
+        let base_token_address = if pool.token_0 == token_address {
+            pool.token_1
+        } else {
+            pool.token_0
+        };
+        if !base_tokens.contains(&base_token_address) {
+            continue;
+        }
+
+        let valuation =
+            match get_pool_valuation(pool.pool, base_token_address, chain, &client).await {
+                Ok(valuation) => valuation,
+                Err(e) => {
+                    println!("could not value pool {:?}: {}", pool.pool, e);
+                    continue;
+                }
+            };
+        let mut dex_data = new_dex_data(
+            Dex::UniswapV3,
+            pool.pool,
+            token_address,
+            valuation,
+            pool.fee,
+        );
+        dex_data.create_at_block_number = block_number;
+        pools.push(dex_data);
+    }
+    pools.sort_by_key(|pool| pool.create_at_block_number);
+
+    Ok(pools)
+}
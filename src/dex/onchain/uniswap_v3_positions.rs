@@ -0,0 +1,367 @@
+//! This module lists the position NFTs of a Uniswap V3 pool on-chain, with their owners.
+//!
+//! Uniswap V3 liquidity is not an ERC20 LP token: each position is an NFT of the
+//! NonfungiblePositionManager, holding liquidity between two ticks, and only the positions whose
+//! range contains the pool's current tick make up the pool's active liquidity. A locker (UNCX, Team
+//! Finance) holds the position NFT itself, so the lock status of the pool's liquidity is read from
+//! the owners of the in-range positions.
+//!
+//! Aerodrome Slipstream pools are Uniswap V3 forks with the same position manager and events, whose
+//! positions identify the pool by its tick spacing rather than its fee tier, so they are listed the
+//! same way.
+//!
+//! The positions are found from the pool's `Mint` events made by the position manager: the
+//! transaction of each mint also holds the position manager's `IncreaseLiquidity` event, which gives
+//! the position's token id. Both are queried over the same block windows and joined on the
+//! transaction hash.
+
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use ethers::providers::Middleware;
+use ethers::types::{Address, Chain, Filter, Log, H256, U256};
+use ethers::utils::keccak256;
+use futures::future::join_all;
+use log::warn;
+
+use crate::abi::slipstream_pool::SLIPSTREAM_POOL;
+use crate::abi::uniswap_pool::UNISWAP_V3_POOL;
+use crate::abi::uniswap_v3_position_manager::UNISWAP_V3_POSITION_MANAGER;
+use crate::app_config::{LOG_QUERY_BLOCK_WINDOW, POSITIONS_LOOKBACK_BLOCKS, POSITIONS_MAX_READ};
+use crate::data::chain_data::CHAIN_DATA;
+use crate::data::provider_manager::get_chain_provider;
+use crate::dex::dex_data::TokenDexData;
+use crate::token_check::check_token_lock::TokenHolders;
+use crate::utils::type_conversion::address_to_string;
+
+use super::shared::block_windows;
+
+/// Signature of the position manager's `IncreaseLiquidity` event, whose first topic is the token id.
+const INCREASE_LIQUIDITY_EVENT: &str = "IncreaseLiquidity(uint256,uint128,uint256,uint256)";
+
+/// A position NFT of a Uniswap V3 pool.
+///
+/// # Fields
+///
+/// * `token_id` - The position's NFT token id.
+/// * `owner` - The owner of the NFT, e.g. the wallet that added the liquidity or a locker.
+/// * `tick_lower` - The lower tick of the position's range.
+/// * `tick_upper` - The upper tick of the position's range.
+/// * `liquidity` - The position's liquidity.
+#[derive(Clone, Debug, Default)]
+pub struct UniswapV3Position {
+    pub token_id: U256,
+    pub owner: Address,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+}
+
+impl UniswapV3Position {
+    /// Returns `true` if the position's range contains the tick, i.e. the position's liquidity is
+    /// active at that price.
+    pub fn is_in_range(&self, tick: i32) -> bool {
+        self.tick_lower <= tick && tick < self.tick_upper
+    }
+}
+
+/// Returns the owners of the positions in range at the pool's current tick, one entry per position,
+/// with the position's liquidity as quantity. These quantities add up to at most the pool's active
+/// liquidity, the remainder being liquidity added without the position manager.
+///
+/// # Arguments
+///
+/// * `positions` - The pool's positions.
+/// * `current_tick` - The pool's current tick.
+pub fn in_range_position_holders(
+    positions: &[UniswapV3Position],
+    current_tick: i32,
+) -> Vec<TokenHolders> {
+    positions
+        .iter()
+        .filter(|position| position.liquidity > 0 && position.is_in_range(current_tick))
+        .map(|position| TokenHolders {
+            holder: address_to_string(position.owner),
+            quantity: U256::from(position.liquidity),
+        })
+        .collect()
+}
+
+/// Keeps the `max_read` positions to which the most liquidity was added, in the order they were
+/// first minted into. Ties keep the earliest position.
+///
+/// The liquidity added is read from the mints' `IncreaseLiquidity` events, so a large position that
+/// has since been withdrawn still ranks high, but the pool's main positions (e.g. the launch
+/// liquidity, often the one locked) are never dropped for a long tail of small ones.
+///
+/// # Arguments
+///
+/// * `liquidity_added` - Each position's token id and the liquidity added to it, in mint order.
+/// * `max_read` - The maximum number of positions to keep.
+///
+/// # Returns
+///
+/// * `Vec<U256>` - The token ids of the positions kept.
+pub fn most_liquid_token_ids(liquidity_added: &[(U256, U256)], max_read: usize) -> Vec<U256> {
+    let mut by_liquidity: Vec<usize> = (0..liquidity_added.len()).collect();
+    by_liquidity.sort_by(|a, b| liquidity_added[*b].1.cmp(&liquidity_added[*a].1));
+    by_liquidity.truncate(max_read);
+    by_liquidity.sort_unstable();
+
+    by_liquidity
+        .into_iter()
+        .map(|index| liquidity_added[index].0)
+        .collect()
+}
+
+/// Lists the position NFTs of a Uniswap V3 pool that still hold liquidity, as described in
+/// [`get_positions_onchain`].
+///
+/// # Arguments
+///
+/// * `dex_data` - The pool.
+/// * `chain` - The chain the pool is on.
+///
+/// # Returns
+///
+/// * `Result<Vec<UniswapV3Position>>` - The positions, in the order they were minted.
+pub async fn get_uniswap_v3_positions_onchain(
+    dex_data: &TokenDexData,
+    chain: &Chain,
+) -> Result<Vec<UniswapV3Position>> {
+    let position_manager_address: Address = CHAIN_DATA
+        .get_address(chain)
+        .uniswap_v3_position_manager
+        .parse()?;
+    get_positions_onchain(position_manager_address, dex_data, dex_data.fee, chain).await
+}
+
+/// Lists the position NFTs of a concentrated liquidity pool that still hold liquidity.
+///
+/// Works with Uniswap V3 style position managers, whose positions identify the pool by its tokens
+/// and `pool_key`: the fee tier for Uniswap V3, the tick spacing for Aerodrome Slipstream.
+///
+/// This asynchronous function performs the following steps:
+///
+/// 1. Picks the blocks to scan: from the block the pool was created at, or the last
+///    [`POSITIONS_LOOKBACK_BLOCKS`] if it is unknown.
+/// 2. Queries, in windows of [`LOG_QUERY_BLOCK_WINDOW`] blocks, the pool's `Mint` events whose owner
+///    is the position manager and the position manager's `IncreaseLiquidity` events.
+/// 3. Takes the token ids of the `IncreaseLiquidity` events emitted in the mints' transactions. If
+///    there are more than [`POSITIONS_MAX_READ`], logs it and keeps the ones to which the most
+///    liquidity was added, see [`most_liquid_token_ids`].
+/// 4. Reads the positions and the owners of their NFTs concurrently, skipping positions of other
+///    pools, positions without liquidity and positions that could not be read.
+///
+/// # Arguments
+///
+/// * `position_manager_address` - The pool's NonfungiblePositionManager.
+/// * `dex_data` - The pool.
+/// * `pool_key` - The fee tier or tick spacing the positions of the pool have.
+/// * `chain` - The chain the pool is on.
+///
+/// # Returns
+///
+/// * `Result<Vec<UniswapV3Position>>` - The positions, in the order they were minted.
+pub async fn get_positions_onchain(
+    position_manager_address: Address,
+    dex_data: &TokenDexData,
+    pool_key: u32,
+    chain: &Chain,
+) -> Result<Vec<UniswapV3Position>> {
+    let client = get_chain_provider(chain).await?;
+    let position_manager =
+        UNISWAP_V3_POSITION_MANAGER::new(position_manager_address, client.clone());
+    let pool = UNISWAP_V3_POOL::new(dex_data.pair_address, client.clone());
+
+    // Step 1: Pick the blocks to scan, never from the genesis block.
+    let latest_block = client.get_block_number().await?.as_u64();
+    let from_block = if dex_data.create_at_block_number > 0 {
+        dex_data.create_at_block_number
+    } else {
+        latest_block.saturating_sub(POSITIONS_LOOKBACK_BLOCKS)
+    };
+
+    // Step 2: Find the mints made through the position manager, and the liquidity it added.
+    let increase_liquidity_topic = H256::from(keccak256(INCREASE_LIQUIDITY_EVENT));
+    let mut mint_transactions: HashSet<H256> = HashSet::new();
+    let mut increase_liquidity_logs: Vec<Log> = Vec::new();
+    for (window_start, window_end) in
+        block_windows(from_block, latest_block, LOG_QUERY_BLOCK_WINDOW)
+    {
+        let mints = pool
+            .mint_filter()
+            .from_block(window_start)
+            .to_block(window_end)
+            .topic1(position_manager_address)
+            .query_with_meta()
+            .await?;
+        if mints.is_empty() {
+            continue;
+        }
+        mint_transactions.extend(mints.into_iter().map(|(_, meta)| meta.transaction_hash));
+
+        let filter = Filter::new()
+            .address(position_manager_address)
+            .topic0(increase_liquidity_topic)
+            .from_block(window_start)
+            .to_block(window_end);
+        increase_liquidity_logs.extend(client.get_logs(&filter).await?);
+    }
+
+    // Step 3: Join the two on the transaction hash. A transaction can mint into several positions,
+    // and a position can be minted into many times.
+    let mut liquidity_added: Vec<(U256, U256)> = Vec::new();
+    for log in increase_liquidity_logs {
+        if !log
+            .transaction_hash
+            .is_some_and(|transaction_hash| mint_transactions.contains(&transaction_hash))
+        {
+            continue;
+        }
+        if let Some(token_id) = log.topics.get(1) {
+            let token_id = U256::from(token_id.as_bytes());
+            // The event's data starts with the liquidity added.
+            let liquidity = log
+                .data
+                .get(..32)
+                .map(U256::from_big_endian)
+                .unwrap_or_default();
+            match liquidity_added.iter_mut().find(|(id, _)| *id == token_id) {
+                Some((_, total)) => *total = total.saturating_add(liquidity),
+                None => liquidity_added.push((token_id, liquidity)),
+            }
+        }
+    }
+    if liquidity_added.len() > POSITIONS_MAX_READ {
+        warn!(
+            "pool {:?} has {} positions, only reading the {} with the most liquidity added",
+            dex_data.pair_address,
+            liquidity_added.len(),
+            POSITIONS_MAX_READ
+        );
+    }
+    let token_ids = most_liquid_token_ids(&liquidity_added, POSITIONS_MAX_READ);
+
+    // Step 4: Read the positions and their owners.
+    let positions = join_all(token_ids.into_iter().map(|token_id| {
+        let position_manager = position_manager.clone();
+        async move {
+            let (
+                _,
+                _,
+                token_0,
+                token_1,
+                position_pool_key,
+                tick_lower,
+                tick_upper,
+                liquidity,
+                _,
+                _,
+                _,
+                _,
+            ) = position_manager
+                .positions(token_id)
+                .call()
+                .await
+                .map_err(|e| anyhow!("could not read position {}: {}", token_id, e))?;
+            if token_0 != dex_data.token_0
+                || token_1 != dex_data.token_1
+                || position_pool_key != pool_key
+                || liquidity == 0
+            {
+                return Ok::<_, anyhow::Error>(None);
+            }
+
+            let owner = position_manager
+                .owner_of(token_id)
+                .call()
+                .await
+                .map_err(|e| anyhow!("could not read owner of position {}: {}", token_id, e))?;
+            Ok(Some(UniswapV3Position {
+                token_id,
+                owner,
+                tick_lower,
+                tick_upper,
+                liquidity,
+            }))
+        }
+    }))
+    .await;
+
+    Ok(positions
+        .into_iter()
+        .filter_map(|position| match position {
+            Ok(position) => position,
+            Err(e) => {
+                println!("{}", e);
+                None
+            }
+        })
+        .collect())
+}
+
+/// Returns the owners of the pool's in-range positions, as described in
+/// [`in_range_position_holders`].
+///
+/// # Arguments
+///
+/// * `dex_data` - The pool.
+/// * `chain` - The chain the pool is on.
+///
+/// # Returns
+///
+/// * `Result<Vec<TokenHolders>>` - One entry per in-range position, with its liquidity.
+pub async fn get_uniswap_v3_position_holders_onchain(
+    dex_data: &TokenDexData,
+    chain: &Chain,
+) -> Result<Vec<TokenHolders>> {
+    let client = get_chain_provider(chain).await?;
+    let (_, current_tick, _, _, _, _, _) = UNISWAP_V3_POOL::new(dex_data.pair_address, client)
+        .slot_0()
+        .call()
+        .await?;
+
+    let positions = get_uniswap_v3_positions_onchain(dex_data, chain).await?;
+    Ok(in_range_position_holders(&positions, current_tick))
+}
+
+/// Returns the owners of an Aerodrome Slipstream pool's in-range positions, as described in
+/// [`in_range_position_holders`].
+///
+/// # Arguments
+///
+/// * `dex_data` - The pool.
+/// * `chain` - The chain the pool is on.
+///
+/// # Returns
+///
+/// * `Result<Vec<TokenHolders>>` - One entry per in-range position, with its liquidity. An error if
+///   Aerodrome is not deployed on the chain.
+pub async fn get_aerodrome_slipstream_position_holders_onchain(
+    dex_data: &TokenDexData,
+    chain: &Chain,
+) -> Result<Vec<TokenHolders>> {
+    let aerodrome = CHAIN_DATA
+        .get_address(chain)
+        .aerodrome
+        .as_ref()
+        .ok_or_else(|| anyhow!("Aerodrome is not deployed on {:?}", chain))?;
+    let position_manager_address: Address = aerodrome.slipstream_position_manager.parse()?;
+
+    // Slipstream's slot0 has no protocol fee, so it is not decoded with the Uniswap V3 pool.
+    let client = get_chain_provider(chain).await?;
+    let (_, current_tick, _, _, _, _) = SLIPSTREAM_POOL::new(dex_data.pair_address, client)
+        .slot_0()
+        .call()
+        .await?;
+
+    let positions = get_positions_onchain(
+        position_manager_address,
+        dex_data,
+        dex_data.tick_spacing as u32,
+        chain,
+    )
+    .await?;
+    Ok(in_range_position_holders(&positions, current_tick))
+}
@@ -0,0 +1,230 @@
+//! This module finds a token's Uniswap V4 pools from the pool manager's `Initialize` events.
+//!
+//! V4 has no factory to query: every pool lives in the singleton pool manager and is identified by
+//! the hash of its pool key (currencies, fee, tick spacing and hook contract), so the same pair can
+//! have any number of pools with different hooks. The pools initialized with the token against a base
+//! token (native ETH, WETH, USDC, USDT, DAI) are found from the events, valued from their in-range
+//! liquidity read through the `StateView` contract, and the deepest one is returned.
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use ethers::abi::{encode, Tokenizable};
+use ethers::contract::{parse_log, EthEvent};
+use ethers::providers::{Provider, Ws};
+use ethers::types::{Address, Chain, H256, U256};
+use ethers::utils::keccak256;
+
+use crate::abi::erc20::ERC20;
+use crate::abi::uniswap_v4_pool_manager::InitializeFilter;
+use crate::abi::uniswap_v4_quoter::PoolKey;
+use crate::abi::uniswap_v4_state_view::UNISWAP_V4_STATE_VIEW;
+use crate::data::chain_data::{UniswapV4Contracts, CHAIN_DATA};
+use crate::data::dex::Dex;
+use crate::data::provider_manager::get_chain_provider;
+use crate::dex::dex_data::{sort_token_dex_data_vec, TokenDexData};
+use crate::utils::type_conversion::address_to_string;
+
+use super::shared::{get_base_token_price_usd, get_base_tokens, get_token_event_logs};
+
+/// Flag set in a pool key's fee when the hook sets the LP fee dynamically.
+pub const UNISWAP_V4_DYNAMIC_FEE_FLAG: u32 = 0x800000;
+
+/// Returns the chain's Uniswap V4 contracts, or an error if Uniswap V4 is not deployed on it.
+pub fn uniswap_v4_contracts(chain: &Chain) -> Result<&'static UniswapV4Contracts> {
+    CHAIN_DATA
+        .get_address(chain)
+        .uniswap_v4
+        .as_ref()
+        .ok_or_else(|| anyhow!("Uniswap V4 is not deployed on {:?}", chain))
+}
+
+/// Builds the pool key of a V4 pool from its dex data.
+pub fn uniswap_v4_pool_key(dex_data: &TokenDexData) -> PoolKey {
+    PoolKey {
+        currency_0: dex_data.token_0,
+        currency_1: dex_data.token_1,
+        fee: dex_data.fee,
+        tick_spacing: dex_data.tick_spacing,
+        hooks: dex_data.hooks,
+    }
+}
+
+/// Computes a V4 pool's id, `keccak256(abi.encode(poolKey))`.
+pub fn uniswap_v4_pool_id(pool_key: &PoolKey) -> [u8; 32] {
+    keccak256(encode(&[pool_key.clone().into_token()]))
+}
+
+/// Finds the token's Uniswap V4 pool with the most liquidity, across pools paired with native ETH or
+/// one of the base tokens.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Option<TokenDexData>>` - The pool, `None` if the token has no pool or Uniswap V4 is not
+///   deployed on the chain.
+pub async fn get_top_uniswap_v4_pool_by_token_and_chain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Option<TokenDexData>> {
+    let pools = get_uniswap_v4_pools_by_token_and_chain(token_address, chain).await?;
+    Ok(sort_token_dex_data_vec(pools).into_iter().next())
+}
+
+/// Lists all of the token's Uniswap V4 pools paired with native ETH or one of the base tokens,
+/// including drained pools, with the block each pool was initialized at.
+///
+/// # Arguments
+///
+/// * `token_address` - The token to look up.
+/// * `chain` - The chain to search on.
+///
+/// # Returns
+///
+/// * `Result<Vec<TokenDexData>>` - The pools, in the order they were initialized, empty if Uniswap V4
+///   is not deployed on the chain. Pools whose state cannot be read are left out.
+pub async fn get_uniswap_v4_pools_by_token_and_chain(
+    token_address: Address,
+    chain: &Chain,
+) -> Result<Vec<TokenDexData>> {
+    let uniswap_v4 = match &CHAIN_DATA.get_address(chain).uniswap_v4 {
+        Some(uniswap_v4) => uniswap_v4,
+        None => return Ok(Vec::new()),
+    };
+    let client = get_chain_provider(chain).await?;
+    let pool_manager_address: Address = uniswap_v4.pool_manager.parse()?;
+
+    // Native ETH is the zero address in V4, and sorts first in every pool it is part of.
+    let mut base_tokens = vec![Address::zero()];
+    base_tokens.extend(get_base_tokens(chain)?);
+
+    // The token is either currency 0 (second topic) or currency 1 (third topic) of the pool.
+    let initialized = get_token_event_logs(
+        pool_manager_address,
+        InitializeFilter::signature(),
+        &[2, 3],
+        token_address,
+        uniswap_v4.pool_manager_deploy_block,
+        chain,
+        &client,
+    )
+    .await?;
+
+    let mut pools = Vec::new();
+    for log in initialized {
+        let block_number = log.block_number.unwrap_or_default().as_u64();
+        let pool: InitializeFilter = parse_log(log)?;
+        let is_token_0 = pool.currency_0 == token_address;
+        let base_token_address = if is_token_0 {
+            pool.currency_1
+        } else {
+            pool.currency_0
+        };
+        if !base_tokens.contains(&base_token_address) {
+            continue;
+        }
+
+        // A pool that cannot be read is left out, rather than failing the lookup of every pool.
+        let mut dex_data =
+            match get_uniswap_v4_pool_data(&pool, is_token_0, pool_manager_address, chain, &client)
+                .await
+            {
+                Ok(dex_data) => dex_data,
+                Err(e) => {
+                    println!(
+                        "could not read Uniswap V4 pool {:?}: {}",
+                        H256::from(pool.id),
+                        e
+                    );
+                    continue;
+                }
+            };
+        dex_data.create_at_block_number = block_number;
+        pools.push(dex_data);
+    }
+    pools.sort_by_key(|pool| pool.create_at_block_number);
+
+    Ok(pools)
+}
+
+/// Builds the dex data of a V4 pool from its `Initialize` event, valuing the pool's liquidity from
+/// the current price and in-range liquidity.
+///
+/// The liquidity is valued as twice the base token's virtual reserve `L / sqrt(P)` (currency 0) or
+/// `L * sqrt(P)` (currency 1): the depth of a full range position holding the pool's in-range
+/// liquidity. It is an estimate, as the actual balances of a V4 pool are pooled in the pool manager.
+/// A pool whose base token has no fresh Chainlink price is kept, valued at 0 and flagged, like the
+/// pools of the other venues.
+async fn get_uniswap_v4_pool_data(
+    pool: &InitializeFilter,
+    is_token_0: bool,
+    pool_manager_address: Address,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> Result<TokenDexData> {
+    let uniswap_v4 = uniswap_v4_contracts(chain)?;
+    let state_view =
+        UNISWAP_V4_STATE_VIEW::new(uniswap_v4.state_view.parse::<Address>()?, client.clone());
+
+    let (base_token_address, base_token_symbol, base_token_decimals) = if is_token_0 {
+        let (symbol, decimals) = get_currency_info(pool.currency_1, client).await?;
+        (pool.currency_1, symbol, decimals)
+    } else {
+        let (symbol, decimals) = get_currency_info(pool.currency_0, client).await?;
+        (pool.currency_0, symbol, decimals)
+    };
+
+    let (sqrt_price_x96, _, _, _) = state_view.get_slot_0(pool.id).call().await?;
+    let liquidity = state_view.get_liquidity(pool.id).call().await?;
+
+    let sqrt_price = u256_to_f64_lossy(sqrt_price_x96) / 2_f64.powi(96);
+    let base_token_virtual_reserve = if sqrt_price == 0.0 {
+        0.0
+    } else if is_token_0 {
+        // The base token is currency 1.
+        liquidity as f64 * sqrt_price
+    } else {
+        liquidity as f64 / sqrt_price
+    } / 10_f64.powi(base_token_decimals as i32);
+    let base_token_price = get_base_token_price_usd(&base_token_symbol, chain, client).await;
+
+    Ok(TokenDexData {
+        dex: Dex::UniswapV4,
+        pair_address: pool_manager_address,
+        token_0: pool.currency_0,
+        token_1: pool.currency_1,
+        is_token_0,
+        base_token_address: address_to_string(base_token_address),
+        base_token_symbol,
+        fee: pool.fee,
+        tick_spacing: pool.tick_spacing,
+        hooks: pool.hooks,
+        liquidity_in_usd: 2_f64 * base_token_virtual_reserve * base_token_price.unwrap_or_default(),
+        onchain_valuation_failed: base_token_price.is_none(),
+        ..Default::default()
+    })
+}
+
+/// Returns the symbol and decimals of a V4 currency, native ETH being the zero address.
+async fn get_currency_info(currency: Address, client: &Arc<Provider<Ws>>) -> Result<(String, u8)> {
+    if currency.is_zero() {
+        return Ok(("ETH".to_string(), 18));
+    }
+    let token = ERC20::new(currency, client.clone());
+    Ok((token.symbol().call().await?, token.decimals().call().await?))
+}
+
+/// Converts a `U256` to `f64`, losing precision beyond 53 bits. Unlike
+/// [`crate::utils::type_conversion::u256_to_f64`], it does not fail on values above `u128::MAX`, such
+/// as a `uint160` square root price.
+fn u256_to_f64_lossy(value: U256) -> f64 {
+    value
+        .0
+        .iter()
+        .rev()
+        .fold(0_f64, |total, limb| total * 2_f64.powi(64) + *limb as f64)
+}
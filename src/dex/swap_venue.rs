@@ -0,0 +1,135 @@
+//! This module defines the interface a DEX implements to be supported by the token check.
+//!
+//! Finding a token's pools, quoting, simulating buys and sells on the anvil fork, pulling liquidity,
+//! and reading the LP supply and holders all go through [`SwapVenue`], looked up from the pool's
+//! [`Dex`] with [`swap_venue`]. Supporting a new venue (a Uniswap V2 fork, SushiSwap, Aerodrome...)
+//! means implementing the trait, adding its contracts to `contracts.toml`, registering it in
+//! [`swap_venue`] and enabling it in `app_config::DEXES`.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use ethers::providers::{Provider, Ws};
+use ethers::types::{Address, Chain, U256};
+
+use crate::data::dex::Dex;
+use crate::data::token_data::ERC20Token;
+use crate::token_check::anvil::simlator::AnvilTestSimulator;
+use crate::token_check::anvil::token_tax::SimulatedSwap;
+use crate::token_check::check_token_lock::TokenHolders;
+
+use super::dex_data::TokenDexData;
+use super::venues::aerodrome::{AerodromeSlipstreamVenue, AerodromeVenue};
+use super::venues::uniswap_v2::UniswapV2Venue;
+use super::venues::uniswap_v3::UniswapV3Venue;
+use super::venues::uniswap_v4::UniswapV4Venue;
+
+/// A DEX the token can be found, priced and traded on.
+#[async_trait]
+pub trait SwapVenue: Send + Sync {
+    /// Finds the token's pair / pool with the most liquidity on this venue.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<TokenDexData>>` - The pool, `None` if the token has no pool on this venue.
+    async fn find_top_pool(
+        &self,
+        token_address: Address,
+        chain: &Chain,
+    ) -> Result<Option<TokenDexData>>;
+
+    /// Lists all of the token's pairs / pools on this venue, including drained ones, so the token's
+    /// liquidity can be compared across pools.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<TokenDexData>>` - The pools, with their creation block when the venue records it.
+    async fn find_pools(&self, token_address: Address, chain: &Chain) -> Result<Vec<TokenDexData>>;
+
+    /// Quotes swapping `amount_in` of `token_in` for `token_out` on the pool, without slippage.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<U256>` - The amount of `token_out` the swap would return.
+    async fn quote(
+        &self,
+        dex_data: &TokenDexData,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        chain: &Chain,
+        client: &Arc<Provider<Ws>>,
+    ) -> Result<U256>;
+
+    /// Buys the token with `eth_amount_in` of ETH on the anvil fork.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SimulatedSwap>` - The outcome of the purchase.
+    async fn buy(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+    ) -> Result<SimulatedSwap>;
+
+    /// Sells `amount_to_sell` of the token on the anvil fork.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SimulatedSwap>` - The outcome of the sale.
+    async fn sell(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount_to_sell: U256,
+    ) -> Result<SimulatedSwap>;
+
+    /// Removes all of an impersonated LP holder's liquidity from the pool on the anvil fork.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - Whether any liquidity was removed.
+    async fn remove_liquidity(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holder: Address,
+    ) -> Result<bool>;
+
+    /// Returns `true` if the pool's liquidity is an ERC20 LP token (e.g. a Uniswap V2 pair), which
+    /// can be burned or locked in a token locker, rather than position NFTs or pool manager state.
+    fn has_erc20_lp_token(&self) -> bool;
+
+    /// Returns the pool's total liquidity: the LP token supply, or the active liquidity for pools
+    /// without LP tokens.
+    async fn lp_supply(&self, dex_data: &TokenDexData, client: &Arc<Provider<Ws>>) -> Result<U256>;
+
+    /// Returns the holders of the pool's liquidity.
+    async fn lp_holders(&self, dex_data: &TokenDexData, chain: &Chain)
+        -> Result<Vec<TokenHolders>>;
+}
+
+/// Returns the venue implementation for a DEX.
+///
+/// # Arguments
+///
+/// * `dex` - The DEX a pool is on.
+///
+/// # Returns
+///
+/// * `Option<&'static dyn SwapVenue>` - The venue, `None` if the DEX is not supported yet.
+pub fn swap_venue(dex: &Dex) -> Option<&'static dyn SwapVenue> {
+    match dex {
+        Dex::UniswapV2 => Some(&UniswapV2Venue),
+        Dex::UniswapV3 => Some(&UniswapV3Venue),
+        Dex::UniswapV4 => Some(&UniswapV4Venue),
+        Dex::Aerodrome => Some(&AerodromeVenue),
+        Dex::AerodromeSlipstream => Some(&AerodromeSlipstreamVenue),
+        _ => None,
+    }
+}
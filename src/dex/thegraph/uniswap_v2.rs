@@ -146,8 +146,10 @@ pub async fn get_top_uniswap_v2_pair_for_token(
         base_token_address,
         base_token_symbol,
         liquidity_in_usd,
+        subgraph_liquidity_in_usd: Some(liquidity_in_usd),
         fee: 3000, // Uniswap V2 has a fixed 0.3% fee (represented as 3000 basis points)
         create_at_block_number,
+        ..Default::default()
     }))
 }
 
@@ -146,8 +146,10 @@ pub async fn get_top_uniswap_v3_pool_for_token(
         base_token_address,
         base_token_symbol,
         liquidity_in_usd,
+        subgraph_liquidity_in_usd: Some(liquidity_in_usd),
         fee,
         create_at_block_number,
+        ..Default::default()
     }))
 }
 
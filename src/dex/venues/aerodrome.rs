@@ -0,0 +1,220 @@
+//! Aerodrome as a [`SwapVenue`]: pools are found on-chain through the Aerodrome factories. Volatile
+//! and stable pools are traded through the Aerodrome router and are their own ERC20 LP token, while
+//! Slipstream concentrated liquidity pools are traded through the Slipstream swap router and their
+//! positions managed through the Slipstream position manager, like Uniswap V3.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use ethers::providers::{Provider, Ws};
+use ethers::types::{Address, Chain, U256};
+
+use crate::data::token_data::ERC20Token;
+use crate::dex::dex_data::TokenDexData;
+use crate::dex::onchain::aerodrome::{
+    get_aerodrome_pools_by_token_and_chain, get_aerodrome_slipstream_pools_by_token_and_chain,
+    get_top_aerodrome_pool_by_token_and_chain,
+    get_top_aerodrome_slipstream_pool_by_token_and_chain,
+};
+use crate::dex::onchain::uniswap_v3_positions::get_aerodrome_slipstream_position_holders_onchain;
+use crate::dex::swap_venue::SwapVenue;
+use crate::token_check::anvil::simlator::AnvilTestSimulator;
+use crate::token_check::anvil::token_tax::SimulatedSwap;
+use crate::token_check::check_token_lock::TokenHolders;
+use crate::token_check::external_api::moralis::get_token_holder_list;
+use crate::token_check::token_methods::{
+    get_total_liquidity_token_supply_uniswap_v2, get_total_liquidity_token_supply_uniswap_v3,
+};
+use crate::utils::tx::{get_amount_out_aerodrome, get_amount_out_aerodrome_slipstream, TxSlippage};
+use crate::utils::type_conversion::address_to_string;
+
+/// Aerodrome volatile and stable pools.
+pub struct AerodromeVenue;
+
+/// Aerodrome Slipstream concentrated liquidity pools.
+pub struct AerodromeSlipstreamVenue;
+
+#[async_trait]
+impl SwapVenue for AerodromeVenue {
+    async fn find_top_pool(
+        &self,
+        token_address: Address,
+        chain: &Chain,
+    ) -> Result<Option<TokenDexData>> {
+        get_top_aerodrome_pool_by_token_and_chain(token_address, chain).await
+    }
+
+    async fn find_pools(&self, token_address: Address, chain: &Chain) -> Result<Vec<TokenDexData>> {
+        get_aerodrome_pools_by_token_and_chain(token_address, chain).await
+    }
+
+    async fn quote(
+        &self,
+        dex_data: &TokenDexData,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        chain: &Chain,
+        client: &Arc<Provider<Ws>>,
+    ) -> Result<U256> {
+        get_amount_out_aerodrome(
+            token_in,
+            token_out,
+            dex_data.is_stable,
+            amount_in,
+            TxSlippage::None,
+            chain,
+            client,
+        )
+        .await
+    }
+
+    async fn buy(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+    ) -> Result<SimulatedSwap> {
+        anvil
+            .simulate_buying_token_on_aerodrome_for_weth(dex_data, token, eth_amount_in)
+            .await
+    }
+
+    async fn sell(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount_to_sell: U256,
+    ) -> Result<SimulatedSwap> {
+        anvil
+            .simulate_selling_token_on_aerodrome_for_weth(dex_data, token, amount_to_sell)
+            .await
+    }
+
+    async fn remove_liquidity(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holder: Address,
+    ) -> Result<bool> {
+        anvil
+            .remove_liquidity_aerodrome(dex_data, token, lp_holder)
+            .await
+    }
+
+    fn has_erc20_lp_token(&self) -> bool {
+        true
+    }
+
+    async fn lp_supply(&self, dex_data: &TokenDexData, client: &Arc<Provider<Ws>>) -> Result<U256> {
+        // The pool is an ERC20 LP token, like a Uniswap V2 pair.
+        get_total_liquidity_token_supply_uniswap_v2(dex_data, client).await
+    }
+
+    async fn lp_holders(
+        &self,
+        dex_data: &TokenDexData,
+        chain: &Chain,
+    ) -> Result<Vec<TokenHolders>> {
+        get_token_holder_list(&address_to_string(dex_data.pair_address), chain).await
+    }
+}
+
+#[async_trait]
+impl SwapVenue for AerodromeSlipstreamVenue {
+    async fn find_top_pool(
+        &self,
+        token_address: Address,
+        chain: &Chain,
+    ) -> Result<Option<TokenDexData>> {
+        get_top_aerodrome_slipstream_pool_by_token_and_chain(token_address, chain).await
+    }
+
+    async fn find_pools(&self, token_address: Address, chain: &Chain) -> Result<Vec<TokenDexData>> {
+        get_aerodrome_slipstream_pools_by_token_and_chain(token_address, chain).await
+    }
+
+    async fn quote(
+        &self,
+        dex_data: &TokenDexData,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        chain: &Chain,
+        client: &Arc<Provider<Ws>>,
+    ) -> Result<U256> {
+        get_amount_out_aerodrome_slipstream(
+            token_in,
+            token_out,
+            dex_data.tick_spacing,
+            amount_in,
+            TxSlippage::None,
+            chain,
+            client,
+        )
+        .await
+    }
+
+    async fn buy(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+    ) -> Result<SimulatedSwap> {
+        anvil
+            .simulate_buying_token_on_aerodrome_slipstream_for_weth(dex_data, token, eth_amount_in)
+            .await
+    }
+
+    async fn sell(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount_to_sell: U256,
+    ) -> Result<SimulatedSwap> {
+        anvil
+            .simulate_selling_token_on_aerodrome_slipstream_for_weth(
+                dex_data,
+                token,
+                amount_to_sell,
+            )
+            .await
+    }
+
+    async fn remove_liquidity(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holder: Address,
+    ) -> Result<bool> {
+        anvil
+            .remove_liquidity_aerodrome_slipstream(dex_data, token, lp_holder)
+            .await
+    }
+
+    fn has_erc20_lp_token(&self) -> bool {
+        false
+    }
+
+    async fn lp_supply(&self, dex_data: &TokenDexData, client: &Arc<Provider<Ws>>) -> Result<U256> {
+        // Slipstream pools expose their active liquidity like Uniswap V3 pools.
+        get_total_liquidity_token_supply_uniswap_v3(dex_data, client).await
+    }
+
+    /// Slipstream liquidity is held in position NFTs, like Uniswap V3: the holders are the owners of
+    /// the in-range positions, read on-chain from the Slipstream position manager.
+    async fn lp_holders(
+        &self,
+        dex_data: &TokenDexData,
+        chain: &Chain,
+    ) -> Result<Vec<TokenHolders>> {
+        get_aerodrome_slipstream_position_holders_onchain(dex_data, chain).await
+    }
+}
@@ -0,0 +1,133 @@
+//! Uniswap V3 as a [`SwapVenue`]: pools are found on TheGraph, or on the factory when TheGraph has no
+//! pool, quoted with the quoter, traded through the swap router and their positions managed through
+//! the position manager.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use ethers::providers::{Provider, Ws};
+use ethers::types::{Address, Chain, U256};
+
+use crate::data::token_data::ERC20Token;
+use crate::dex::dex_data::TokenDexData;
+use crate::dex::onchain::shared::value_subgraph_pool_onchain;
+use crate::dex::onchain::uniswap_v3::{
+    get_top_uniswap_v3_pool_by_token_and_chain_onchain,
+    get_uniswap_v3_pools_by_token_and_chain_onchain,
+};
+use crate::dex::onchain::uniswap_v3_positions::get_uniswap_v3_position_holders_onchain;
+use crate::dex::swap_venue::SwapVenue;
+use crate::dex::thegraph::uniswap_v3::get_top_uniswap_v3_pool_by_token_and_chain;
+use crate::token_check::anvil::simlator::AnvilTestSimulator;
+use crate::token_check::anvil::token_tax::SimulatedSwap;
+use crate::token_check::check_token_lock::TokenHolders;
+use crate::token_check::token_methods::get_total_liquidity_token_supply_uniswap_v3;
+use crate::utils::tx::{get_amount_out_uniswap_v3, TxSlippage};
+
+/// Uniswap V3 pools.
+pub struct UniswapV3Venue;
+
+#[async_trait]
+impl SwapVenue for UniswapV3Venue {
+    async fn find_top_pool(
+        &self,
+        token_address: Address,
+        chain: &Chain,
+    ) -> Result<Option<TokenDexData>> {
+        // TheGraph can be down, or not have indexed a brand-new pool yet: read the factory instead.
+        match get_top_uniswap_v3_pool_by_token_and_chain(token_address, chain).await {
+            Ok(Some(dex_data)) => Ok(Some(value_subgraph_pool_onchain(dex_data, chain).await?)),
+            Ok(None) => {
+                get_top_uniswap_v3_pool_by_token_and_chain_onchain(token_address, chain).await
+            }
+            Err(e) => {
+                println!(
+                    "TheGraph Uniswap V3 lookup failed, querying the factory on-chain: {}",
+                    e
+                );
+                get_top_uniswap_v3_pool_by_token_and_chain_onchain(token_address, chain).await
+            }
+        }
+    }
+
+    async fn find_pools(&self, token_address: Address, chain: &Chain) -> Result<Vec<TokenDexData>> {
+        get_uniswap_v3_pools_by_token_and_chain_onchain(token_address, chain).await
+    }
+
+    async fn quote(
+        &self,
+        dex_data: &TokenDexData,
+        token_in: Address,
+        token_out: Address,
+        amount_in: U256,
+        chain: &Chain,
+        client: &Arc<Provider<Ws>>,
+    ) -> Result<U256> {
+        get_amount_out_uniswap_v3(
+            token_in,
+            token_out,
+            dex_data.fee,
+            amount_in,
+            TxSlippage::None,
+            chain,
+            client,
+        )
+        .await
+    }
+
+    async fn buy(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+    ) -> Result<SimulatedSwap> {
+        anvil
+            .simulate_buying_token_on_uniswap_v3_for_weth(dex_data, token, eth_amount_in)
+            .await
+    }
+
+    async fn sell(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount_to_sell: U256,
+    ) -> Result<SimulatedSwap> {
+        anvil
+            .simulate_selling_token_on_uniswap_v3_for_weth(dex_data, token, amount_to_sell)
+            .await
+    }
+
+    async fn remove_liquidity(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holder: Address,
+    ) -> Result<bool> {
+        anvil
+            .remove_liquidity_uniswap_v3(dex_data, token, lp_holder)
+            .await
+    }
+
+    fn has_erc20_lp_token(&self) -> bool {
+        false
+    }
+
+    async fn lp_supply(&self, dex_data: &TokenDexData, client: &Arc<Provider<Ws>>) -> Result<U256> {
+        get_total_liquidity_token_supply_uniswap_v3(dex_data, client).await
+    }
+
+    /// Holders are the owners of the pool's position NFTs in range at the current tick, read
+    /// on-chain, with each position's liquidity: they add up to at most [`Self::lp_supply`], the
+    /// pool's active liquidity.
+    async fn lp_holders(
+        &self,
+        dex_data: &TokenDexData,
+        chain: &Chain,
+    ) -> Result<Vec<TokenHolders>> {
+        get_uniswap_v3_position_holders_onchain(dex_data, chain).await
+    }
+}
@@ -0,0 +1,127 @@
+//! Uniswap V4 as a [`SwapVenue`]: pools are found from the pool manager's `Initialize` events, quoted
+//! with the V4 quoter and traded through the Universal Router. Liquidity positions are not indexed by
+//! any of the APIs used, so the LP holder and rug pull checks do not cover V4 pools yet.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use ethers::providers::{Middleware, Provider, Ws};
+use ethers::types::{Address, Chain, U256};
+
+use crate::abi::uniswap_v4_state_view::UNISWAP_V4_STATE_VIEW;
+use crate::data::token_data::ERC20Token;
+use crate::dex::dex_data::TokenDexData;
+use crate::dex::onchain::uniswap_v4::{
+    get_top_uniswap_v4_pool_by_token_and_chain, get_uniswap_v4_pools_by_token_and_chain,
+    uniswap_v4_contracts, uniswap_v4_pool_id, uniswap_v4_pool_key,
+};
+use crate::dex::swap_venue::SwapVenue;
+use crate::token_check::anvil::simlator::AnvilTestSimulator;
+use crate::token_check::anvil::token_tax::SimulatedSwap;
+use crate::token_check::check_token_lock::TokenHolders;
+use crate::utils::tx::{get_amount_out_uniswap_v4, TxSlippage};
+
+/// Uniswap V4 pools.
+pub struct UniswapV4Venue;
+
+#[async_trait]
+impl SwapVenue for UniswapV4Venue {
+    async fn find_top_pool(
+        &self,
+        token_address: Address,
+        chain: &Chain,
+    ) -> Result<Option<TokenDexData>> {
+        get_top_uniswap_v4_pool_by_token_and_chain(token_address, chain).await
+    }
+
+    async fn find_pools(&self, token_address: Address, chain: &Chain) -> Result<Vec<TokenDexData>> {
+        get_uniswap_v4_pools_by_token_and_chain(token_address, chain).await
+    }
+
+    async fn quote(
+        &self,
+        dex_data: &TokenDexData,
+        token_in: Address,
+        _token_out: Address,
+        amount_in: U256,
+        chain: &Chain,
+        client: &Arc<Provider<Ws>>,
+    ) -> Result<U256> {
+        get_amount_out_uniswap_v4(
+            uniswap_v4_pool_key(dex_data),
+            token_in,
+            amount_in,
+            TxSlippage::None,
+            chain,
+            client,
+        )
+        .await
+    }
+
+    async fn buy(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+    ) -> Result<SimulatedSwap> {
+        anvil
+            .simulate_buying_token_on_uniswap_v4_for_eth(dex_data, token, eth_amount_in)
+            .await
+    }
+
+    async fn sell(
+        &self,
+        anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount_to_sell: U256,
+    ) -> Result<SimulatedSwap> {
+        anvil
+            .simulate_selling_token_on_uniswap_v4_for_eth(dex_data, token, amount_to_sell)
+            .await
+    }
+
+    /// Removing V4 liquidity needs the holder's position ids, which are not indexed, so nothing is
+    /// removed.
+    async fn remove_liquidity(
+        &self,
+        _anvil: &AnvilTestSimulator,
+        dex_data: &TokenDexData,
+        _token: &ERC20Token,
+        lp_holder: Address,
+    ) -> Result<bool> {
+        println!(
+            "removing liquidity of {:?} not supported on {:?}",
+            lp_holder, dex_data.dex
+        );
+        Ok(false)
+    }
+
+    fn has_erc20_lp_token(&self) -> bool {
+        false
+    }
+
+    async fn lp_supply(&self, dex_data: &TokenDexData, client: &Arc<Provider<Ws>>) -> Result<U256> {
+        // Like Uniswap V3, the pool's active liquidity stands in for an LP token supply.
+        // The state view is per chain, read it on the chain the client is connected to.
+        let chain = Chain::try_from(client.get_chainid().await?.as_u64())?;
+        let uniswap_v4 = uniswap_v4_contracts(&chain)?;
+        let state_view =
+            UNISWAP_V4_STATE_VIEW::new(uniswap_v4.state_view.parse::<Address>()?, client.clone());
+        let pool_id = uniswap_v4_pool_id(&uniswap_v4_pool_key(dex_data));
+        let liquidity = state_view.get_liquidity(pool_id).call().await?;
+
+        Ok(U256::from(liquidity))
+    }
+
+    /// V4 positions are not indexed by any of the APIs used, so no holders are returned.
+    async fn lp_holders(
+        &self,
+        _dex_data: &TokenDexData,
+        _chain: &Chain,
+    ) -> Result<Vec<TokenHolders>> {
+        Ok(Vec::new())
+    }
+}
@@ -3,12 +3,27 @@
 
 pub mod env_config;
 pub mod dex {
+    pub mod onchain {
+        pub mod aerodrome;
+        pub mod shared;
+        pub mod uniswap_v2;
+        pub mod uniswap_v3;
+        pub mod uniswap_v3_positions;
+        pub mod uniswap_v4;
+    }
     pub mod thegraph {
         pub mod shared;
         pub mod uniswap_v2;
         pub mod uniswap_v3;
     }
+    pub mod venues {
+        pub mod aerodrome;
+        pub mod uniswap_v2;
+        pub mod uniswap_v3;
+        pub mod uniswap_v4;
+    }
     pub mod dex_data;
+    pub mod swap_venue;
 }
 
 pub mod chainlink {
@@ -17,18 +32,39 @@ pub mod chainlink {
 }
 
 pub mod abi {
+    pub mod access_control;
+    pub mod aerodrome_pool_factory;
+    pub mod aerodrome_router;
     pub mod chainlink_aggregator;
     pub mod erc20;
+    pub mod gnosis_safe;
+    pub mod ownable;
+    pub mod permit2;
+    pub mod pinklock;
+    pub mod slipstream_factory;
+    pub mod slipstream_pool;
+    pub mod slipstream_quoter;
+    pub mod slipstream_router;
+    pub mod team_finance_locker;
+    pub mod timelock_controller;
+    pub mod uncx_locker;
     pub mod uniswap_factory_v2;
     pub mod uniswap_pair;
     pub mod uniswap_pool;
     pub mod uniswap_quoter;
     pub mod uniswap_router_v2;
     pub mod uniswap_v3_factory;
+    pub mod uniswap_v3_position_manager;
     pub mod uniswap_v3_router;
+    pub mod uniswap_v4_pool_manager;
+    pub mod uniswap_v4_quoter;
+    pub mod uniswap_v4_state_view;
+    pub mod universal_router;
+    pub mod upgradeable_proxy;
 }
 
 pub mod data {
+    pub mod address_labels;
     pub mod chain_data;
     pub mod dex;
     pub mod provider_manager;
@@ -95,11 +131,19 @@ pub mod token_check {
         pub mod structs;
     }
     pub mod check_token_lock;
+    pub mod contract_source;
     pub mod main_token_check;
     pub mod token_checklist;
+    pub mod token_deployer_check;
+    pub mod token_depth_check;
     pub mod token_holder_check;
+    pub mod token_hook_check;
     pub mod token_liquidity_check;
+    pub mod token_lock_expiry_check;
     pub mod token_methods;
+    pub mod token_ownership_check;
+    pub mod token_pool_check;
+    pub mod token_proxy_check;
     pub mod token_score;
     pub mod external_api {
         pub mod etherscan_api;
@@ -111,10 +155,21 @@ pub mod token_check {
         }
     }
     pub mod anvil {
+        pub mod anvil_pool;
+        pub mod base_token_route;
+        pub mod buy_sell_aerodrome;
         pub mod buy_sell_uniswap_v2;
         pub mod buy_sell_uniswap_v3;
+        pub mod buy_sell_uniswap_v4;
+        pub mod fee_receivers;
+        pub mod owner_privileges;
+        pub mod rug_pull;
         pub mod simlator;
         pub mod supporting_methods;
+        pub mod time_travel;
+        pub mod token_tax;
+        pub mod trace_report;
+        pub mod trade_limits;
         pub mod tx_trait;
         pub mod validation;
     }
@@ -141,6 +141,18 @@ pub struct TokenCodeCheck {
     pub reason_could_be_legitimate_or_not: String,
 }
 
+/// Represents a review of a Uniswap V4 hook contract's code.
+///
+/// The expected JSON payload states whether the hook could trap or rob the pool's traders or
+/// liquidity providers, and why.
+#[derive(Deserialize, Clone, Debug)]
+pub struct HookCodeCheck {
+    /// Indicates if the hook might be malicious.
+    pub possible_malicious_hook: bool,
+    /// Explanation for the assessment.
+    pub reason: String,
+}
+
 /// Represents a token check for website content.
 ///
 /// Contains indicators and summarizing information for assessing potential scams on a website.
@@ -6,13 +6,13 @@ use reqwest::{Client, Response};
 use serde::de::DeserializeOwned;
 
 use crate::{
-    app_config::{CODE_CHECK_PROMPT, WEBSITE_CHECK_PROMPT},
+    app_config::{CODE_CHECK_PROMPT, HOOK_CHECK_PROMPT, WEBSITE_CHECK_PROMPT},
     utils::type_conversion::truncate_code_unicode,
 };
 
 use super::ai_structs::{
-    AiChatCompletion, AiErrorResponse, ChatCompletionRequest, HasContent, MessageToSend,
-    PromptType, TokenCodeCheck, TokenWebsiteCheck,
+    AiChatCompletion, AiErrorResponse, ChatCompletionRequest, HasContent, HookCodeCheck,
+    MessageToSend, PromptType, TokenCodeCheck, TokenWebsiteCheck,
 };
 
 use crate::token_check::{
@@ -78,6 +78,35 @@ pub async fn check_code_with_ai(
     Ok(code_check)
 }
 
+/// Checks the code of a Uniswap V4 hook contract using the specified AI model.
+///
+/// Works like [`check_code_with_ai`], with a prompt focused on what the hook can do to the pool's
+/// swaps and liquidity.
+///
+/// # Arguments
+///
+/// * `code` - The Solidity code of the hook.
+/// * `ai_model` - The AI model to use for checking, either `AIModel::OpenAi` or `AIModel::DeepSeek`.
+///
+/// # Returns
+///
+/// * `anyhow::Result<Option<HookCodeCheck>>` - The result containing hook check information, if any.
+pub async fn check_hook_code_with_ai(
+    code: String,
+    ai_model: &AIModel,
+) -> anyhow::Result<Option<HookCodeCheck>> {
+    let truncated_code = truncate_code_unicode(&code, 115_000);
+
+    let hook_chat = AIChat {
+        prompt_instructions: HOOK_CHECK_PROMPT.to_string(),
+        ai_persona: "You are a solidity security expert and Uniswap V4 hook auditor.".to_string(),
+        prompt_content_to_review: truncated_code,
+        prompt_type: PromptType::Code,
+    };
+
+    chat_submission::<HookCodeCheck>(hook_chat, ai_model).await
+}
+
 /// Checks the provided website content using the specified AI model.
 ///
 /// This function creates a chat request using a website review prompt,
@@ -0,0 +1,222 @@
+//! This module keeps a pool of warm forked Anvil instances per chain, so an audit does not have to
+//! spawn (and wait for) a brand-new `anvil --fork` process.
+//!
+//! Each instance takes an `evm_snapshot` of its clean state right after it is forked and funded. When
+//! an audit is done the instance is reverted to that snapshot, any account the audit impersonated is
+//! no longer impersonated, and the instance is returned to the pool. Instances
+//! forked longer than [`ANVIL_FORK_MAX_AGE_SECONDS`] ago are moved to the latest block with
+//! `anvil_reset` before they are handed out again. The number of instances in use per chain is capped
+//! at [`ANVIL_POOL_MAX_INSTANCES_PER_CHAIN`], and instances that fail to revert or reset are dropped,
+//! which kills the underlying process.
+
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use ethers::types::{Address, Chain, U256};
+use log::error;
+use once_cell::sync::Lazy;
+use serde_json::json;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+
+use crate::app_config::{ANVIL_FORK_MAX_AGE_SECONDS, ANVIL_POOL_MAX_INSTANCES_PER_CHAIN, CHAINS};
+use crate::data::chain_data::CHAIN_DATA;
+use crate::env_config::Config;
+
+use super::simlator::AnvilTestSimulator;
+
+/// The global pool of forked Anvil instances.
+pub static ANVIL_POOL: Lazy<AnvilPool> = Lazy::new(AnvilPool::new);
+
+/// A forked Anvil instance together with the snapshot of its clean state.
+struct PooledFork {
+    simulator: AnvilTestSimulator,
+    clean_snapshot: U256,
+    forked_at: Instant,
+}
+
+/// Pool of warm forked Anvil instances, keyed by chain.
+pub struct AnvilPool {
+    idle: Mutex<HashMap<Chain, Vec<PooledFork>>>,
+    permits: HashMap<Chain, Arc<Semaphore>>,
+}
+
+/// An Anvil instance checked out of the pool. Dereferences to the [`AnvilTestSimulator`].
+///
+/// Hand it back with [`AnvilPool::release`] once the audit is done. A lease that is dropped instead
+/// is not reused, its Anvil process is killed.
+pub struct AnvilLease {
+    fork: PooledFork,
+    chain: Chain,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl Deref for AnvilLease {
+    type Target = AnvilTestSimulator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.fork.simulator
+    }
+}
+
+impl Default for AnvilPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnvilPool {
+    /// Creates an empty pool for every supported chain.
+    pub fn new() -> Self {
+        let permits = CHAINS
+            .iter()
+            .map(|chain| {
+                (
+                    *chain,
+                    Arc::new(Semaphore::new(ANVIL_POOL_MAX_INSTANCES_PER_CHAIN)),
+                )
+            })
+            .collect();
+
+        Self {
+            idle: Mutex::new(HashMap::new()),
+            permits,
+        }
+    }
+
+    /// Checks out a clean forked Anvil instance for the chain.
+    ///
+    /// This function performs the following steps:
+    /// 1. Waits until fewer than [`ANVIL_POOL_MAX_INSTANCES_PER_CHAIN`] instances are in use.
+    /// 2. Takes an idle instance from the pool, resetting it to the latest block if it is stale.
+    /// 3. Spawns a new instance if none is idle, or the stale one could not be reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - The chain to fork.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<AnvilLease>` - The checked out instance, or an error if the chain is not supported
+    ///   or a new instance could not be spawned.
+    pub async fn checkout(&self, chain: &Chain) -> Result<AnvilLease> {
+        let permit = self
+            .permits
+            .get(chain)
+            .ok_or_else(|| anyhow!("No anvil pool for chain {:?}", chain))?
+            .clone()
+            .acquire_owned()
+            .await?;
+
+        let idle_fork = self
+            .idle
+            .lock()
+            .await
+            .get_mut(chain)
+            .and_then(|forks| forks.pop());
+
+        let fork = match idle_fork {
+            Some(fork)
+                if fork.forked_at.elapsed() < Duration::from_secs(ANVIL_FORK_MAX_AGE_SECONDS) =>
+            {
+                fork
+            }
+            Some(fork) => match reset_fork(fork, chain).await {
+                Ok(fork) => fork,
+                Err(e) => {
+                    error!("Failed to reset anvil fork, spawning a new one: {}", e);
+                    spawn_fork(chain).await?
+                }
+            },
+            None => spawn_fork(chain).await?,
+        };
+
+        Ok(AnvilLease {
+            fork,
+            chain: *chain,
+            _permit: permit,
+        })
+    }
+
+    /// Returns a checked out instance to the pool, reverting it to its clean state and stopping the
+    /// impersonation of any account the audit left impersonated.
+    ///
+    /// If the instance cannot be reverted it is dropped instead, killing its Anvil process.
+    ///
+    /// # Arguments
+    ///
+    /// * `lease` - The instance returned by [`AnvilPool::checkout`].
+    pub async fn release(&self, lease: AnvilLease) {
+        let AnvilLease {
+            mut fork, chain, ..
+        } = lease;
+
+        // Reverting consumes the snapshot, so a new one is taken of the (same) clean state.
+        // Impersonation is not part of the snapshot, so it is stopped separately.
+        let reverted = async {
+            fork.simulator
+                .revert_to_snapshot(fork.clean_snapshot)
+                .await?;
+            let impersonated_accounts: Vec<Address> = fork
+                .simulator
+                .impersonated_accounts
+                .lock()
+                .map(|accounts| accounts.iter().copied().collect())
+                .unwrap_or_default();
+            for account in impersonated_accounts {
+                fork.simulator.stop_impersonating_account(account).await?;
+            }
+            fork.simulator.snapshot().await
+        }
+        .await;
+
+        match reverted {
+            Ok(clean_snapshot) => {
+                fork.clean_snapshot = clean_snapshot;
+                self.idle.lock().await.entry(chain).or_default().push(fork);
+            }
+            Err(e) => error!("Failed to revert anvil fork, discarding it: {}", e),
+        }
+    }
+}
+
+/// Returns the RPC url Anvil forks from for the chain.
+fn fork_url(chain: &Chain) -> String {
+    let config = Config::from_env();
+    if config.environment == "production" {
+        CHAIN_DATA.get_address(chain).alchemy_url.clone()
+    } else {
+        CHAIN_DATA.get_address(chain).ws_url.clone()
+    }
+}
+
+/// Spawns a new forked Anvil instance and snapshots its clean state.
+async fn spawn_fork(chain: &Chain) -> Result<PooledFork> {
+    let simulator = AnvilTestSimulator::new(&fork_url(chain), chain).await?;
+    let clean_snapshot = simulator.snapshot().await?;
+
+    Ok(PooledFork {
+        simulator,
+        clean_snapshot,
+        forked_at: Instant::now(),
+    })
+}
+
+/// Moves a stale fork to the latest block with `anvil_reset`, re-funds the wallet and snapshots the
+/// new clean state.
+async fn reset_fork(mut fork: PooledFork, chain: &Chain) -> Result<PooledFork> {
+    fork.simulator
+        .client
+        .request::<_, ()>(
+            "anvil_reset",
+            [json!({ "forking": { "jsonRpcUrl": fork_url(chain) } })],
+        )
+        .await?;
+    fork.simulator.fund_wallet().await?;
+
+    fork.clean_snapshot = fork.simulator.snapshot().await?;
+    fork.forked_at = Instant::now();
+    Ok(fork)
+}
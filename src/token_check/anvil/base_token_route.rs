@@ -0,0 +1,248 @@
+//! This module routes the first hop of a simulated buy for tokens that are not paired against WETH.
+//!
+//! The simulation wallet only holds ETH, so a token paired against e.g. USDC or VIRTUAL is bought in
+//! two hops: ETH → base token on the deepest WETH / base token pool (Uniswap V3 or V2), then base
+//! token → target token on the token's own pool. Sells stay a single hop back into the base token.
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use ethers::providers::{Provider, Ws};
+use ethers::types::{Address, Chain, U256};
+
+use crate::abi::erc20::ERC20;
+use crate::abi::uniswap_factory_v2::UNISWAP_V2_FACTORY;
+use crate::abi::uniswap_pair::UNISWAP_PAIR;
+use crate::abi::uniswap_pool::UNISWAP_V3_POOL;
+use crate::abi::uniswap_router_v2::UNISWAP_V2_ROUTER;
+use crate::abi::uniswap_v3_factory::UNISWAP_V3_FACTORY;
+use crate::abi::uniswap_v3_router::{ExactInputSingleParams, UNISWAP_V3_ROUTER};
+use crate::data::chain_data::CHAIN_DATA;
+use crate::token_check::anvil::tx_trait::Txs;
+use crate::utils::tx::{
+    amount_out_adjusted_for_slippage, get_amount_out_uniswap_v2, get_amount_out_uniswap_v3,
+    TxSlippage,
+};
+
+use super::simlator::AnvilTestSimulator;
+
+/// Uniswap V3 fee tiers searched for a WETH / base token pool.
+pub const UNISWAP_V3_FEE_TIERS: [u32; 4] = [100, 500, 3000, 10000];
+
+/// The pool used to swap ETH into a base token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BaseTokenVenue {
+    UniswapV2,
+    UniswapV3 { fee: u32 },
+}
+
+/// Returns `true` if the base token is the chain's WETH, in which case it can be bought directly with ETH.
+///
+/// # Arguments
+///
+/// * `base_token_address` - The base token the target token is paired against.
+/// * `chain` - The chain the token is on.
+///
+/// # Returns
+///
+/// * `Result<bool>` - Whether the base token is WETH.
+pub fn is_weth(base_token_address: Address, chain: &Chain) -> Result<bool> {
+    let weth_address: Address = CHAIN_DATA.get_address(chain).weth.parse()?;
+    Ok(base_token_address == weth_address)
+}
+
+/// Finds the deepest pool for swapping WETH into the given base token.
+///
+/// Uniswap V3 pools are preferred, choosing the fee tier with the most in-range liquidity. If no V3
+/// pool exists, a Uniswap V2 pair with reserves on both sides is used instead.
+///
+/// # Arguments
+///
+/// * `base_token_address` - The base token to acquire.
+/// * `chain` - The chain to search on.
+/// * `client` - The provider used for the on-chain lookups.
+///
+/// # Returns
+///
+/// * `Result<Option<BaseTokenVenue>>` - The venue to use, or `None` if no WETH / base token pool exists.
+pub async fn find_weth_to_base_token_venue(
+    base_token_address: Address,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> Result<Option<BaseTokenVenue>> {
+    let addresses = CHAIN_DATA.get_address(chain);
+    let weth_address: Address = addresses.weth.parse()?;
+
+    // 1. Search the V3 fee tiers for the pool with the most liquidity.
+    let v3_factory_address: Address = addresses.uniswap_v3_factory.parse()?;
+    let v3_factory = UNISWAP_V3_FACTORY::new(v3_factory_address, client.clone());
+    let mut best_v3_pool: Option<(u32, u128)> = None;
+    for fee in UNISWAP_V3_FEE_TIERS {
+        let pool_address = v3_factory
+            .get_pool(weth_address, base_token_address, fee)
+            .call()
+            .await?;
+        if pool_address == Address::zero() {
+            continue;
+        }
+
+        let liquidity = UNISWAP_V3_POOL::new(pool_address, client.clone())
+            .liquidity()
+            .call()
+            .await?;
+        if liquidity > 0 && best_v3_pool.is_none_or(|(_, best)| liquidity > best) {
+            best_v3_pool = Some((fee, liquidity));
+        }
+    }
+
+    if let Some((fee, _)) = best_v3_pool {
+        return Ok(Some(BaseTokenVenue::UniswapV3 { fee }));
+    }
+
+    // 2. Fall back to the V2 pair.
+    let v2_factory_address: Address = addresses.uniswap_v2_factory.parse()?;
+    let v2_factory = UNISWAP_V2_FACTORY::new(v2_factory_address, client.clone());
+    let pair_address = v2_factory
+        .get_pair(weth_address, base_token_address)
+        .call()
+        .await?;
+    if pair_address == Address::zero() {
+        return Ok(None);
+    }
+
+    let (reserve_0, reserve_1, _) = UNISWAP_PAIR::new(pair_address, client.clone())
+        .get_reserves()
+        .call()
+        .await?;
+    if reserve_0 == 0 || reserve_1 == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(BaseTokenVenue::UniswapV2))
+}
+
+impl AnvilTestSimulator {
+    /// Swaps ETH into a base token so a token paired against it can be bought.
+    ///
+    /// This function performs the following steps:
+    /// 1. Finds the deepest WETH / base token pool.
+    /// 2. Quotes the swap and derives the minimum amount out.
+    /// 3. Sends the ETH → base token swap and awaits its confirmation.
+    /// 4. Approves `spender` (the router used for the second hop) to spend the base token received.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_token_address` - The base token to acquire.
+    /// * `amount_in` - The amount of ETH to spend.
+    /// * `spender` - The router that will swap the base token into the target token.
+    /// * `chain` - The chain the simulation is forked from.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<U256>` - The amount of base token received.
+    pub async fn buy_base_token_with_eth(
+        &self,
+        base_token_address: Address,
+        amount_in: U256,
+        spender: Address,
+        chain: &Chain,
+    ) -> Result<U256> {
+        let addresses = CHAIN_DATA.get_address(chain);
+        let weth_address: Address = addresses.weth.parse()?;
+
+        let venue = find_weth_to_base_token_venue(base_token_address, chain, &self.client)
+            .await?
+            .ok_or_else(|| anyhow!("No WETH pool found for base token {:?}", base_token_address))?;
+        println!("buying base token {:?} on {:?}", base_token_address, venue);
+
+        let base_token_balance_before = self
+            .get_wallet_token_balance_by_address(base_token_address)
+            .await?;
+
+        match venue {
+            BaseTokenVenue::UniswapV3 { fee } => {
+                let router_address: Address = addresses.uniswap_v3_router.parse()?;
+                let router = UNISWAP_V3_ROUTER::new(router_address, self.signed_client.clone());
+                let expected_amount_out = get_amount_out_uniswap_v3(
+                    weth_address,
+                    base_token_address,
+                    fee,
+                    amount_in,
+                    TxSlippage::None,
+                    chain,
+                    &self.client,
+                )
+                .await?;
+
+                let params = ExactInputSingleParams {
+                    token_in: weth_address,
+                    token_out: base_token_address,
+                    fee,
+                    recipient: self.sender,
+                    amount_in,
+                    amount_out_minimum: amount_out_adjusted_for_slippage(
+                        expected_amount_out,
+                        TxSlippage::FivePercent,
+                    ),
+                    sqrt_price_limit_x96: U256::zero(), // No price limit
+                };
+                router
+                    .exact_input_single(params)
+                    .value(amount_in)
+                    .send()
+                    .await?
+                    .await?;
+            }
+            BaseTokenVenue::UniswapV2 => {
+                let router_address: Address = addresses.uniswap_v2_router.parse()?;
+                let router = UNISWAP_V2_ROUTER::new(router_address, self.signed_client.clone());
+                let expected_amount_out = get_amount_out_uniswap_v2(
+                    weth_address,
+                    base_token_address,
+                    amount_in,
+                    TxSlippage::None,
+                    chain,
+                    &self.client,
+                )
+                .await?;
+
+                // Set a deadline timestamp 5 minutes in the future.
+                let deadline = self.get_current_timestamp().await? + 300;
+                router
+                    .swap_exact_eth_for_tokens(
+                        amount_out_adjusted_for_slippage(
+                            expected_amount_out,
+                            TxSlippage::FivePercent,
+                        ),
+                        vec![weth_address, base_token_address],
+                        self.sender,
+                        U256::from(deadline),
+                    )
+                    .value(amount_in)
+                    .send()
+                    .await?
+                    .await?;
+            }
+        }
+
+        let base_token_received = self
+            .get_wallet_token_balance_by_address(base_token_address)
+            .await?
+            .saturating_sub(base_token_balance_before);
+        if base_token_received.is_zero() {
+            return Err(anyhow!(
+                "Failed to acquire base token {:?}",
+                base_token_address
+            ));
+        }
+
+        // Approve the second hop's router to spend the base token.
+        ERC20::new(base_token_address, self.signed_client.clone())
+            .approve(spender, base_token_received)
+            .send()
+            .await?
+            .await?;
+
+        Ok(base_token_received)
+    }
+}
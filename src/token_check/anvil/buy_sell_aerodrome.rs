@@ -0,0 +1,536 @@
+//! This module contains functionality for simulating token transactions on an Anvil test
+//! environment using Aerodrome. Volatile and stable pools are traded through the Aerodrome router,
+//! Slipstream concentrated liquidity pools through the Slipstream swap router.
+
+use anyhow::{anyhow, Result};
+use ethers::abi::Detokenize;
+use ethers::contract::builders::ContractCall;
+use ethers::core::k256::ecdsa::SigningKey;
+use ethers::middleware::SignerMiddleware;
+use ethers::providers::{Provider, Ws};
+use ethers::signers::Wallet;
+use ethers::types::{Address, Chain, U256};
+use ethers::utils::format_units;
+use log::error;
+
+use crate::abi::aerodrome_router::{Route, AERODROME_ROUTER};
+use crate::abi::erc20::ERC20;
+use crate::abi::slipstream_router::{ExactInputSingleParams, SLIPSTREAM_ROUTER};
+use crate::data::chain_data::{AerodromeContracts, CHAIN_DATA};
+use crate::data::token_data::ERC20Token;
+use crate::dex::dex_data::TokenDexData;
+use crate::token_check::anvil::base_token_route::is_weth;
+use crate::token_check::anvil::buy_sell_uniswap_v2::extract_revert_reason;
+use crate::token_check::anvil::token_tax::SimulatedSwap;
+use crate::token_check::anvil::trace_report::SimulatedTx;
+use crate::token_check::anvil::tx_trait::Txs;
+use crate::utils::tx::{
+    amount_out_adjusted_for_slippage, get_amount_out_aerodrome,
+    get_amount_out_aerodrome_slipstream, TxSlippage,
+};
+
+use super::simlator::AnvilTestSimulator;
+
+/// Returns the Aerodrome contracts of a chain, or an error if Aerodrome is not deployed on it.
+fn aerodrome_contracts(chain: &Chain) -> Result<&'static AerodromeContracts> {
+    CHAIN_DATA
+        .get_address(chain)
+        .aerodrome
+        .as_ref()
+        .ok_or_else(|| anyhow!("Aerodrome is not deployed on {:?}", chain))
+}
+
+impl AnvilTestSimulator {
+    /// Simulates buying a token for WETH on an Aerodrome volatile or stable pool.
+    ///
+    /// This function performs the following steps:
+    /// 1. Retrieves the router and pool factory addresses.
+    /// 2. Determines the amount of base token to spend, first swapping ETH into the base token if
+    ///    the token is not paired against WETH.
+    /// 3. Quotes the amount of tokens expected from the swap and derives the minimum amount out.
+    /// 4. Sends the swap transaction and awaits its confirmation.
+    /// 5. Updates the token balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's Aerodrome pool.
+    /// * `token` - A reference to the [`ERC20Token`] that is being purchased.
+    /// * `eth_amount_in` - The amount of ETH to spend on the purchase.
+    ///
+    /// # Returns
+    ///
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received token amounts, and the token
+    ///   balance after the purchase.
+    pub async fn simulate_buying_token_on_aerodrome_for_weth(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+    ) -> Result<SimulatedSwap> {
+        let aerodrome = aerodrome_contracts(&token.chain)?;
+        let router_address: Address = aerodrome.router.parse()?;
+        let router = AERODROME_ROUTER::new(router_address, self.signed_client.clone());
+        let base_token_address: Address = dex_data.base_token_address.parse()?;
+
+        // Tokens paired against another base token are bought with the base token acquired for ETH.
+        let buying_with_eth = is_weth(base_token_address, &token.chain)?;
+        let amount_in = if buying_with_eth {
+            eth_amount_in
+        } else {
+            self.buy_base_token_with_eth(
+                base_token_address,
+                eth_amount_in,
+                router_address,
+                &token.chain,
+            )
+            .await?
+        };
+        let token_balance_before = self
+            .get_wallet_token_balance_by_address(token.address)
+            .await?;
+
+        // Quote the amount of token the swap should return, then derive the minimum amount out.
+        let expected_amount_out = get_amount_out_aerodrome(
+            base_token_address,
+            token.address,
+            dex_data.is_stable,
+            amount_in,
+            TxSlippage::None,
+            &token.chain,
+            &self.client,
+        )
+        .await?;
+        let amount_out_min =
+            amount_out_adjusted_for_slippage(expected_amount_out, TxSlippage::FivePercent);
+        println!(
+            "calculated amount out min {}",
+            format_units(amount_out_min, token.decimals as u32)?
+        );
+
+        // Set a deadline timestamp 5 minutes in the future.
+        let deadline = U256::from(self.get_current_timestamp().await? + 300);
+        let routes = vec![Route {
+            from: base_token_address,
+            to: token.address,
+            stable: dex_data.is_stable,
+            factory: aerodrome.pool_factory.parse()?,
+        }];
+        let tx = if buying_with_eth {
+            router
+                .swap_exact_eth_for_tokens(amount_out_min, routes, self.sender, deadline)
+                .value(amount_in)
+        } else {
+            router.swap_exact_tokens_for_tokens(
+                amount_in,
+                amount_out_min,
+                routes,
+                self.sender,
+                deadline,
+            )
+        };
+
+        let simulated_tx = self.send_simulated_swap(&tx).await?;
+        let new_token_balance = match simulated_tx {
+            SimulatedTx::Mined(_) => {
+                self.get_wallet_token_balance_by_address(token.address)
+                    .await?
+            }
+            SimulatedTx::Failed(_) => U256::zero(),
+        };
+
+        println!("token balance after purchase => {}", new_token_balance);
+        Ok(SimulatedSwap {
+            expected_amount_out,
+            amount_out: new_token_balance.saturating_sub(token_balance_before),
+            token_balance: new_token_balance,
+            tx: Some(simulated_tx),
+        })
+    }
+
+    /// Simulates selling a token for its base token on an Aerodrome volatile or stable pool.
+    ///
+    /// The swap uses the router's fee-on-transfer variant without a minimum amount out, so a taxed
+    /// token can still be sold and its tax measured, rather than reverting the swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's Aerodrome pool.
+    /// * `token` - A reference to the [`ERC20Token`] to be sold.
+    /// * `amount_to_sell` - The amount of the token to sell.
+    ///
+    /// # Returns
+    ///
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received base token amounts, and the token
+    ///   balance after selling the token.
+    pub async fn simulate_selling_token_on_aerodrome_for_weth(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount_to_sell: U256,
+    ) -> Result<SimulatedSwap> {
+        let aerodrome = aerodrome_contracts(&token.chain)?;
+        let router_address: Address = aerodrome.router.parse()?;
+        let router = AERODROME_ROUTER::new(router_address, self.signed_client.clone());
+        let base_token_address: Address = dex_data.base_token_address.parse()?;
+
+        let base_token_balance_before = self
+            .get_wallet_token_balance_by_address(base_token_address)
+            .await?;
+
+        // Approve the router to spend the token on behalf of the sender.
+        ERC20::new(token.address, self.signed_client.clone())
+            .approve(router_address, amount_to_sell)
+            .send()
+            .await?;
+
+        // Quote the amount of base token the sale should return without any tax.
+        let expected_amount_out = get_amount_out_aerodrome(
+            token.address,
+            base_token_address,
+            dex_data.is_stable,
+            amount_to_sell,
+            TxSlippage::None,
+            &token.chain,
+            &self.client,
+        )
+        .await?;
+
+        // Set a deadline timestamp 5 minutes in the future.
+        let deadline = U256::from(self.get_current_timestamp().await? + 300);
+        let routes = vec![Route {
+            from: token.address,
+            to: base_token_address,
+            stable: dex_data.is_stable,
+            factory: aerodrome.pool_factory.parse()?,
+        }];
+        let tx = router.swap_exact_tokens_for_tokens_supporting_fee_on_transfer_tokens(
+            amount_to_sell,
+            U256::zero(), // Measure the tax instead of reverting
+            routes,
+            self.sender,
+            deadline,
+        );
+
+        let simulated_tx = self.send_simulated_swap(&tx).await?;
+        self.simulated_sale(
+            simulated_tx,
+            token,
+            base_token_address,
+            base_token_balance_before,
+            expected_amount_out,
+        )
+        .await
+    }
+
+    /// Simulates buying a token for WETH on an Aerodrome Slipstream pool.
+    ///
+    /// Follows the same steps as [`AnvilTestSimulator::simulate_buying_token_on_aerodrome_for_weth`],
+    /// swapping through the Slipstream swap router's `exactInputSingle`. The router only wraps ETH
+    /// sent with the call when paying in WETH.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's Slipstream pool.
+    /// * `token` - A reference to the [`ERC20Token`] that is being purchased.
+    /// * `eth_amount_in` - The amount of ETH to spend on the purchase.
+    ///
+    /// # Returns
+    ///
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received token amounts, and the token
+    ///   balance after the purchase.
+    pub async fn simulate_buying_token_on_aerodrome_slipstream_for_weth(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+    ) -> Result<SimulatedSwap> {
+        let aerodrome = aerodrome_contracts(&token.chain)?;
+        let router_address: Address = aerodrome.slipstream_router.parse()?;
+        let router = SLIPSTREAM_ROUTER::new(router_address, self.signed_client.clone());
+        let base_token_address: Address = dex_data.base_token_address.parse()?;
+
+        // Tokens paired against another base token are bought with the base token acquired for ETH.
+        let buying_with_eth = is_weth(base_token_address, &token.chain)?;
+        let amount_in = if buying_with_eth {
+            eth_amount_in
+        } else {
+            self.buy_base_token_with_eth(
+                base_token_address,
+                eth_amount_in,
+                router_address,
+                &token.chain,
+            )
+            .await?
+        };
+        let token_balance_before = self
+            .get_wallet_token_balance_by_address(token.address)
+            .await?;
+
+        // Quote the amount of token the swap should return, then derive the minimum amount out.
+        let expected_amount_out = get_amount_out_aerodrome_slipstream(
+            base_token_address,
+            token.address,
+            dex_data.tick_spacing,
+            amount_in,
+            TxSlippage::None,
+            &token.chain,
+            &self.client,
+        )
+        .await?;
+        let amount_out_min =
+            amount_out_adjusted_for_slippage(expected_amount_out, TxSlippage::FivePercent);
+        println!(
+            "calculated amount out min {}",
+            format_units(amount_out_min, token.decimals as u32)?
+        );
+
+        let deadline = U256::from(self.get_current_timestamp().await? + 300);
+        let params = ExactInputSingleParams {
+            token_in: base_token_address,
+            token_out: token.address,
+            tick_spacing: dex_data.tick_spacing,
+            recipient: self.sender,
+            deadline,
+            amount_in,
+            amount_out_minimum: amount_out_min,
+            sqrt_price_limit_x96: U256::zero(), // No price limit
+        };
+        let value = if buying_with_eth {
+            amount_in
+        } else {
+            U256::zero()
+        };
+        let tx = router.exact_input_single(params).value(value);
+
+        let simulated_tx = self.send_simulated_swap(&tx).await?;
+        let new_token_balance = match simulated_tx {
+            SimulatedTx::Mined(_) => {
+                self.get_wallet_token_balance_by_address(token.address)
+                    .await?
+            }
+            SimulatedTx::Failed(_) => U256::zero(),
+        };
+
+        println!("token balance after purchase => {}", new_token_balance);
+        Ok(SimulatedSwap {
+            expected_amount_out,
+            amount_out: new_token_balance.saturating_sub(token_balance_before),
+            token_balance: new_token_balance,
+            tx: Some(simulated_tx),
+        })
+    }
+
+    /// Simulates selling a token for its base token on an Aerodrome Slipstream pool.
+    ///
+    /// No minimum amount out is set, so a tax on the sale is measured rather than reverting the
+    /// swap. Like Uniswap V3, Slipstream pools reject tokens that tax the transfer into the pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's Slipstream pool.
+    /// * `token` - A reference to the [`ERC20Token`] to be sold.
+    /// * `amount_to_sell` - The amount of the token to sell.
+    ///
+    /// # Returns
+    ///
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received base token amounts, and the token
+    ///   balance after selling the token.
+    pub async fn simulate_selling_token_on_aerodrome_slipstream_for_weth(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount_to_sell: U256,
+    ) -> Result<SimulatedSwap> {
+        let aerodrome = aerodrome_contracts(&token.chain)?;
+        let router_address: Address = aerodrome.slipstream_router.parse()?;
+        let router = SLIPSTREAM_ROUTER::new(router_address, self.signed_client.clone());
+        let base_token_address: Address = dex_data.base_token_address.parse()?;
+
+        let base_token_balance_before = self
+            .get_wallet_token_balance_by_address(base_token_address)
+            .await?;
+
+        // Approve the router to spend the token on behalf of the sender.
+        ERC20::new(token.address, self.signed_client.clone())
+            .approve(router_address, amount_to_sell)
+            .send()
+            .await?;
+
+        // Quote the amount of base token the sale should return without any tax.
+        let expected_amount_out = get_amount_out_aerodrome_slipstream(
+            token.address,
+            base_token_address,
+            dex_data.tick_spacing,
+            amount_to_sell,
+            TxSlippage::None,
+            &token.chain,
+            &self.client,
+        )
+        .await?;
+
+        let deadline = U256::from(self.get_current_timestamp().await? + 300);
+        let params = ExactInputSingleParams {
+            token_in: token.address,
+            token_out: base_token_address,
+            tick_spacing: dex_data.tick_spacing,
+            recipient: self.sender,
+            deadline,
+            amount_in: amount_to_sell,
+            amount_out_minimum: U256::zero(), // Measure the tax instead of reverting
+            sqrt_price_limit_x96: U256::zero(), // No price limit
+        };
+        let tx = router.exact_input_single(params);
+
+        let simulated_tx = self.send_simulated_swap(&tx).await?;
+        self.simulated_sale(
+            simulated_tx,
+            token,
+            base_token_address,
+            base_token_balance_before,
+            expected_amount_out,
+        )
+        .await
+    }
+
+    /// Removes all of an impersonated holder's liquidity from an Aerodrome volatile or stable pool
+    /// through the router.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - Whether any liquidity was removed.
+    pub async fn remove_liquidity_aerodrome(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holder: Address,
+    ) -> Result<bool> {
+        let aerodrome = aerodrome_contracts(&token.chain)?;
+        let router_address: Address = aerodrome.router.parse()?;
+        let router = AERODROME_ROUTER::new(router_address, self.client.clone());
+        // Aerodrome pools are their own ERC20 LP token.
+        let pool = ERC20::new(dex_data.pair_address, self.client.clone());
+
+        let liquidity = pool.balance_of(lp_holder).call().await?;
+        if liquidity.is_zero() {
+            return Ok(false);
+        }
+
+        let deadline = self.get_current_timestamp().await? + 300;
+        let approve = pool.approve(router_address, liquidity).from(lp_holder);
+        let remove_liquidity = router
+            .remove_liquidity(
+                dex_data.token_0,
+                dex_data.token_1,
+                dex_data.is_stable,
+                liquidity,
+                U256::zero(),
+                U256::zero(),
+                lp_holder,
+                U256::from(deadline),
+            )
+            .from(lp_holder);
+
+        match approve.send().await {
+            Ok(pending_tx) => {
+                pending_tx.await?;
+            }
+            Err(e) => {
+                error!("approving LP tokens of {:?} failed: {}", lp_holder, e);
+                return Ok(false);
+            }
+        }
+        let removed = match remove_liquidity.send().await {
+            Ok(pending_tx) => {
+                pending_tx.await?;
+                true
+            }
+            Err(e) => {
+                error!("removing liquidity of {:?} failed: {}", lp_holder, e);
+                false
+            }
+        };
+
+        Ok(removed)
+    }
+
+    /// Removes all of an impersonated holder's liquidity from an Aerodrome Slipstream pool through
+    /// the Slipstream position manager.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - Whether any liquidity was removed.
+    pub async fn remove_liquidity_aerodrome_slipstream(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holder: Address,
+    ) -> Result<bool> {
+        let aerodrome = aerodrome_contracts(&token.chain)?;
+        // Slipstream positions identify their pool by tick spacing where Uniswap V3 uses the fee.
+        self.remove_liquidity_from_positions(
+            aerodrome.slipstream_position_manager.parse()?,
+            dex_data,
+            dex_data.tick_spacing as u32,
+            lp_holder,
+        )
+        .await
+    }
+
+    /// Sends a simulated swap and waits for it to be mined.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SimulatedTx>` - The mined transaction, or the request if the node rejected it (for
+    ///   example because the swap reverts), so it can be traced either way.
+    pub(crate) async fn send_simulated_swap<D: Detokenize>(
+        &self,
+        tx: &ContractCall<SignerMiddleware<Provider<Ws>, Wallet<SigningKey>>, D>,
+    ) -> Result<SimulatedTx> {
+        println!("sending swap transaction");
+        match tx.send().await {
+            Ok(pending_tx) => {
+                let receipt = pending_tx
+                    .await?
+                    .ok_or_else(|| anyhow!("Transaction receipt not found"))?;
+                println!("tx_hash => {:?}", receipt.transaction_hash);
+                Ok(SimulatedTx::Mined(receipt.transaction_hash))
+            }
+            Err(tx_err) => {
+                error!("Failed to send transaction: {:?}", tx_err);
+                if let Some(revert_reason) = extract_revert_reason(&tx_err) {
+                    error!("Revert reason: {}", revert_reason);
+                } else {
+                    error!("Failed to extract revert reason");
+                }
+                Ok(SimulatedTx::Failed(Box::new(tx.tx.clone())))
+            }
+        }
+    }
+
+    /// Measures the outcome of a simulated sale from the wallet's balances.
+    async fn simulated_sale(
+        &self,
+        simulated_tx: SimulatedTx,
+        token: &ERC20Token,
+        base_token_address: Address,
+        base_token_balance_before: U256,
+        expected_amount_out: U256,
+    ) -> Result<SimulatedSwap> {
+        let (new_token_balance, base_token_balance_after) = match simulated_tx {
+            SimulatedTx::Mined(_) => (
+                self.get_wallet_token_balance_by_address(token.address)
+                    .await?,
+                self.get_wallet_token_balance_by_address(base_token_address)
+                    .await?,
+            ),
+            SimulatedTx::Failed(_) => (U256::zero(), base_token_balance_before),
+        };
+
+        Ok(SimulatedSwap {
+            expected_amount_out,
+            amount_out: base_token_balance_after.saturating_sub(base_token_balance_before),
+            token_balance: new_token_balance,
+            tx: Some(simulated_tx),
+        })
+    }
+}
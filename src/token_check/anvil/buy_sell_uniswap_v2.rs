@@ -7,8 +7,11 @@ use crate::abi::uniswap_router_v2::UNISWAP_V2_ROUTER;
 use crate::data::chain_data::CHAIN_DATA;
 use crate::data::token_data::ERC20Token;
 use crate::dex::dex_data::TokenDexData;
+use crate::token_check::anvil::base_token_route::is_weth;
+use crate::token_check::anvil::token_tax::SimulatedSwap;
+use crate::token_check::anvil::trace_report::SimulatedTx;
 use crate::token_check::anvil::tx_trait::Txs;
-use crate::utils::tx::{get_amount_out_uniswap_v2, test_amount_of_token_to_purchase, TxSlippage};
+use crate::utils::tx::{amount_out_adjusted_for_slippage, get_amount_out_uniswap_v2, TxSlippage};
 use ethers::types::Address;
 use ethers::types::U256;
 use ethers::utils::format_units;
@@ -23,49 +26,71 @@ impl AnvilTestSimulator {
     /// This function performs the following steps:
     /// 1. Retrieves required addresses (router and WETH).
     /// 2. Checks wallet ETH balance.
-    /// 3. Determines the amount of token to purchase.
-    /// 4. Calculates the minimum amount of tokens expected from the swap.
+    /// 3. Determines the amount of token to purchase, first swapping ETH into the base token if
+    ///    the token is not paired against WETH.
+    /// 4. Quotes the amount of tokens expected from the swap and derives the minimum amount out.
     /// 5. Sends the swap transaction and awaits its confirmation.
     /// 6. Traces the transaction and updates token balance.
     ///
     /// # Arguments
     ///
     /// * `token` - A reference to the [`ERC20Token`] that is being purchased.
+    /// * `eth_amount_in` - The amount of ETH to spend on the purchase.
     ///
     /// # Returns
     ///
-    /// * [`anyhow::Result<U256>`] - Returns the updated token balance after the purchase.
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received token amounts, and the token
+    ///   balance after the purchase.
     pub async fn simulate_buying_token_on_uniswap_v2_for_weth(
         &self,
         dex_data: &TokenDexData,
         token: &ERC20Token,
-    ) -> anyhow::Result<U256> {
+        eth_amount_in: U256,
+    ) -> anyhow::Result<SimulatedSwap> {
         let router_address: Address = CHAIN_DATA
             .get_address(&token.chain)
             .uniswap_v2_router
             .parse()?;
 
-        // TODO - NEED TO FIRST PURCHASE BASE TOKEN if not WETH
-
         let mut new_token_balance = U256::from(0);
+        let simulated_tx;
         let router = UNISWAP_V2_ROUTER::new(router_address, self.signed_client.clone());
 
         // Ensure the wallet has sufficient ETH
         let base_token_address: Address = dex_data.base_token_address.parse()?;
         self.get_wallet_token_balance_by_address(base_token_address)
             .await?;
-        let amount_in = test_amount_of_token_to_purchase()?;
 
-        // Calculate the minimum amount of token expected from the swap based on slippage settings.
-        let amount_out_min = get_amount_out_uniswap_v2(
+        // Tokens paired against another base token are bought with the base token acquired for ETH.
+        let buying_with_eth = is_weth(base_token_address, &token.chain)?;
+        let amount_in = if buying_with_eth {
+            eth_amount_in
+        } else {
+            self.buy_base_token_with_eth(
+                base_token_address,
+                eth_amount_in,
+                router_address,
+                &token.chain,
+            )
+            .await?
+        };
+        let token_balance_before = self
+            .get_wallet_token_balance_by_address(token.address)
+            .await?;
+
+        // Quote the amount of token the swap should return, then derive the minimum amount of
+        // token expected from the swap based on slippage settings.
+        let expected_amount_out = get_amount_out_uniswap_v2(
             base_token_address,
             token.address,
             amount_in,
-            TxSlippage::FivePercent,
+            TxSlippage::None,
             &token.chain,
             &self.client,
         )
         .await?;
+        let amount_out_min =
+            amount_out_adjusted_for_slippage(expected_amount_out, TxSlippage::FivePercent);
 
         let amount_out_min_readable = format_units(amount_out_min, 18u32)?;
         println!("calculated amount out min {}", amount_out_min_readable);
@@ -75,19 +100,28 @@ impl AnvilTestSimulator {
         let deadline = self.get_current_timestamp().await? + 300;
 
         // Prepare the swap transaction:
-        // The swap function will exchange ETH for the specified token.
-        let tx = router
-            .swap_exact_eth_for_tokens(
+        // The swap function will exchange ETH (or the base token) for the specified token.
+        let path = vec![base_token_address, token.address];
+        let tx = if buying_with_eth {
+            router
+                .swap_exact_eth_for_tokens(amount_out_min, path, self.sender, U256::from(deadline))
+                .value(amount_in)
+        } else {
+            router.swap_exact_tokens_for_tokens(
+                amount_in,
                 amount_out_min,
-                vec![base_token_address, token.address],
+                path,
                 self.sender,
                 U256::from(deadline),
             )
-            .value(amount_in);
+        };
 
-        // Estimate the gas for the transaction.
-        let gas_estimate = tx.estimate_gas().await?;
-        println!("gas est => {}", gas_estimate);
+        // Estimate the gas for the transaction. A failed estimate means the swap reverts, which is
+        // reported (and traced) when sending it below.
+        match tx.estimate_gas().await {
+            Ok(gas_estimate) => println!("gas est => {}", gas_estimate),
+            Err(e) => error!("Gas estimation failed: {:?}", e),
+        }
 
         println!("sending tx");
         let pending_tx_result = tx.send().await;
@@ -107,8 +141,8 @@ impl AnvilTestSimulator {
 
                 let tx_hash = receipt.transaction_hash;
 
-                // Trace the transaction details.
-                self.trace_transaction(tx_hash).await?;
+                // Keep the transaction so it can be traced.
+                simulated_tx = Some(SimulatedTx::Mined(tx_hash));
 
                 println!("........................................................");
                 println!("balance after buying {}...", token.name);
@@ -119,6 +153,8 @@ impl AnvilTestSimulator {
                     .await?;
             }
             Err(tx_err) => {
+                simulated_tx = Some(SimulatedTx::Failed(Box::new(tx.tx.clone())));
+
                 // Transaction sending failed; log the error details.
                 error!("Failed to send transaction: {:?}", tx_err);
 
@@ -132,7 +168,12 @@ impl AnvilTestSimulator {
         }
 
         println!("token balance after purchase => {}", new_token_balance);
-        Ok(new_token_balance)
+        Ok(SimulatedSwap {
+            expected_amount_out,
+            amount_out: new_token_balance.saturating_sub(token_balance_before),
+            token_balance: new_token_balance,
+            tx: simulated_tx,
+        })
     }
 
     /// Simulates selling a token for WETH using the Uniswap V2 router.
@@ -140,22 +181,28 @@ impl AnvilTestSimulator {
     /// The function performs these steps:
     /// 1. Retrieves router and WETH addresses.
     /// 2. Approves the swap router to trade the token.
-    /// 3. Calculates the minimum acceptable amount (considering slippage).
+    /// 3. Quotes the amount of WETH the sale should return.
     /// 4. Sends the swap transaction and awaits its confirmation.
     /// 5. Traces the transaction and updates token balance.
     ///
+    /// The swap uses the router's fee-on-transfer variant without a minimum amount out, so a taxed
+    /// token can still be sold and its tax measured, rather than reverting the swap.
+    ///
     /// # Arguments
     ///
     /// * `token` - A reference to the [`ERC20Token`] to be sold.
+    /// * `amount_to_sell` - The amount of the token to sell.
     ///
     /// # Returns
     ///
-    /// * [`anyhow::Result<U256>`] - Returns the updated token balance after selling the token.
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received WETH amounts, and the token
+    ///   balance after selling the token.
     pub async fn simulate_selling_token_on_uniswap_v2_for_weth(
         &self,
         dex_data: &TokenDexData,
         token: &ERC20Token,
-    ) -> anyhow::Result<U256> {
+        amount_to_sell: U256,
+    ) -> anyhow::Result<SimulatedSwap> {
         let router_address: Address = CHAIN_DATA
             .get_address(&token.chain)
             .uniswap_v2_router
@@ -164,15 +211,14 @@ impl AnvilTestSimulator {
         let base_token_address: Address = dex_data.base_token_address.parse()?;
 
         let mut new_token_balance = U256::from(0);
+        let simulated_tx;
         let router = UNISWAP_V2_ROUTER::new(router_address, self.signed_client.clone());
 
         println!("........................................................");
-        self.get_wallet_token_balance_by_address(base_token_address)
-            .await?;
-
-        let amount_to_sell = self
-            .get_wallet_token_balance_by_address(token.address)
+        let base_token_balance_before = self
+            .get_wallet_token_balance_by_address(base_token_address)
             .await?;
+        let mut base_token_balance_after = base_token_balance_before;
 
         // Approve the router to spend the token on behalf of the sender.
         token_contract
@@ -180,12 +226,12 @@ impl AnvilTestSimulator {
             .send()
             .await?;
 
-        // Calculate the minimum amount of WETH that should be received.
-        let amount_out_min = get_amount_out_uniswap_v2(
+        // Quote the amount of WETH the sale should return without any tax.
+        let expected_amount_out = get_amount_out_uniswap_v2(
             token.address,
             base_token_address,
             amount_to_sell,
-            TxSlippage::FivePercent,
+            TxSlippage::None,
             &token.chain,
             &self.client,
         )
@@ -196,10 +242,11 @@ impl AnvilTestSimulator {
         // Set a deadline timestamp 5 minutes in the future.
         let deadline = self.get_current_timestamp().await? + 300;
 
-        // Prepare the swap transaction to convert tokens to ETH.
-        let tx = router.swap_exact_tokens_for_eth(
+        // Prepare the swap transaction to convert tokens to WETH. No minimum amount out is set so
+        // the tax can be measured instead of reverting the sale.
+        let tx = router.swap_exact_tokens_for_tokens_supporting_fee_on_transfer_tokens(
             amount_to_sell,
-            amount_out_min,
+            U256::zero(),
             vec![token.address, base_token_address],
             self.sender,
             U256::from(deadline),
@@ -222,16 +269,19 @@ impl AnvilTestSimulator {
                     .ok_or_else(|| anyhow::anyhow!("Transaction receipt not found"))?;
 
                 let tx_hash = receipt.transaction_hash;
-                self.trace_transaction(tx_hash).await?;
+                simulated_tx = Some(SimulatedTx::Mined(tx_hash));
 
                 new_token_balance = self
                     .get_wallet_token_balance_by_address(token.address)
                     .await?;
 
-                self.get_wallet_token_balance_by_address(base_token_address)
+                base_token_balance_after = self
+                    .get_wallet_token_balance_by_address(base_token_address)
                     .await?;
             }
             Err(tx_err) => {
+                simulated_tx = Some(SimulatedTx::Failed(Box::new(tx.tx.clone())));
+
                 // Log failure details for debugging.
                 error!("Failed to send transaction: {:?}", tx_err);
 
@@ -244,7 +294,12 @@ impl AnvilTestSimulator {
             }
         }
 
-        Ok(new_token_balance)
+        Ok(SimulatedSwap {
+            expected_amount_out,
+            amount_out: base_token_balance_after.saturating_sub(base_token_balance_before),
+            token_balance: new_token_balance,
+            tx: simulated_tx,
+        })
     }
 }
 
@@ -7,9 +7,12 @@ use crate::abi::uniswap_v3_router::{ExactInputSingleParams, UNISWAP_V3_ROUTER};
 use crate::data::chain_data::CHAIN_DATA;
 use crate::data::token_data::ERC20Token;
 use crate::dex::dex_data::TokenDexData;
+use crate::token_check::anvil::base_token_route::is_weth;
 use crate::token_check::anvil::buy_sell_uniswap_v2::extract_revert_reason;
+use crate::token_check::anvil::token_tax::SimulatedSwap;
+use crate::token_check::anvil::trace_report::SimulatedTx;
 use crate::token_check::anvil::tx_trait::Txs;
-use crate::utils::tx::{get_amount_out_uniswap_v3, test_amount_of_token_to_purchase, TxSlippage};
+use crate::utils::tx::{amount_out_adjusted_for_slippage, get_amount_out_uniswap_v3, TxSlippage};
 use ethers::types::Address;
 use ethers::types::U256;
 use ethers::utils::format_units;
@@ -23,58 +26,80 @@ impl AnvilTestSimulator {
     /// This function performs the following steps:
     /// 1. Retrieves required addresses (router and WETH).
     /// 2. Checks wallet ETH balance.
-    /// 3. Determines the amount of token to purchase.
-    /// 4. Calculates the minimum amount of tokens expected from the swap.
+    /// 3. Determines the amount of token to purchase, first swapping ETH into the base token if
+    ///    the token is not paired against WETH.
+    /// 4. Quotes the amount of tokens expected from the swap and derives the minimum amount out.
     /// 5. Sends the swap transaction and awaits its confirmation.
     /// 6. Traces the transaction and updates token balance.
     ///
     /// # Arguments
     ///
     /// * `token` - A reference to the [`ERC20Token`] that is being purchased.
+    /// * `eth_amount_in` - The amount of ETH to spend on the purchase.
     ///
     /// # Returns
     ///
-    /// * [`anyhow::Result<U256>`] - Returns the updated token balance after the purchase.
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received token amounts, and the token
+    ///   balance after the purchase.
     pub async fn simulate_buying_token_on_uniswap_v3_for_weth(
         &self,
         dex_data: &TokenDexData,
         token: &ERC20Token,
-    ) -> anyhow::Result<U256> {
+        eth_amount_in: U256,
+    ) -> anyhow::Result<SimulatedSwap> {
         let router_address: Address = CHAIN_DATA
             .get_address(&token.chain)
             .uniswap_v3_router
             .parse()?;
 
         let mut new_token_balance = U256::from(0);
+        let simulated_tx;
         let router = UNISWAP_V3_ROUTER::new(router_address, self.signed_client.clone());
         let base_token_address: Address = dex_data.base_token_address.parse()?;
 
-        // TODO -BUY BASE TOKEN IF NOT WETH
         // Ensure the wallet has sufficient ETH
-        // UPDATE
         self.get_wallet_token_balance_by_address(base_token_address)
             .await?;
-        let amount_in = test_amount_of_token_to_purchase()?;
 
-        // Calculate the minimum amount of token expected from the swap based on slippage settings.
-        // Use fixed fee tier of 3000 (0.3%) for V3
-        let amount_out_min = get_amount_out_uniswap_v3(
+        // Tokens paired against another base token are bought with the base token acquired for ETH.
+        let buying_with_eth = is_weth(base_token_address, &token.chain)?;
+        let amount_in = if buying_with_eth {
+            eth_amount_in
+        } else {
+            self.buy_base_token_with_eth(
+                base_token_address,
+                eth_amount_in,
+                router_address,
+                &token.chain,
+            )
+            .await?
+        };
+        let token_balance_before = self
+            .get_wallet_token_balance_by_address(token.address)
+            .await?;
+
+        // Quote the amount of token the swap should return, then derive the minimum amount of
+        // token expected from the swap based on slippage settings.
+        let expected_amount_out = get_amount_out_uniswap_v3(
             base_token_address,
             token.address,
             dex_data.fee,
             amount_in,
-            TxSlippage::FivePercent,
+            TxSlippage::None,
             &token.chain,
             &self.client,
         )
         .await?;
+        let amount_out_min =
+            amount_out_adjusted_for_slippage(expected_amount_out, TxSlippage::FivePercent);
 
         let amount_out_min_readable = format_units(amount_out_min, token.decimals as u32)?;
         println!("calculated amount out min {}", amount_out_min_readable);
         println!("........................................................");
 
         // Prepare the swap transaction:
-        // The swap function will exchange ETH for the specified token using V3 exactInputSingle
+        // The swap function will exchange ETH (or the base token) for the specified token using V3
+        // exactInputSingle. The router only wraps ETH sent with the call when paying in WETH.
         let params = ExactInputSingleParams {
             token_in: base_token_address,
             token_out: token.address,
@@ -85,7 +110,12 @@ impl AnvilTestSimulator {
             sqrt_price_limit_x96: U256::zero(), // No price limit
         };
 
-        let tx = router.exact_input_single(params).value(amount_in);
+        let value = if buying_with_eth {
+            amount_in
+        } else {
+            U256::zero()
+        };
+        let tx = router.exact_input_single(params).value(value);
 
         println!("sending tx");
         let pending_tx_result = tx.send().await;
@@ -105,8 +135,8 @@ impl AnvilTestSimulator {
 
                 let tx_hash = receipt.transaction_hash;
 
-                // Trace the transaction details.
-                self.trace_transaction(tx_hash).await?;
+                // Keep the transaction so it can be traced.
+                simulated_tx = Some(SimulatedTx::Mined(tx_hash));
 
                 println!("........................................................");
                 println!("balance after buying {}...", token.name);
@@ -117,6 +147,8 @@ impl AnvilTestSimulator {
                     .await?;
             }
             Err(tx_err) => {
+                simulated_tx = Some(SimulatedTx::Failed(Box::new(tx.tx.clone())));
+
                 // Transaction sending failed; log the error details.
                 error!("Failed to send transaction: {:?}", tx_err);
 
@@ -130,7 +162,12 @@ impl AnvilTestSimulator {
         }
 
         println!("token balance after purchase => {}", new_token_balance);
-        Ok(new_token_balance)
+        Ok(SimulatedSwap {
+            expected_amount_out,
+            amount_out: new_token_balance.saturating_sub(token_balance_before),
+            token_balance: new_token_balance,
+            tx: simulated_tx,
+        })
     }
 
     /// Simulates selling a token for WETH using the Uniswap V3 router.
@@ -138,22 +175,28 @@ impl AnvilTestSimulator {
     /// The function performs these steps:
     /// 1. Retrieves router and WETH addresses.
     /// 2. Approves the swap router to trade the token.
-    /// 3. Calculates the minimum acceptable amount (considering slippage).
+    /// 3. Quotes the amount of WETH the sale should return.
     /// 4. Sends the swap transaction and awaits its confirmation.
     /// 5. Traces the transaction and updates token balance.
     ///
+    /// No minimum amount out is set, so a tax on the sale is measured rather than reverting the
+    /// swap. Note that V3 pools reject tokens that tax the transfer into the pool itself.
+    ///
     /// # Arguments
     ///
     /// * `token` - A reference to the [`ERC20Token`] to be sold.
+    /// * `amount_to_sell` - The amount of the token to sell.
     ///
     /// # Returns
     ///
-    /// * [`anyhow::Result<U256>`] - Returns the updated token balance after selling the token.
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received WETH amounts, and the token
+    ///   balance after selling the token.
     pub async fn simulate_selling_token_on_uniswap_v3_for_weth(
         &self,
         dex_data: &TokenDexData,
         token: &ERC20Token,
-    ) -> anyhow::Result<U256> {
+        amount_to_sell: U256,
+    ) -> anyhow::Result<SimulatedSwap> {
         let router_address: Address = CHAIN_DATA
             .get_address(&token.chain)
             .uniswap_v3_router
@@ -162,14 +205,14 @@ impl AnvilTestSimulator {
         let base_token_address: Address = dex_data.base_token_address.parse()?;
 
         let mut new_token_balance = U256::from(0);
+        let simulated_tx;
         let router = UNISWAP_V3_ROUTER::new(router_address, self.signed_client.clone());
 
         println!("........................................................");
-        self.get_wallet_token_balance_by_address(base_token_address)
-            .await?;
-        let amount_to_sell = self
-            .get_wallet_token_balance_by_address(token.address)
+        let base_token_balance_before = self
+            .get_wallet_token_balance_by_address(base_token_address)
             .await?;
+        let mut base_token_balance_after = base_token_balance_before;
 
         // Approve the router to spend the token on behalf of the sender.
         token_contract
@@ -177,13 +220,13 @@ impl AnvilTestSimulator {
             .send()
             .await?;
 
-        // Calculate the minimum amount of WETH that should be received.
-        let amount_out_min = get_amount_out_uniswap_v3(
+        // Quote the amount of WETH the sale should return without any tax.
+        let expected_amount_out = get_amount_out_uniswap_v3(
             token.address,
             base_token_address,
             dex_data.fee,
             amount_to_sell,
-            TxSlippage::FivePercent,
+            TxSlippage::None,
             &token.chain,
             &self.client,
         )
@@ -198,7 +241,8 @@ impl AnvilTestSimulator {
             fee: dex_data.fee,
             recipient: self.sender,
             amount_in: amount_to_sell,
-            amount_out_minimum: amount_out_min,
+            amount_out_minimum: U256::zero(), // Measure the tax instead of reverting
+
             sqrt_price_limit_x96: U256::zero(), // No price limit
         };
 
@@ -221,15 +265,18 @@ impl AnvilTestSimulator {
                     .ok_or_else(|| anyhow::anyhow!("Transaction receipt not found"))?;
 
                 let tx_hash = receipt.transaction_hash;
-                self.trace_transaction(tx_hash).await?;
+                simulated_tx = Some(SimulatedTx::Mined(tx_hash));
 
                 new_token_balance = self
                     .get_wallet_token_balance_by_address(token.address)
                     .await?;
-                self.get_wallet_token_balance_by_address(base_token_address)
+                base_token_balance_after = self
+                    .get_wallet_token_balance_by_address(base_token_address)
                     .await?;
             }
             Err(tx_err) => {
+                simulated_tx = Some(SimulatedTx::Failed(Box::new(tx.tx.clone())));
+
                 // Log failure details for debugging.
                 error!("Failed to send transaction: {:?}", tx_err);
 
@@ -242,6 +289,11 @@ impl AnvilTestSimulator {
             }
         }
 
-        Ok(new_token_balance)
+        Ok(SimulatedSwap {
+            expected_amount_out,
+            amount_out: base_token_balance_after.saturating_sub(base_token_balance_before),
+            token_balance: new_token_balance,
+            tx: simulated_tx,
+        })
     }
 }
@@ -0,0 +1,335 @@
+//! This module contains functionality for simulating token transactions on an Anvil test
+//! environment using Uniswap V4. Swaps are sent through the Universal Router as a `V4_SWAP` command
+//! holding three V4 router actions: swap an exact input on the pool, settle the input owed to the
+//! pool manager, and take the output. ERC20 inputs are pulled by the router through Permit2.
+
+use anyhow::{anyhow, Result};
+use ethers::abi::{encode, Token, Tokenizable};
+use ethers::providers::Middleware;
+use ethers::types::{Address, Bytes, TransactionRequest, U256};
+use ethers::utils::format_units;
+
+use crate::abi::erc20::ERC20;
+use crate::abi::permit2::PERMIT2;
+use crate::abi::uniswap_v4_quoter::PoolKey;
+use crate::abi::universal_router::UNIVERSAL_ROUTER;
+use crate::data::chain_data::UniswapV4Contracts;
+use crate::data::token_data::ERC20Token;
+use crate::dex::dex_data::TokenDexData;
+use crate::dex::onchain::uniswap_v4::{uniswap_v4_contracts, uniswap_v4_pool_key};
+use crate::token_check::anvil::base_token_route::is_weth;
+use crate::token_check::anvil::token_tax::SimulatedSwap;
+use crate::token_check::anvil::trace_report::SimulatedTx;
+use crate::token_check::anvil::tx_trait::Txs;
+use crate::utils::tx::{amount_out_adjusted_for_slippage, get_amount_out_uniswap_v4, TxSlippage};
+
+use super::simlator::AnvilTestSimulator;
+
+/// Universal Router command executing a sequence of V4 router actions.
+pub const V4_SWAP_COMMAND: u8 = 0x10;
+/// V4 router action swapping an exact input on a single pool.
+pub const SWAP_EXACT_IN_SINGLE_ACTION: u8 = 0x06;
+/// V4 router action paying the pool manager everything owed in a currency.
+pub const SETTLE_ALL_ACTION: u8 = 0x0c;
+/// V4 router action taking everything the pool manager owes in a currency.
+pub const TAKE_ALL_ACTION: u8 = 0x0f;
+
+/// Largest Permit2 allowance expiration (`uint48`).
+const PERMIT2_MAX_EXPIRATION: u64 = (1 << 48) - 1;
+
+/// Encodes an exact input swap on a single V4 pool as Universal Router commands and inputs.
+///
+/// # Arguments
+///
+/// * `pool_key` - The key of the pool to swap on.
+/// * `zero_for_one` - Whether currency 0 is swapped for currency 1.
+/// * `amount_in` - The amount of the input currency to swap.
+/// * `amount_out_minimum` - The minimum amount of the output currency, the swap reverts below it.
+///
+/// # Returns
+///
+/// * `(Bytes, Vec<Bytes>)` - The `commands` and `inputs` arguments of the router's `execute`.
+pub fn encode_v4_exact_in_single_swap(
+    pool_key: &PoolKey,
+    zero_for_one: bool,
+    amount_in: u128,
+    amount_out_minimum: u128,
+) -> (Bytes, Vec<Bytes>) {
+    let (currency_in, currency_out) = if zero_for_one {
+        (pool_key.currency_0, pool_key.currency_1)
+    } else {
+        (pool_key.currency_1, pool_key.currency_0)
+    };
+
+    let actions = vec![
+        SWAP_EXACT_IN_SINGLE_ACTION,
+        SETTLE_ALL_ACTION,
+        TAKE_ALL_ACTION,
+    ];
+    let params = vec![
+        // ExactInputSingleParams(poolKey, zeroForOne, amountIn, amountOutMinimum, hookData)
+        encode(&[Token::Tuple(vec![
+            pool_key.clone().into_token(),
+            Token::Bool(zero_for_one),
+            Token::Uint(U256::from(amount_in)),
+            Token::Uint(U256::from(amount_out_minimum)),
+            Token::Bytes(Vec::new()),
+        ])]),
+        encode(&[
+            Token::Address(currency_in),
+            Token::Uint(U256::from(amount_in)),
+        ]),
+        encode(&[
+            Token::Address(currency_out),
+            Token::Uint(U256::from(amount_out_minimum)),
+        ]),
+    ];
+    let input = encode(&[
+        Token::Bytes(actions),
+        Token::Array(params.into_iter().map(Token::Bytes).collect()),
+    ]);
+
+    (Bytes::from(vec![V4_SWAP_COMMAND]), vec![Bytes::from(input)])
+}
+
+impl AnvilTestSimulator {
+    /// Simulates buying a token with ETH on a Uniswap V4 pool.
+    ///
+    /// This function performs the following steps:
+    /// 1. Determines the amount of base currency to spend: ETH is sent with the swap for native ETH
+    ///    pools, wrapped for WETH pools, or swapped into the base token for other pools, which is then
+    ///    approved to the Universal Router through Permit2.
+    /// 2. Quotes the amount of tokens expected from the swap and derives the minimum amount out.
+    /// 3. Sends the swap through the Universal Router and awaits its confirmation.
+    /// 4. Updates the token balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's V4 pool.
+    /// * `token` - A reference to the [`ERC20Token`] that is being purchased.
+    /// * `eth_amount_in` - The amount of ETH to spend on the purchase.
+    ///
+    /// # Returns
+    ///
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received token amounts, and the token
+    ///   balance after the purchase.
+    pub async fn simulate_buying_token_on_uniswap_v4_for_eth(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+    ) -> Result<SimulatedSwap> {
+        let uniswap_v4 = uniswap_v4_contracts(&token.chain)?;
+        let router_address: Address = uniswap_v4.universal_router.parse()?;
+        let router = UNIVERSAL_ROUTER::new(router_address, self.signed_client.clone());
+        let base_token_address: Address = dex_data.base_token_address.parse()?;
+        let pool_key = uniswap_v4_pool_key(dex_data);
+
+        // Native ETH is paid with the swap, any other base currency through Permit2.
+        let amount_in = if base_token_address.is_zero() {
+            eth_amount_in
+        } else {
+            let amount_in = if is_weth(base_token_address, &token.chain)? {
+                // WETH mints to whoever sends it ETH.
+                self.signed_client
+                    .send_transaction(
+                        TransactionRequest::new()
+                            .to(base_token_address)
+                            .value(eth_amount_in),
+                        None,
+                    )
+                    .await?
+                    .await?;
+                eth_amount_in
+            } else {
+                self.buy_base_token_with_eth(
+                    base_token_address,
+                    eth_amount_in,
+                    uniswap_v4.permit2.parse()?,
+                    &token.chain,
+                )
+                .await?
+            };
+            self.approve_universal_router(base_token_address, amount_in, uniswap_v4)
+                .await?;
+            amount_in
+        };
+        let token_balance_before = self
+            .get_wallet_token_balance_by_address(token.address)
+            .await?;
+
+        // Quote the amount of token the swap should return, then derive the minimum amount out.
+        let expected_amount_out = get_amount_out_uniswap_v4(
+            pool_key.clone(),
+            base_token_address,
+            amount_in,
+            TxSlippage::None,
+            &token.chain,
+            &self.client,
+        )
+        .await?;
+        let amount_out_min =
+            amount_out_adjusted_for_slippage(expected_amount_out, TxSlippage::FivePercent);
+        println!(
+            "calculated amount out min {}",
+            format_units(amount_out_min, token.decimals as u32)?
+        );
+
+        let (commands, inputs) = encode_v4_exact_in_single_swap(
+            &pool_key,
+            base_token_address == pool_key.currency_0,
+            to_u128(amount_in)?,
+            to_u128(amount_out_min)?,
+        );
+        // Set a deadline timestamp 5 minutes in the future.
+        let deadline = U256::from(self.get_current_timestamp().await? + 300);
+        let value = if base_token_address.is_zero() {
+            amount_in
+        } else {
+            U256::zero()
+        };
+        let tx = router.execute(commands, inputs, deadline).value(value);
+
+        let simulated_tx = self.send_simulated_swap(&tx).await?;
+        let new_token_balance = match simulated_tx {
+            SimulatedTx::Mined(_) => {
+                self.get_wallet_token_balance_by_address(token.address)
+                    .await?
+            }
+            SimulatedTx::Failed(_) => U256::zero(),
+        };
+
+        println!("token balance after purchase => {}", new_token_balance);
+        Ok(SimulatedSwap {
+            expected_amount_out,
+            amount_out: new_token_balance.saturating_sub(token_balance_before),
+            token_balance: new_token_balance,
+            tx: Some(simulated_tx),
+        })
+    }
+
+    /// Simulates selling a token for its base currency on a Uniswap V4 pool.
+    ///
+    /// No minimum amount out is set, so a tax on the sale (by the token or the pool's hook) is
+    /// measured rather than reverting the swap. For native ETH pools, the gas paid for the swap is
+    /// added back to the ETH received.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's V4 pool.
+    /// * `token` - A reference to the [`ERC20Token`] to be sold.
+    /// * `amount_to_sell` - The amount of the token to sell.
+    ///
+    /// # Returns
+    ///
+    /// * [`anyhow::Result<SimulatedSwap>`] - The quoted and received base currency amounts, and the
+    ///   token balance after selling the token.
+    pub async fn simulate_selling_token_on_uniswap_v4_for_eth(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount_to_sell: U256,
+    ) -> Result<SimulatedSwap> {
+        let uniswap_v4 = uniswap_v4_contracts(&token.chain)?;
+        let router_address: Address = uniswap_v4.universal_router.parse()?;
+        let router = UNIVERSAL_ROUTER::new(router_address, self.signed_client.clone());
+        let base_token_address: Address = dex_data.base_token_address.parse()?;
+        let pool_key = uniswap_v4_pool_key(dex_data);
+
+        self.approve_universal_router(token.address, amount_to_sell, uniswap_v4)
+            .await?;
+        let base_balance_before = self.get_base_currency_balance(base_token_address).await?;
+
+        // Quote the amount of base currency the sale should return without any tax.
+        let expected_amount_out = get_amount_out_uniswap_v4(
+            pool_key.clone(),
+            token.address,
+            amount_to_sell,
+            TxSlippage::None,
+            &token.chain,
+            &self.client,
+        )
+        .await?;
+
+        let (commands, inputs) = encode_v4_exact_in_single_swap(
+            &pool_key,
+            token.address == pool_key.currency_0,
+            to_u128(amount_to_sell)?,
+            0, // Measure the tax instead of reverting
+        );
+        let deadline = U256::from(self.get_current_timestamp().await? + 300);
+        let tx = router.execute(commands, inputs, deadline);
+
+        let simulated_tx = self.send_simulated_swap(&tx).await?;
+        let (new_token_balance, amount_out) = match &simulated_tx {
+            SimulatedTx::Mined(tx_hash) => {
+                let mut base_balance_after =
+                    self.get_base_currency_balance(base_token_address).await?;
+                if base_token_address.is_zero() {
+                    let receipt = self
+                        .client
+                        .get_transaction_receipt(*tx_hash)
+                        .await?
+                        .ok_or_else(|| anyhow!("Transaction receipt not found"))?;
+                    let gas_cost = receipt.gas_used.unwrap_or_default()
+                        * receipt.effective_gas_price.unwrap_or_default();
+                    base_balance_after = base_balance_after.saturating_add(gas_cost);
+                }
+                (
+                    self.get_wallet_token_balance_by_address(token.address)
+                        .await?,
+                    base_balance_after.saturating_sub(base_balance_before),
+                )
+            }
+            SimulatedTx::Failed(_) => (U256::zero(), U256::zero()),
+        };
+
+        Ok(SimulatedSwap {
+            expected_amount_out,
+            amount_out,
+            token_balance: new_token_balance,
+            tx: Some(simulated_tx),
+        })
+    }
+
+    /// Lets the Universal Router pull `amount` of an ERC20 token: approves Permit2 on the token, then
+    /// grants the router an allowance on Permit2.
+    async fn approve_universal_router(
+        &self,
+        token_address: Address,
+        amount: U256,
+        uniswap_v4: &UniswapV4Contracts,
+    ) -> Result<()> {
+        let permit2_address: Address = uniswap_v4.permit2.parse()?;
+        ERC20::new(token_address, self.signed_client.clone())
+            .approve(permit2_address, amount)
+            .send()
+            .await?
+            .await?;
+        PERMIT2::new(permit2_address, self.signed_client.clone())
+            .approve(
+                token_address,
+                uniswap_v4.universal_router.parse()?,
+                amount,
+                PERMIT2_MAX_EXPIRATION,
+            )
+            .send()
+            .await?
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the wallet's balance of a V4 currency, the zero address being native ETH.
+    async fn get_base_currency_balance(&self, currency: Address) -> Result<U256> {
+        if currency.is_zero() {
+            self.get_wallet_eth_balance().await
+        } else {
+            self.get_wallet_token_balance_by_address(currency).await
+        }
+    }
+}
+
+/// Converts an amount to the `uint128` V4 swaps take.
+fn to_u128(amount: U256) -> Result<u128> {
+    u128::try_from(amount).map_err(|_| anyhow!("Amount {} too large for a V4 swap", amount))
+}
@@ -0,0 +1,160 @@
+//! This module finds where a token's taxes go.
+//!
+//! Tax tokens route their fees to marketing or development wallets, which often end up dumping on
+//! holders. The `Transfer` events decoded from the traces of the simulated buy, transfer and sell
+//! show every recipient of the token: anything other than the pair and our own wallets received a
+//! cut of the trades, except burn addresses. Each receiver is classified as a contract or an EOA, and flagged if it is the
+//! owner, an admin or the deployer.
+
+use anyhow::Result;
+use ethers::providers::Middleware;
+use ethers::types::{Address, Chain, U256};
+
+use crate::data::address_labels::{AddressCategory, ADDRESS_LABELS};
+use crate::token_check::token_holder_check::u256_div_u256_to_f64;
+
+use super::simlator::AnvilTestSimulator;
+use super::trace_report::{TokenTransfer, TraceReport};
+
+/// An address that received tokens during the simulated trades, other than the pair and our wallets.
+///
+/// # Fields
+///
+/// * `address` - The receiver.
+/// * `is_contract` - Whether the receiver has code. Fees are often collected by the token contract
+///   itself, then swapped for ETH and forwarded to a wallet.
+/// * `is_token_contract` - Whether the receiver is the token contract.
+/// * `is_owner_related` - Whether the receiver is the owner, an admin or the deployer.
+/// * `amount_received` - The tokens it received over the buy, transfer and sell.
+/// * `share_percentage` - Its share of the tokens moved by the simulated trades (0.0 to 100.0).
+#[derive(Clone, Debug, Default)]
+pub struct FeeReceiver {
+    pub address: Address,
+    pub is_contract: bool,
+    pub is_token_contract: bool,
+    pub is_owner_related: bool,
+    pub amount_received: U256,
+    pub share_percentage: Option<f64>,
+}
+
+/// Finds the receivers of the token's fees in the transfers of the simulated trades.
+///
+/// # Arguments
+///
+/// * `token` - The token traded.
+/// * `chain` - The chain the token is on, whose burn addresses are not receivers.
+/// * `transfers` - The `Transfer` events emitted during the simulated trades, for any token.
+/// * `trade_parties` - The pair and our wallets, whose transfers are the trades themselves.
+/// * `related_accounts` - The owner, admins and deployer of the token.
+///
+/// # Returns
+///
+/// * `Vec<FeeReceiver>` - The receivers, largest first. `is_contract` is not set, as it needs a node.
+pub fn fee_receivers_from_transfers(
+    token: Address,
+    chain: &Chain,
+    transfers: &[TokenTransfer],
+    trade_parties: &[Address],
+    related_accounts: &[Address],
+) -> Vec<FeeReceiver> {
+    let token_transfers = transfers
+        .iter()
+        .filter(|transfer| transfer.token == token)
+        .collect::<Vec<_>>();
+
+    // The tokens moved by the trades: everything sent by the pair or our wallets.
+    let amount_traded = token_transfers
+        .iter()
+        .filter(|transfer| trade_parties.contains(&transfer.from))
+        .fold(U256::zero(), |total, transfer| {
+            total.saturating_add(transfer.value)
+        });
+
+    let mut fee_receivers: Vec<FeeReceiver> = Vec::new();
+    for transfer in token_transfers {
+        // Transfers to the zero address or a burn address are burns.
+        let is_burn = transfer.to.is_zero()
+            || ADDRESS_LABELS
+                .get(chain, transfer.to)
+                .is_some_and(|label| label.category == AddressCategory::Burn);
+        if is_burn || transfer.value.is_zero() || trade_parties.contains(&transfer.to) {
+            continue;
+        }
+
+        match fee_receivers
+            .iter_mut()
+            .find(|fee_receiver| fee_receiver.address == transfer.to)
+        {
+            Some(fee_receiver) => {
+                fee_receiver.amount_received =
+                    fee_receiver.amount_received.saturating_add(transfer.value)
+            }
+            None => fee_receivers.push(FeeReceiver {
+                address: transfer.to,
+                is_token_contract: transfer.to == token,
+                is_owner_related: related_accounts.contains(&transfer.to),
+                amount_received: transfer.value,
+                ..Default::default()
+            }),
+        }
+    }
+
+    for fee_receiver in fee_receivers.iter_mut() {
+        fee_receiver.share_percentage =
+            u256_div_u256_to_f64(fee_receiver.amount_received, amount_traded)
+                .ok()
+                .map(|share| 100_f64 * share);
+    }
+    fee_receivers.sort_by_key(|fee_receiver| std::cmp::Reverse(fee_receiver.amount_received));
+
+    fee_receivers
+}
+
+impl AnvilTestSimulator {
+    /// Finds the receivers of the token's fees in the traces of the simulated trades, and checks
+    /// which of them are contracts.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token traded.
+    /// * `chain` - The chain the token is on.
+    /// * `pair_address` - The pair / pool the token was traded on.
+    /// * `traces` - The traces of the simulated buy, transfer and sell.
+    /// * `related_accounts` - The owner, admins and deployer of the token.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<FeeReceiver>>` - The receivers, largest first.
+    pub async fn find_fee_receivers(
+        &self,
+        token: Address,
+        chain: &Chain,
+        pair_address: Address,
+        traces: &[TraceReport],
+        related_accounts: &[Address],
+    ) -> Result<Vec<FeeReceiver>> {
+        let transfers = traces
+            .iter()
+            .flat_map(|trace| trace.transfers.iter().cloned())
+            .collect::<Vec<_>>();
+        let trade_parties = [
+            pair_address,
+            self.sender,
+            self.second_signed_client.address(),
+        ];
+
+        let mut fee_receivers = fee_receivers_from_transfers(
+            token,
+            chain,
+            &transfers,
+            &trade_parties,
+            related_accounts,
+        );
+        for fee_receiver in fee_receivers.iter_mut() {
+            let code = self.client.get_code(fee_receiver.address, None).await?;
+            fee_receiver.is_contract = !code.is_empty();
+        }
+
+        Ok(fee_receivers)
+    }
+}
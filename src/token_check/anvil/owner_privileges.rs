@@ -0,0 +1,456 @@
+//! This module proves (or disproves) that the owner of a token can actually trap holders.
+//!
+//! The AI review of the source code only flags functions that *look* dangerous. Here, after the test
+//! buy, the owner is impersonated on the anvil fork with `anvil_impersonateAccount` and every
+//! privileged function found in the verified ABI (fee setters, blacklists, trading switches and
+//! transaction limits) is called with arguments that would hurt our wallet. The sell is then
+//! re-attempted to see whether that owner action makes the token unsellable. Each action runs inside
+//! an `evm_snapshot` that is reverted afterwards.
+
+use anyhow::Result;
+use ethers::abi::{Abi, Function, ParamType, StateMutability, Token};
+use ethers::providers::Middleware;
+use ethers::types::{Address, TransactionRequest, U256};
+use log::error;
+
+use crate::app_config::{HONEYPOT_TAX_PERCENTAGE, PRIVILEGED_FEE_PROBE_VALUES};
+use crate::data::token_data::ERC20Token;
+use crate::dex::dex_data::TokenDexData;
+use crate::token_check::anvil::tx_trait::Txs;
+
+use super::simlator::AnvilTestSimulator;
+
+/// The kind of harm a privileged function can do to holders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PrivilegedAction {
+    /// Sets buy / sell / transfer fees.
+    SetFees,
+    /// Blacklists (or flags as a bot) a wallet.
+    Blacklist,
+    /// Pauses or disables trading.
+    PauseTrading,
+    /// Sets max transaction, max wallet or max sell amounts.
+    LimitTransactions,
+}
+
+/// The outcome of the owner calling a single privileged function.
+///
+/// # Fields
+///
+/// * `function_signature` - The function called, e.g. `setFees(uint256,uint256)`.
+/// * `action` - The kind of harm the function can do.
+/// * `owner_call_succeeded` - Whether the owner's call went through.
+/// * `sell_succeeded_after` - Whether our wallet could still sell its full balance afterwards.
+/// * `sell_tax_after` - The sell tax afterwards (0.0 to 100.0), `None` if the sale failed.
+#[derive(Clone, Debug)]
+pub struct OwnerActionCheck {
+    pub function_signature: String,
+    pub action: PrivilegedAction,
+    pub owner_call_succeeded: bool,
+    pub sell_succeeded_after: bool,
+    pub sell_tax_after: Option<f64>,
+}
+
+impl OwnerActionCheck {
+    /// Returns `true` if the owner could call the function and it left our wallet unable to sell
+    /// (or only able to sell at a honeypot-level tax).
+    pub fn makes_token_unsellable(&self) -> bool {
+        self.owner_call_succeeded
+            && (!self.sell_succeeded_after
+                || self.sell_tax_after.unwrap_or(0.0) >= HONEYPOT_TAX_PERCENTAGE)
+    }
+}
+
+/// Owner actions simulated on the fork.
+///
+/// # Fields
+///
+/// * `privileged_account` - The account impersonated (the owner, or an admin role holder), `None` if
+///   the token has no active owner or no verified ABI, in which case nothing was simulated.
+/// * `actions` - The outcome of each privileged function called.
+#[derive(Clone, Debug, Default)]
+pub struct OwnerPrivilegeCheck {
+    pub privileged_account: Option<Address>,
+    pub actions: Vec<OwnerActionCheck>,
+}
+
+impl OwnerPrivilegeCheck {
+    /// Returns `true` if at least one owner action made the token unsellable.
+    pub fn can_trap_holders(&self) -> bool {
+        self.actions
+            .iter()
+            .any(OwnerActionCheck::makes_token_unsellable)
+    }
+}
+
+/// Classifies a function from the token's ABI by the harm it could do to holders.
+///
+/// Only state changing functions are considered, and functions that undo a restriction or hand over
+/// control (`unpause`, `removeFromBlacklist`, `renounceOwnership`, `excludeFromFee`, ...) are ignored.
+///
+/// # Arguments
+///
+/// * `function` - The ABI function to classify.
+///
+/// # Returns
+///
+/// * `Option<PrivilegedAction>` - The kind of harm, or `None` if the function is not privileged.
+pub fn classify_privileged_function(function: &Function) -> Option<PrivilegedAction> {
+    if matches!(
+        function.state_mutability,
+        StateMutability::View | StateMutability::Pure
+    ) {
+        return None;
+    }
+
+    let name = function.name.to_lowercase();
+    let ignored_prefixes = [
+        "un", "remove", "renounce", "exclude", "include", "transfer", "approve",
+    ];
+    if ignored_prefixes
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+    {
+        return None;
+    }
+
+    let has_param = |predicate: fn(&ParamType) -> bool| {
+        function.inputs.iter().any(|input| predicate(&input.kind))
+    };
+    let has_uint_param = has_param(|kind| matches!(kind, ParamType::Uint(_)));
+    let has_bool_param = has_param(|kind| matches!(kind, ParamType::Bool));
+    let words = function_name_words(&function.name);
+    let name_has = |phrases: &[&str]| phrases.iter().any(|phrase| has_phrase(&words, phrase));
+
+    // `enableTrading()` or `openTrading()` can only turn trading on: trading switches are only
+    // harmful if they take a bool or their name turns trading off.
+    let switches_trading = name_has(&["trading", "tradable", "tradeable", "sell enabled"])
+        && (has_bool_param || name_has(&["disable", "stop", "close", "halt"]));
+
+    if name_has(&[
+        "blacklist",
+        "blacklisted",
+        "black list",
+        "blocklist",
+        "block list",
+        "denylist",
+        "deny list",
+        "bot",
+        "sniper",
+        "ban",
+        "banned",
+        "block address",
+        "block wallet",
+    ]) {
+        Some(PrivilegedAction::Blacklist)
+    } else if name_has(&["pause", "paused", "disable sell"]) || switches_trading {
+        Some(PrivilegedAction::PauseTrading)
+    } else if name_has(&["fee", "tax", "taxes"])
+        && has_uint_param
+        && !name_has(&["wallet", "receiver", "address", "threshold"])
+    {
+        Some(PrivilegedAction::SetFees)
+    } else if name_has(&[
+        "max tx",
+        "max transaction",
+        "max wallet",
+        "max sell",
+        "max buy",
+        "limit",
+    ]) && has_uint_param
+    {
+        Some(PrivilegedAction::LimitTransactions)
+    } else {
+        None
+    }
+}
+
+/// Splits a function name into lowercase words at camelCase and snake_case boundaries, e.g.
+/// `setMaxTXAmount` into `set`, `max`, `tx`, `amount`.
+fn function_name_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        let starts_word = c.is_uppercase()
+            && i > 0
+            && (!chars[i - 1].is_uppercase()
+                || chars.get(i + 1).is_some_and(|next| next.is_lowercase()));
+        if starts_word && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+/// Returns `true` if the words contain the phrase's words in a row, each word matching exactly or
+/// with a plural "s" (`bot` matches `setBots` but neither `setRobot` nor `bottom`).
+fn has_phrase(words: &[String], phrase: &str) -> bool {
+    let phrase_words: Vec<&str> = phrase.split(' ').collect();
+    words.windows(phrase_words.len()).any(|window| {
+        window.iter().zip(&phrase_words).all(|(word, phrase_word)| {
+            word == phrase_word || word.strip_suffix('s') == Some(*phrase_word)
+        })
+    })
+}
+
+/// Builds the argument sets to call a privileged function with, in the order they should be tried.
+///
+/// Addresses are set to our wallet, bools to whatever enables the restriction, and integers to the
+/// most harmful values (the highest fees, the lowest limits). Fee setters are tried with each of the
+/// [`PRIVILEGED_FEE_PROBE_VALUES`], as contracts use percentages, basis points or other precisions.
+///
+/// # Arguments
+///
+/// * `function` - The privileged function.
+/// * `action` - The kind of harm the function can do.
+/// * `target_wallet` - The wallet the restriction should be applied to.
+///
+/// # Returns
+///
+/// * `Vec<Vec<Token>>` - The argument sets, empty if the function takes a parameter type we cannot fill.
+pub fn privileged_call_arguments(
+    function: &Function,
+    action: &PrivilegedAction,
+    target_wallet: Address,
+) -> Vec<Vec<Token>> {
+    let uint_values: Vec<u64> = match action {
+        PrivilegedAction::SetFees => PRIVILEGED_FEE_PROBE_VALUES.to_vec(),
+        PrivilegedAction::LimitTransactions => vec![0, 1],
+        PrivilegedAction::Blacklist | PrivilegedAction::PauseTrading => vec![0],
+    };
+
+    // Names like `pause(bool)` or `disableTrading(bool)` restrict on `true`, while names like
+    // `setTradingEnabled(bool)` or `enableTrading(bool)` restrict on `false`.
+    let words = function_name_words(&function.name);
+    let restricting_bool = match action {
+        PrivilegedAction::PauseTrading => ["pause", "paused", "disable", "stop", "lock"]
+            .iter()
+            .any(|word| has_phrase(&words, word)),
+        _ => true,
+    };
+
+    uint_values
+        .into_iter()
+        .map(|uint_value| {
+            function
+                .inputs
+                .iter()
+                .map(|input| match &input.kind {
+                    ParamType::Address => Some(Token::Address(target_wallet)),
+                    ParamType::Array(kind) if **kind == ParamType::Address => {
+                        Some(Token::Array(vec![Token::Address(target_wallet)]))
+                    }
+                    ParamType::Bool => Some(Token::Bool(restricting_bool)),
+                    ParamType::Uint(_) => Some(Token::Uint(U256::from(uint_value))),
+                    _ => None,
+                })
+                .collect::<Option<Vec<Token>>>()
+        })
+        .collect::<Option<Vec<Vec<Token>>>>()
+        .unwrap_or_default()
+}
+
+impl AnvilTestSimulator {
+    /// Lets the fork accept unsigned transactions from `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The account to impersonate.
+    pub async fn impersonate_account(&self, account: Address) -> Result<()> {
+        self.client
+            .request::<_, ()>("anvil_impersonateAccount", [account])
+            .await?;
+        if let Ok(mut impersonated_accounts) = self.impersonated_accounts.lock() {
+            impersonated_accounts.insert(account);
+        }
+        Ok(())
+    }
+
+    /// Funds an impersonated account for gas. This changes the account's balance on the fork, so it
+    /// should be called inside a snapshot that is reverted afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The account to fund.
+    pub async fn fund_account_for_gas(&self, account: Address) -> Result<()> {
+        self.client
+            .request::<_, ()>(
+                "anvil_setBalance",
+                [
+                    format!("{:#x}", account),
+                    "0x8ac7230489e80000".to_string(), // 10 ETH
+                ],
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Stops impersonating `account`.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The account passed to [`AnvilTestSimulator::impersonate_account`].
+    pub async fn stop_impersonating_account(&self, account: Address) -> Result<()> {
+        self.client
+            .request::<_, ()>("anvil_stopImpersonatingAccount", [account])
+            .await?;
+        if let Ok(mut impersonated_accounts) = self.impersonated_accounts.lock() {
+            impersonated_accounts.remove(&account);
+        }
+        Ok(())
+    }
+
+    /// Calls every privileged function in the token's ABI as the owner and re-attempts the sell after each.
+    ///
+    /// This function performs the following steps:
+    /// 1. Impersonates the privileged account.
+    /// 2. For each privileged function, takes a snapshot, funds the privileged account for gas and
+    ///    calls the function with each argument set from [`privileged_call_arguments`] until one goes
+    ///    through.
+    /// 3. If the call went through, attempts to sell our full balance and measures the sell tax.
+    /// 4. Reverts to the snapshot before moving to the next function.
+    /// 5. Reverts the fork and stops impersonating the privileged account, also when a call failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's top liquidity pair / pool.
+    /// * `token` - The token that has just been bought.
+    /// * `privileged_account` - The token owner, or an admin role holder.
+    /// * `abi` - The token's verified ABI (the implementation's ABI for proxies).
+    ///
+    /// # Returns
+    ///
+    /// * `Result<OwnerPrivilegeCheck>` - The outcome of each privileged function called.
+    pub async fn run_owner_privilege_check(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        privileged_account: Address,
+        abi: &Abi,
+    ) -> Result<OwnerPrivilegeCheck> {
+        let snapshot_id = self.snapshot().await?;
+        self.impersonate_account(privileged_account).await?;
+
+        let actions = self
+            .call_privileged_functions(dex_data, token, privileged_account, abi)
+            .await;
+
+        self.revert_to_snapshot(snapshot_id).await?;
+        self.stop_impersonating_account(privileged_account).await?;
+
+        Ok(OwnerPrivilegeCheck {
+            privileged_account: Some(privileged_account),
+            actions: actions?,
+        })
+    }
+
+    /// Runs steps 2 to 4 of [`AnvilTestSimulator::run_owner_privilege_check`] as the impersonated
+    /// privileged account.
+    async fn call_privileged_functions(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        privileged_account: Address,
+        abi: &Abi,
+    ) -> Result<Vec<OwnerActionCheck>> {
+        let mut actions = Vec::new();
+        for function in abi.functions() {
+            let action = match classify_privileged_function(function) {
+                Some(action) => action,
+                None => continue,
+            };
+            let function_signature = function.signature();
+            println!("calling {} as owner", function_signature);
+
+            let mut action_check = OwnerActionCheck {
+                function_signature,
+                action: action.clone(),
+                owner_call_succeeded: false,
+                sell_succeeded_after: false,
+                sell_tax_after: None,
+            };
+
+            for arguments in privileged_call_arguments(function, &action, self.sender) {
+                let snapshot_id = self.snapshot().await?;
+                self.fund_account_for_gas(privileged_account).await?;
+
+                if self
+                    .call_as(privileged_account, token.address, function, &arguments)
+                    .await?
+                {
+                    action_check.owner_call_succeeded = true;
+
+                    let balance = self
+                        .get_wallet_token_balance_by_address(token.address)
+                        .await?;
+                    if let Ok(sell_swap) = self
+                        .simulate_selling_token_on_dex(dex_data, token, balance)
+                        .await
+                    {
+                        let balance_after_sell = self
+                            .get_wallet_token_balance_by_address(token.address)
+                            .await?;
+                        action_check.sell_succeeded_after =
+                            balance_after_sell.is_zero() && !sell_swap.amount_out.is_zero();
+                        action_check.sell_tax_after = sell_swap.tax_percentage();
+                    }
+                }
+
+                self.revert_to_snapshot(snapshot_id).await?;
+                if action_check.owner_call_succeeded {
+                    break;
+                }
+            }
+
+            if action_check.makes_token_unsellable() {
+                println!(
+                    "owner can make token unsellable with {}",
+                    action_check.function_signature
+                );
+            }
+            actions.push(action_check);
+        }
+
+        Ok(actions)
+    }
+
+    /// Sends an unsigned transaction from an impersonated account calling `function` on `contract`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - Whether the transaction was mined without reverting.
+    async fn call_as(
+        &self,
+        from: Address,
+        contract: Address,
+        function: &Function,
+        arguments: &[Token],
+    ) -> Result<bool> {
+        let data = function.encode_input(arguments)?;
+        let tx = TransactionRequest::new().from(from).to(contract).data(data);
+
+        let pending_tx = match self.client.send_transaction(tx, None).await {
+            Ok(pending_tx) => pending_tx,
+            Err(e) => {
+                error!("{} reverted: {}", function.signature(), e);
+                return Ok(false);
+            }
+        };
+        let receipt = pending_tx.await?;
+
+        Ok(receipt.and_then(|receipt| receipt.status) == Some(1.into()))
+    }
+}
@@ -0,0 +1,386 @@
+//! This module measures the damage a rug pull would do to holders.
+//!
+//! `percentage_liquidity_locked_or_burned` says how much liquidity cannot be pulled, but not what
+//! happens if the rest is. Here the largest holders of unlocked liquidity are impersonated on the
+//! anvil fork with `anvil_impersonateAccount`, and their liquidity is removed through the pool's swap
+//! venue: the Uniswap V2 or Aerodrome router for ERC20 LP tokens, the Uniswap V3 or Aerodrome
+//! Slipstream position manager for position NFTs. Our wallet's sell is quoted before and after,
+//! giving a concrete "worst case if the team rugs" price impact. The removal runs inside an
+//! `evm_snapshot` that is reverted afterwards.
+
+use anyhow::Result;
+use ethers::types::{Address, U256};
+use log::error;
+
+use crate::abi::erc20::ERC20;
+use crate::abi::uniswap_pair::UNISWAP_PAIR;
+use crate::abi::uniswap_router_v2::UNISWAP_V2_ROUTER;
+use crate::abi::uniswap_v3_position_manager::{
+    CollectParams, DecreaseLiquidityParams, UNISWAP_V3_POSITION_MANAGER,
+};
+use crate::data::chain_data::CHAIN_DATA;
+use crate::data::token_data::ERC20Token;
+use crate::dex::dex_data::TokenDexData;
+use crate::dex::swap_venue::{swap_venue, SwapVenue};
+use crate::token_check::anvil::tx_trait::Txs;
+use crate::token_check::token_holder_check::u256_div_u256_to_f64;
+
+use super::simlator::AnvilTestSimulator;
+
+/// Outcome of pulling the unlocked liquidity on the fork.
+///
+/// # Fields
+///
+/// * `lp_holders_removed` - The unlocked LP holders whose liquidity was removed.
+/// * `base_token_reserve_before` - The pool's base token balance before the removal.
+/// * `base_token_reserve_after` - The pool's base token balance after the removal.
+/// * `liquidity_removed_percentage` - Percentage of the pool's base token reserve pulled (0.0 to 100.0).
+/// * `sell_amount_out_before` - Base token quoted for selling our balance before the removal.
+/// * `sell_amount_out_after` - Base token quoted for selling our balance after the removal, zero if
+///   the pool can no longer quote the sell.
+/// * `sell_price_impact_percentage` - How much less a holder's sell returns after the removal
+///   (0.0 to 100.0), `None` if nothing was simulated.
+#[derive(Clone, Debug, Default)]
+pub struct RugPullCheck {
+    pub lp_holders_removed: Vec<Address>,
+    pub base_token_reserve_before: U256,
+    pub base_token_reserve_after: U256,
+    pub liquidity_removed_percentage: Option<f64>,
+    pub sell_amount_out_before: U256,
+    pub sell_amount_out_after: U256,
+    pub sell_price_impact_percentage: Option<f64>,
+}
+
+/// Returns by how much `after` is lower than `before`, as a percentage (0.0 to 100.0).
+///
+/// # Arguments
+///
+/// * `before` - The amount before the change.
+/// * `after` - The amount after the change.
+///
+/// # Returns
+///
+/// * `Option<f64>` - The percentage decrease, 0.0 if `after` did not decrease, `None` if `before` is zero.
+pub fn percentage_decrease(before: U256, after: U256) -> Option<f64> {
+    if before.is_zero() {
+        return None;
+    }
+    let remaining = u256_div_u256_to_f64(after.min(before), before).ok()?;
+    Some(100_f64 * (1_f64 - remaining))
+}
+
+impl AnvilTestSimulator {
+    /// Removes the liquidity of the largest unlocked LP holders and measures the impact on a holder's sell.
+    ///
+    /// This function performs the following steps:
+    /// 1. Reads the pool's base token reserve and quotes selling `amount_to_sell`.
+    /// 2. Takes a snapshot.
+    /// 3. Impersonates each LP holder and removes all of their liquidity in the pool.
+    /// 4. Reads the reserve again and re-quotes the sell.
+    /// 5. Reverts to the snapshot, so the pool's liquidity is restored, also when a removal failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's top liquidity pair / pool.
+    /// * `token` - The token that has just been bought.
+    /// * `lp_holders` - The holders of unlocked liquidity, largest first.
+    /// * `amount_to_sell` - The amount of token the sell is quoted for.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<RugPullCheck>` - The liquidity removed and the resulting price impact. Empty if the pool's
+    ///   dex has no swap venue.
+    pub async fn run_rug_pull_check(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holders: &[Address],
+        amount_to_sell: U256,
+    ) -> Result<RugPullCheck> {
+        let venue = match swap_venue(&dex_data.dex) {
+            Some(venue) => venue,
+            None => {
+                println!("rug pull simulation not supported on {:?}", dex_data.dex);
+                return Ok(RugPullCheck::default());
+            }
+        };
+
+        let base_token_address: Address = dex_data.base_token_address.parse()?;
+        let base_token = ERC20::new(base_token_address, self.client.clone());
+
+        let base_token_reserve_before = base_token.balance_of(dex_data.pair_address).call().await?;
+        let sell_amount_out_before = venue
+            .quote(
+                dex_data,
+                token.address,
+                base_token_address,
+                amount_to_sell,
+                &token.chain,
+                &self.client,
+            )
+            .await?;
+
+        // The fork is reverted even if pulling the liquidity fails, so the audit can carry on.
+        let snapshot_id = self.snapshot().await?;
+        let pulled = async {
+            let lp_holders_removed = self
+                .pull_liquidity(venue, dex_data, token, lp_holders)
+                .await?;
+
+            let base_token_reserve_after =
+                base_token.balance_of(dex_data.pair_address).call().await?;
+            // A drained pool cannot quote the sell at all.
+            let sell_amount_out_after = venue
+                .quote(
+                    dex_data,
+                    token.address,
+                    base_token_address,
+                    amount_to_sell,
+                    &token.chain,
+                    &self.client,
+                )
+                .await
+                .unwrap_or_default();
+
+            Ok::<_, anyhow::Error>((
+                lp_holders_removed,
+                base_token_reserve_after,
+                sell_amount_out_after,
+            ))
+        }
+        .await;
+        self.revert_to_snapshot(snapshot_id).await?;
+        let (lp_holders_removed, base_token_reserve_after, sell_amount_out_after) = pulled?;
+
+        let rug_pull_check = RugPullCheck {
+            lp_holders_removed,
+            base_token_reserve_before,
+            base_token_reserve_after,
+            liquidity_removed_percentage: percentage_decrease(
+                base_token_reserve_before,
+                base_token_reserve_after,
+            ),
+            sell_amount_out_before,
+            sell_amount_out_after,
+            sell_price_impact_percentage: percentage_decrease(
+                sell_amount_out_before,
+                sell_amount_out_after,
+            ),
+        };
+        println!(
+            "pulling unlocked liquidity removes {:?}% of the pool, sell price impact {:?}%",
+            rug_pull_check.liquidity_removed_percentage,
+            rug_pull_check.sell_price_impact_percentage
+        );
+
+        Ok(rug_pull_check)
+    }
+
+    /// Impersonates each LP holder and removes all of their liquidity through the venue. Each holder
+    /// stops being impersonated once their removal is done, whether or not it went through.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Address>>` - The LP holders whose liquidity was removed.
+    async fn pull_liquidity(
+        &self,
+        venue: &dyn SwapVenue,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holders: &[Address],
+    ) -> Result<Vec<Address>> {
+        let mut lp_holders_removed = Vec::new();
+        for lp_holder in lp_holders {
+            println!("pulling liquidity of {:?}", lp_holder);
+            self.impersonate_account(*lp_holder).await?;
+            let removed = async {
+                self.fund_account_for_gas(*lp_holder).await?;
+                venue
+                    .remove_liquidity(self, dex_data, token, *lp_holder)
+                    .await
+            }
+            .await;
+            self.stop_impersonating_account(*lp_holder).await?;
+
+            if removed? {
+                lp_holders_removed.push(*lp_holder);
+            }
+        }
+        Ok(lp_holders_removed)
+    }
+
+    /// Removes all of an impersonated holder's liquidity from a Uniswap V2 pair through the router.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - Whether any liquidity was removed.
+    pub async fn remove_liquidity_uniswap_v2(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holder: Address,
+    ) -> Result<bool> {
+        let router_address: Address = CHAIN_DATA
+            .get_address(&token.chain)
+            .uniswap_v2_router
+            .parse()?;
+        let pair = UNISWAP_PAIR::new(dex_data.pair_address, self.client.clone());
+        let router = UNISWAP_V2_ROUTER::new(router_address, self.client.clone());
+
+        let liquidity = pair.balance_of(lp_holder).call().await?;
+        if liquidity.is_zero() {
+            return Ok(false);
+        }
+
+        // Set a deadline timestamp 5 minutes in the future.
+        let deadline = self.get_current_timestamp().await? + 300;
+        let approve = pair.approve(router_address, liquidity).from(lp_holder);
+        let remove_liquidity = router
+            .remove_liquidity(
+                dex_data.token_0,
+                dex_data.token_1,
+                liquidity,
+                U256::zero(),
+                U256::zero(),
+                lp_holder,
+                U256::from(deadline),
+            )
+            .from(lp_holder);
+
+        match approve.send().await {
+            Ok(pending_tx) => {
+                pending_tx.await?;
+            }
+            Err(e) => {
+                error!("approving LP tokens of {:?} failed: {}", lp_holder, e);
+                return Ok(false);
+            }
+        }
+        let removed = match remove_liquidity.send().await {
+            Ok(pending_tx) => {
+                pending_tx.await?;
+                true
+            }
+            Err(e) => {
+                error!("removing liquidity of {:?} failed: {}", lp_holder, e);
+                false
+            }
+        };
+
+        Ok(removed)
+    }
+
+    /// Removes all of an impersonated holder's liquidity from a Uniswap V3 pool through the position manager.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - Whether any liquidity was removed.
+    pub async fn remove_liquidity_uniswap_v3(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        lp_holder: Address,
+    ) -> Result<bool> {
+        let position_manager_address: Address = CHAIN_DATA
+            .get_address(&token.chain)
+            .uniswap_v3_position_manager
+            .parse()?;
+        self.remove_liquidity_from_positions(
+            position_manager_address,
+            dex_data,
+            dex_data.fee,
+            lp_holder,
+        )
+        .await
+    }
+
+    /// Fully decreases every position NFT an impersonated holder owns in a concentrated liquidity pool,
+    /// and collects the tokens.
+    ///
+    /// Works with Uniswap V3 style position managers, whose positions identify the pool by its tokens
+    /// and `pool_key`: the fee tier for Uniswap V3, the tick spacing for Aerodrome Slipstream.
+    ///
+    /// # Arguments
+    ///
+    /// * `position_manager_address` - The pool's NonfungiblePositionManager.
+    /// * `dex_data` - The pool.
+    /// * `pool_key` - The fee tier or tick spacing the positions of the pool have.
+    /// * `lp_holder` - The impersonated holder.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - Whether any liquidity was removed.
+    pub async fn remove_liquidity_from_positions(
+        &self,
+        position_manager_address: Address,
+        dex_data: &TokenDexData,
+        pool_key: u32,
+        lp_holder: Address,
+    ) -> Result<bool> {
+        let position_manager =
+            UNISWAP_V3_POSITION_MANAGER::new(position_manager_address, self.client.clone());
+
+        // Find the holder's positions in this pool.
+        let position_count = position_manager.balance_of(lp_holder).call().await?;
+        let mut positions = Vec::new();
+        for index in 0..position_count.as_u64() {
+            let token_id = position_manager
+                .token_of_owner_by_index(lp_holder, U256::from(index))
+                .call()
+                .await?;
+            let (_, _, token_0, token_1, position_pool_key, _, _, liquidity, _, _, _, _) =
+                position_manager.positions(token_id).call().await?;
+            if token_0 == dex_data.token_0
+                && token_1 == dex_data.token_1
+                && position_pool_key == pool_key
+                && liquidity > 0
+            {
+                positions.push((token_id, liquidity));
+            }
+        }
+
+        let deadline = self.get_current_timestamp().await? + 300;
+        let mut removed = false;
+        for (token_id, liquidity) in positions {
+            let decrease_liquidity = position_manager
+                .decrease_liquidity(DecreaseLiquidityParams {
+                    token_id,
+                    liquidity,
+                    amount_0_min: U256::zero(),
+                    amount_1_min: U256::zero(),
+                    deadline: U256::from(deadline),
+                })
+                .from(lp_holder);
+            match decrease_liquidity.send().await {
+                Ok(pending_tx) => {
+                    pending_tx.await?;
+                }
+                Err(e) => {
+                    error!("removing liquidity of position {} failed: {}", token_id, e);
+                    continue;
+                }
+            }
+
+            let collect = position_manager
+                .collect(CollectParams {
+                    token_id,
+                    recipient: lp_holder,
+                    amount_0_max: u128::MAX,
+                    amount_1_max: u128::MAX,
+                })
+                .from(lp_holder);
+            // The decreased liquidity only leaves the pool once it is collected.
+            match collect.send().await {
+                Ok(pending_tx) => {
+                    pending_tx.await?;
+                    removed = true;
+                }
+                Err(e) => {
+                    error!("collecting tokens of position {} failed: {}", token_id, e);
+                }
+            };
+        }
+
+        Ok(removed)
+    }
+}
@@ -2,6 +2,9 @@
 //! blockchain. It sets up an Anvil instance with forking based on a provided Geth RPC URL, creates wallets,
 //! and initializes signed clients that can interact with the Anvil provider.
 //!
+//! The simulation wallets are ephemeral: they are generated from random keys for every instance and
+//! funded with `anvil_setBalance`, so no private keys are needed to run a simulation.
+//!
 //! The simulator is useful for testing transaction flows and blockchain interactions in an isolated
 //! and controllable environment.
 
@@ -10,13 +13,12 @@ use ethers::{
     core::k256::ecdsa::SigningKey,
     middleware::SignerMiddleware,
     providers::{Middleware, Provider, Ws},
-    signers::{Signer, Wallet},
+    signers::{LocalWallet, Signer, Wallet},
     types::{Address, Chain},
     utils::{Anvil, AnvilInstance},
 };
-use std::sync::Arc;
-
-use crate::utils::tx::{get_test_wallet, get_wallet};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
 
 /// The starting balance for the simulated account.
 pub const STARTING_BALANCE: f64 = 1000.0;
@@ -37,6 +39,9 @@ pub struct AnvilTestSimulator {
     pub anvil: AnvilInstance,
     /// The address of the primary sender.
     pub sender: Address,
+    /// The accounts currently impersonated on the fork. Impersonation is not undone by reverting a
+    /// snapshot, so these are tracked to stop impersonating them when the instance is reused.
+    pub impersonated_accounts: Mutex<HashSet<Address>>,
 }
 
 impl AnvilTestSimulator {
@@ -88,10 +93,10 @@ impl AnvilTestSimulator {
         let provider = Provider::<Ws>::connect(anvil_ws_url).await?;
         let client = Arc::new(provider.clone());
 
-        // Retrieve primary and test wallets.
+        // Generate ephemeral primary and test wallets, they only ever exist on the fork.
         println!("getting wallets setup");
-        let wallet = get_wallet(chain)?; // Primary wallet.
-        let second_wallet = get_test_wallet(chain)?; // Secondary/test wallet.
+        let wallet = LocalWallet::new(&mut rand::thread_rng()).with_chain_id(*chain as u64); // Primary wallet.
+        let second_wallet = LocalWallet::new(&mut rand::thread_rng()).with_chain_id(*chain as u64); // Secondary/test wallet.
         let from_address = wallet.address();
 
         // Create signer middleware clients for both wallets.
@@ -99,28 +104,38 @@ impl AnvilTestSimulator {
         let signed_client = Arc::new(SignerMiddleware::new(provider.clone(), wallet));
         let second_signed_client = Arc::new(SignerMiddleware::new(provider, second_wallet));
 
-        // Set the balance for the primary wallet using the Anvil RPC method.
-        // The balance is hardcoded to "0x3635c9adc5dea00000", which corresponds to 100 ETH.
-        signed_client
-            .provider()
-            .request::<_, ()>(
-                "anvil_setBalance",
-                [
-                    format!("{:#x}", from_address),
-                    "0x3635c9adc5dea00000".to_string(), // 100 ETH
-                ],
-            )
-            .await?;
-
-        // Construct and return the simulator instance.
+        // Construct the simulator instance and fund both wallets.
         let simulator = Self {
             signed_client,
             second_signed_client,
             client,
             anvil,
             sender: from_address,
+            impersonated_accounts: Mutex::new(HashSet::new()),
         };
+        simulator.fund_wallet().await?;
 
         Ok(simulator)
     }
+
+    /// Sets the balance of the primary and test wallets using the Anvil RPC method.
+    ///
+    /// The balance is hardcoded to "0x3635c9adc5dea00000", which corresponds to 100 ETH. The test
+    /// wallet needs it for gas when sending tokens back. This needs to be called again after the fork
+    /// is reset, as resetting discards the balances.
+    pub async fn fund_wallet(&self) -> Result<()> {
+        for wallet_address in [self.sender, self.second_signed_client.address()] {
+            self.signed_client
+                .provider()
+                .request::<_, ()>(
+                    "anvil_setBalance",
+                    [
+                        format!("{:#x}", wallet_address),
+                        "0x3635c9adc5dea00000".to_string(), // 100 ETH
+                    ],
+                )
+                .await?;
+        }
+        Ok(())
+    }
 }
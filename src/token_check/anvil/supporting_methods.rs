@@ -1,92 +1,97 @@
 use super::simlator::AnvilTestSimulator;
+use super::trace_report::SimulatedTx;
 use crate::abi::erc20::ERC20;
-use anyhow::Result;
-use ethers::types::{
-    CallFrame, GethDebugTracerType, GethDebugTracingOptions, GethTrace, GethTraceFrame, H256, U256,
-};
-use ethers::{providers::Middleware, types::Address};
-use log::debug;
+use ethers::types::Address;
+use ethers::types::{CallFrame, U256};
+use log::error;
+
+/// The outcome of a dummy transfer to the second wallet and back.
+///
+/// # Fields
+///
+/// * `received_by_second_wallet` - The amount the second wallet received.
+/// * `received_back` - The amount the first wallet received back.
+/// * `tx` - The transfer to the second wallet, mined or rejected, so it can be traced.
+#[derive(Clone, Debug)]
+pub struct DummyTransfer {
+    pub received_by_second_wallet: U256,
+    pub received_back: U256,
+    pub tx: SimulatedTx,
+}
 
 /// Supporting methods for debugging and diagnosis.
 impl AnvilTestSimulator {
-    /// Traces a transaction given its hash.
-    ///
-    /// This function queries the Ethereum client using `debug_trace_transaction` with custom
-    /// tracing options. If a revert is detected in the call trace, the function logs and prints
-    /// the address of the revert call. Otherwise, it notifies that no revert was found.
-    ///
-    /// # Parameters
-    /// - `tx_hash`: The transaction hash to be traced.
-    ///
-    /// # Returns
-    /// - `Result<()>`: Returns Ok if the transaction trace was successfully processed, or an error otherwise.
-    pub async fn trace_transaction(&self, tx_hash: H256) -> Result<()> {
-        // Configure tracing options to include storage and stack tracing, and select a built-in call tracer.
-        let mut tracing_options = GethDebugTracingOptions::default();
-        tracing_options.disable_storage = Some(false); // Enable storage tracing
-        tracing_options.disable_stack = Some(false); // Enable stack tracing
-        tracing_options.tracer = Some(GethDebugTracerType::BuiltInTracer(
-            ethers::types::GethDebugBuiltInTracerType::CallTracer,
-        ));
-
-        // Retrieve the transaction trace using the provider.
-        let trace = self
-            .signed_client
-            .provider()
-            .debug_trace_transaction(tx_hash, tracing_options)
-            .await?;
-
-        // Process the trace by matching on its type.
-        match trace {
-            GethTrace::Known(GethTraceFrame::CallTracer(ref call_frame)) => {
-                // Recursively search the call trace for a revert.
-                if let Some(revert_call) = find_revert(call_frame) {
-                    debug!("Revert: {:?}", revert_call);
-                    println!("Revert occurred in call to: {:?}", revert_call.to);
-                } else {
-                    println!("No revert found in the trace");
-                }
-            }
-            _ => {
-                println!("Unexpected trace format");
-            }
-        }
-        Ok(())
-    }
-
     /// Performs a dummy token transfer.
     ///
     /// This function simulates a token transfer by moving tokens from one wallet to another and
-    /// then transferring them back using two distinct ERC20 contract instances.
+    /// then transferring whatever the second wallet received back, using two distinct ERC20
+    /// contract instances. The amounts received are returned so a transfer tax can be measured.
+    ///
+    /// If the first transfer is rejected, nothing is received and the rejected transfer is returned
+    /// so it can be traced.
     ///
     /// # Parameters
     /// - `token_address`: The address of the ERC20 token contract.
     /// - `amount`: The amount of tokens to transfer.
     ///
     /// # Returns
-    /// - `Result<()>`: Returns Ok if both transfers are successful, or an error otherwise.
+    /// - `Result<DummyTransfer>`: The amount received by the second wallet and the amount received
+    ///   back by the first wallet, or an error if the transfer back fails.
     pub async fn do_dummy_transfer(
         &self,
         token_address: Address,
         amount: U256,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<DummyTransfer> {
         // Create contract instances for both wallet clients.
         let token_contract_wallet_1 = ERC20::new(token_address, self.signed_client.clone());
         let token_contract_wallet_2 = ERC20::new(token_address, self.second_signed_client.clone());
 
+        let wallet_1 = self.signed_client.address();
+        let wallet_2 = self.second_signed_client.address();
+
         // Transfer tokens from wallet1 to wallet2.
-        token_contract_wallet_1
-            .transfer(self.second_signed_client.address(), amount)
-            .send()
-            .await?;
+        let wallet_2_balance_before = token_contract_wallet_1.balance_of(wallet_2).call().await?;
+        let transfer = token_contract_wallet_1.transfer(wallet_2, amount);
+        let tx = match transfer.send().await {
+            Ok(pending_tx) => {
+                let receipt = pending_tx
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("Transaction receipt not found"))?;
+                SimulatedTx::Mined(receipt.transaction_hash)
+            }
+            Err(e) => {
+                error!("Transfer failed: {:?}", e);
+                return Ok(DummyTransfer {
+                    received_by_second_wallet: U256::zero(),
+                    received_back: U256::zero(),
+                    tx: SimulatedTx::Failed(Box::new(transfer.tx.clone())),
+                });
+            }
+        };
+        let received_by_second_wallet = token_contract_wallet_1
+            .balance_of(wallet_2)
+            .call()
+            .await?
+            .saturating_sub(wallet_2_balance_before);
 
-        // Transfer tokens back from wallet2 to wallet1.
+        // Transfer the received tokens back from wallet2 to wallet1.
+        let wallet_1_balance_before = token_contract_wallet_1.balance_of(wallet_1).call().await?;
         token_contract_wallet_2
-            .transfer(self.signed_client.address(), amount)
+            .transfer(wallet_1, received_by_second_wallet)
             .send()
+            .await?
             .await?;
+        let received_back = token_contract_wallet_1
+            .balance_of(wallet_1)
+            .call()
+            .await?
+            .saturating_sub(wallet_1_balance_before);
 
-        Ok(())
+        Ok(DummyTransfer {
+            received_by_second_wallet,
+            received_back,
+            tx,
+        })
     }
 }
 
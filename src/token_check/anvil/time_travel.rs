@@ -0,0 +1,218 @@
+//! This module detects "time bomb" tokens: tokens that can be bought and sold right after launch but
+//! turn into honeypots after a delay, a cooldown or a hard-coded timestamp.
+//!
+//! After the simulated buy the fork is moved forward with `evm_increaseTime` across several horizons
+//! (see [`TIME_BOMB_HORIZONS`]). At each horizon the wallet's balance is checked and a full sell is
+//! attempted inside an `evm_snapshot`, which is reverted afterwards so every horizon starts from the
+//! same position.
+
+use anyhow::{anyhow, Result};
+use ethers::types::U256;
+
+use crate::app_config::{HONEYPOT_TAX_PERCENTAGE, TIME_BOMB_HORIZONS};
+use crate::data::token_data::ERC20Token;
+use crate::dex::dex_data::TokenDexData;
+use crate::token_check::anvil::tx_trait::Txs;
+
+use super::simlator::AnvilTestSimulator;
+
+/// The token's behaviour at a single point in time after purchase.
+///
+/// # Fields
+///
+/// * `horizon` - Human readable time elapsed since purchase (e.g. "1 day").
+/// * `seconds_after_purchase` - Time elapsed since purchase, in seconds.
+/// * `balance_intact` - Whether the wallet still held at least the amount bought.
+/// * `can_sell` - Whether the full balance could be sold.
+/// * `sell_tax` - The tax taken on the sale (0.0 to 100.0), `None` if the sale failed.
+#[derive(Clone, Debug, Default)]
+pub struct TimeHorizonCheck {
+    pub horizon: String,
+    pub seconds_after_purchase: u64,
+    pub balance_intact: bool,
+    pub can_sell: bool,
+    pub sell_tax: Option<f64>,
+}
+
+impl TimeHorizonCheck {
+    /// Returns `true` if the token could be held and sold at this horizon without a honeypot-level tax.
+    pub fn is_tradable(&self) -> bool {
+        self.balance_intact
+            && self.can_sell
+            && self.sell_tax.unwrap_or(0.0) < HONEYPOT_TAX_PERCENTAGE
+    }
+}
+
+/// Outcome of re-checking the token at every time horizon.
+///
+/// # Fields
+///
+/// * `horizons` - The checks at each horizon, in chronological order.
+/// * `behaviour_changed_at` - The first horizon at which the token's tradability differed from the
+///   first horizon, `None` if it behaved the same throughout.
+#[derive(Clone, Debug, Default)]
+pub struct TimeBombCheck {
+    pub horizons: Vec<TimeHorizonCheck>,
+    pub behaviour_changed_at: Option<String>,
+}
+
+impl TimeBombCheck {
+    /// Builds the check from per-horizon results, finding the first horizon where behaviour changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `horizons` - The checks at each horizon, in chronological order.
+    ///
+    /// # Returns
+    ///
+    /// * `TimeBombCheck` - The horizons together with the horizon at which behaviour changed.
+    pub fn from_horizons(horizons: Vec<TimeHorizonCheck>) -> Self {
+        let behaviour_changed_at = horizons.first().and_then(|first| {
+            horizons
+                .iter()
+                .skip(1)
+                .find(|horizon| horizon.is_tradable() != first.is_tradable())
+                .map(|horizon| horizon.horizon.clone())
+        });
+
+        Self {
+            horizons,
+            behaviour_changed_at,
+        }
+    }
+
+    /// Returns `true` if the token was tradable right after purchase but stopped being tradable later.
+    ///
+    /// A token that only becomes tradable later (e.g. a sell cooldown) is not a time bomb.
+    pub fn is_time_bomb(&self) -> bool {
+        self.horizons
+            .first()
+            .is_some_and(|first| first.is_tradable() && self.behaviour_changed_at.is_some())
+    }
+}
+
+impl AnvilTestSimulator {
+    /// Moves the fork's clock forward and mines a block so the new timestamp takes effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - The number of seconds to advance.
+    pub async fn increase_time(&self, seconds: u64) -> Result<()> {
+        self.client
+            .request::<_, serde_json::Value>("evm_increaseTime", [seconds])
+            .await?;
+        self.client
+            .request::<_, String>("evm_mine", None::<()>)
+            .await?;
+        Ok(())
+    }
+
+    /// Takes a snapshot of the fork's state.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<U256>` - The snapshot id, to be passed to [`AnvilTestSimulator::revert_to_snapshot`].
+    pub async fn snapshot(&self) -> Result<U256> {
+        let snapshot_id = self
+            .client
+            .request::<_, U256>("evm_snapshot", None::<()>)
+            .await?;
+        Ok(snapshot_id)
+    }
+
+    /// Reverts the fork's state (including its clock) to a snapshot. The snapshot is consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot_id` - The id returned by [`AnvilTestSimulator::snapshot`].
+    pub async fn revert_to_snapshot(&self, snapshot_id: U256) -> Result<()> {
+        let reverted = self
+            .client
+            .request::<_, bool>("evm_revert", [snapshot_id])
+            .await?;
+        if !reverted {
+            return Err(anyhow!("Failed to revert to snapshot {}", snapshot_id));
+        }
+        Ok(())
+    }
+
+    /// Re-checks the token's balance and sellability at each of the [`TIME_BOMB_HORIZONS`].
+    ///
+    /// This function performs the following steps for each horizon:
+    /// 1. Advances the fork's clock to the horizon, measured from the time of the call.
+    /// 2. Takes a snapshot.
+    /// 3. Checks the wallet's token balance has not dropped.
+    /// 4. Attempts to sell the full balance and measures the sell tax.
+    /// 5. Reverts to the snapshot, so the wallet holds the bought tokens again.
+    ///
+    /// The fork is left at the last horizon with the wallet's balance untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's top liquidity pair / pool.
+    /// * `token` - The token that has just been bought.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<TimeBombCheck>` - The check at each horizon, and the horizon at which behaviour changed.
+    pub async fn run_time_bomb_check(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+    ) -> Result<TimeBombCheck> {
+        let purchase_timestamp = self.get_current_timestamp().await?;
+        let balance_after_purchase = self
+            .get_wallet_token_balance_by_address(token.address)
+            .await?;
+
+        let mut horizons = Vec::new();
+        for (horizon, seconds_after_purchase) in TIME_BOMB_HORIZONS {
+            println!("checking token {} after purchase", horizon);
+            let elapsed = self
+                .get_current_timestamp()
+                .await?
+                .saturating_sub(purchase_timestamp);
+            if seconds_after_purchase > elapsed {
+                self.increase_time(seconds_after_purchase - elapsed).await?;
+            }
+
+            let snapshot_id = self.snapshot().await?;
+
+            let balance = self
+                .get_wallet_token_balance_by_address(token.address)
+                .await?;
+            let (can_sell, sell_tax) = match self
+                .simulate_selling_token_on_dex(dex_data, token, balance)
+                .await
+            {
+                Ok(sell_swap) => {
+                    let balance_after_sell = self
+                        .get_wallet_token_balance_by_address(token.address)
+                        .await?;
+                    (balance_after_sell.is_zero(), sell_swap.tax_percentage())
+                }
+                Err(err) => {
+                    println!("sell failed {} after purchase: {:?}", horizon, err);
+                    (false, None)
+                }
+            };
+
+            self.revert_to_snapshot(snapshot_id).await?;
+
+            horizons.push(TimeHorizonCheck {
+                horizon: horizon.to_string(),
+                seconds_after_purchase,
+                balance_intact: balance >= balance_after_purchase,
+                can_sell,
+                sell_tax,
+            });
+        }
+
+        let time_bomb_check = TimeBombCheck::from_horizons(horizons);
+        if let Some(horizon) = &time_bomb_check.behaviour_changed_at {
+            println!("token behaviour changed {} after purchase", horizon);
+        }
+
+        Ok(time_bomb_check)
+    }
+}
@@ -0,0 +1,70 @@
+//! This module quantifies the taxes a token charges on buys, sells and transfers.
+//!
+//! During the anvil simulation every swap is quoted (without slippage) right before it is sent, and
+//! the quoted amount is compared with the amount that actually arrived in the wallet. The difference
+//! is the tax the token (or its hidden fee logic) took. Transfers are measured the same way by
+//! comparing the amount sent with the amount the receiving wallet got.
+
+use ethers::types::U256;
+
+use crate::token_check::anvil::trace_report::SimulatedTx;
+use crate::token_check::token_holder_check::u256_div_u256_to_f64;
+
+/// The outcome of a simulated swap.
+///
+/// # Fields
+///
+/// * `expected_amount_out` - The amount quoted by the router / quoter right before the swap (no slippage).
+/// * `amount_out` - The amount the wallet actually received.
+/// * `token_balance` - The wallet's balance of the token being checked after the swap.
+/// * `tx` - The swap transaction, mined or rejected, so it can be traced. `None` if it was never sent.
+#[derive(Clone, Debug, Default)]
+pub struct SimulatedSwap {
+    pub expected_amount_out: U256,
+    pub amount_out: U256,
+    pub token_balance: U256,
+    pub tx: Option<SimulatedTx>,
+}
+
+impl SimulatedSwap {
+    /// Returns the tax taken on the swap as a percentage (0.0 to 100.0).
+    pub fn tax_percentage(&self) -> Option<f64> {
+        tax_percentage(self.expected_amount_out, self.amount_out)
+    }
+}
+
+/// Buy, sell and transfer tax percentages measured during simulation.
+///
+/// Each value is `None` if the corresponding step of the simulation could not be run.
+#[derive(Clone, Debug, Default)]
+pub struct TokenTax {
+    pub buy_tax: Option<f64>,
+    pub sell_tax: Option<f64>,
+    pub transfer_tax: Option<f64>,
+}
+
+/// Calculates the tax taken between an expected and a received amount, as a percentage.
+///
+/// Receiving more than expected (e.g. reflection tokens) is reported as a 0% tax.
+///
+/// # Arguments
+///
+/// * `expected` - The amount that should have been received without any tax.
+/// * `received` - The amount actually received.
+///
+/// # Returns
+///
+/// * `Some(f64)` between 0.0 and 100.0, or `None` if nothing was expected.
+pub fn tax_percentage(expected: U256, received: U256) -> Option<f64> {
+    if expected.is_zero() {
+        return None;
+    }
+
+    if received >= expected {
+        return Some(0.0);
+    }
+
+    u256_div_u256_to_f64(expected - received, expected)
+        .ok()
+        .map(|ratio| 100_f64 * ratio)
+}
@@ -0,0 +1,481 @@
+//! This module turns the call trace of a simulated buy, transfer or sell into a structured report, so
+//! an audit can show *why* a step failed (e.g. "sell reverted in transfer(address,uint256) on 0x…:
+//! blacklisted") rather than only that it did.
+//!
+//! Mined transactions are traced with `debug_traceTransaction`, transactions that could not be sent
+//! (the node rejects calls that revert) are replayed with `debug_traceCall`. Both use the built-in
+//! call tracer with logs enabled. Function selectors, revert reasons, custom errors and events are
+//! decoded against the ERC20 and Uniswap ABIs, plus the token's verified ABI when there is one.
+
+use anyhow::{anyhow, Result};
+use ethers::abi::{Abi, Function, RawLog, Token};
+use ethers::providers::Middleware;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::Address;
+use ethers::types::{
+    CallConfig, CallFrame, GethDebugBuiltInTracerConfig, GethDebugBuiltInTracerType,
+    GethDebugTracerConfig, GethDebugTracerType, GethDebugTracingCallOptions,
+    GethDebugTracingOptions, GethTrace, GethTraceFrame, NameOrAddress, H256, I256, U256,
+};
+
+use crate::abi::erc20::ERC20_ABI;
+use crate::abi::uniswap_pair::UNISWAP_PAIR_ABI;
+use crate::abi::uniswap_pool::UNISWAP_V3_POOL_ABI;
+use crate::abi::uniswap_router_v2::UNISWAP_V2_ROUTER_ABI;
+use crate::abi::uniswap_v3_router::UNISWAP_V3_ROUTER_ABI;
+
+use super::simlator::AnvilTestSimulator;
+use super::supporting_methods::find_revert;
+
+/// Selector of the standard `Error(string)` revert.
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Selector of the `Panic(uint256)` revert raised by failed asserts, overflows, etc.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// A simulated transaction that can be traced.
+#[derive(Clone, Debug)]
+pub enum SimulatedTx {
+    /// The transaction was mined, with this hash.
+    Mined(H256),
+    /// The transaction was rejected before being mined, this is the request that was sent.
+    Failed(Box<TypedTransaction>),
+}
+
+/// A single call in the trace's call tree.
+///
+/// # Fields
+///
+/// * `depth` - The nesting depth, 0 for the top level call.
+/// * `call_type` - The kind of call (`CALL`, `DELEGATECALL`, `STATICCALL`, ...).
+/// * `to` - The contract called.
+/// * `function` - The decoded function signature, or the selector if it is not in a known ABI.
+/// * `error` - The error the call failed with, `None` if it succeeded.
+#[derive(Clone, Debug, Default)]
+pub struct TracedCall {
+    pub depth: usize,
+    pub call_type: String,
+    pub to: Option<Address>,
+    pub function: String,
+    pub error: Option<String>,
+}
+
+/// An event emitted during the traced transaction.
+///
+/// # Fields
+///
+/// * `contract` - The contract that emitted the event.
+/// * `event` - The decoded event with its arguments, e.g. `Transfer(from: 0x…, to: 0x…, value: 100)`,
+///   or the topic if it is not in a known ABI.
+#[derive(Clone, Debug, Default)]
+pub struct DecodedEvent {
+    pub contract: Option<Address>,
+    pub event: String,
+}
+
+/// A token `Transfer` event emitted during the traced transaction.
+///
+/// # Fields
+///
+/// * `token` - The token transferred.
+/// * `from` - The sender.
+/// * `to` - The recipient.
+/// * `value` - The amount transferred.
+#[derive(Clone, Debug, Default)]
+pub struct TokenTransfer {
+    pub token: Address,
+    pub from: Address,
+    pub to: Address,
+    pub value: U256,
+}
+
+/// Structured trace of a simulated buy, transfer or sell.
+///
+/// # Fields
+///
+/// * `operation` - The simulation step traced (`buy`, `transfer` or `sell`).
+/// * `reverted` - Whether the transaction reverted.
+/// * `revert_location` - The innermost call that reverted, e.g. `transfer(address,uint256) on 0x…`.
+/// * `revert_reason` - The decoded revert reason, custom error or panic code.
+/// * `calls` - The call tree, flattened in execution order.
+/// * `events` - The events emitted, in order. Events of reverted calls are not emitted.
+/// * `transfers` - The `Transfer` events emitted, decoded so the tokens moved can be followed.
+#[derive(Clone, Debug, Default)]
+pub struct TraceReport {
+    pub operation: String,
+    pub reverted: bool,
+    pub revert_location: Option<String>,
+    pub revert_reason: Option<String>,
+    pub calls: Vec<TracedCall>,
+    pub events: Vec<DecodedEvent>,
+    pub transfers: Vec<TokenTransfer>,
+}
+
+impl TraceReport {
+    /// Builds the report from the root frame of a call trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The simulation step traced.
+    /// * `call_frame` - The root frame returned by the call tracer.
+    /// * `token_abi` - The token's verified ABI, used to decode its functions, events and custom errors.
+    ///
+    /// # Returns
+    ///
+    /// * `TraceReport` - The decoded call tree, revert and events.
+    pub fn from_call_frame(
+        operation: &str,
+        call_frame: &CallFrame,
+        token_abi: Option<&Abi>,
+    ) -> Self {
+        let abis = known_abis(token_abi);
+
+        let mut calls = Vec::new();
+        let mut events = Vec::new();
+        let mut transfers = Vec::new();
+        flatten_call_frame(
+            call_frame,
+            0,
+            &abis,
+            &mut calls,
+            &mut events,
+            &mut transfers,
+        );
+
+        let reverted = call_frame.error.is_some();
+        let (revert_location, revert_reason) = match find_revert(call_frame) {
+            Some(revert_call) if reverted => (
+                Some(format!(
+                    "{} on {:?}",
+                    decode_function(&revert_call.input, &abis),
+                    call_address(revert_call).unwrap_or_default()
+                )),
+                Some(decode_revert_reason(
+                    revert_call.output.as_deref().unwrap_or_default(),
+                    revert_call.error.as_deref(),
+                    token_abi,
+                )),
+            ),
+            _ => (None, None),
+        };
+
+        Self {
+            operation: operation.to_string(),
+            reverted,
+            revert_location,
+            revert_reason,
+            calls,
+            events,
+            transfers,
+        }
+    }
+
+    /// Returns a one line summary, e.g. `sell reverted in transfer(address,uint256) on 0x…: blacklisted`.
+    pub fn summary(&self) -> String {
+        if self.reverted {
+            format!(
+                "{} reverted in {}: {}",
+                self.operation,
+                self.revert_location.as_deref().unwrap_or("unknown call"),
+                self.revert_reason.as_deref().unwrap_or("no reason")
+            )
+        } else {
+            format!(
+                "{} succeeded with {} calls and {} events",
+                self.operation,
+                self.calls.len(),
+                self.events.len()
+            )
+        }
+    }
+}
+
+/// Decodes the revert data returned by a failed call into a readable reason.
+///
+/// # Arguments
+///
+/// * `output` - The revert data returned by the call.
+/// * `error` - The error reported by the tracer (e.g. `execution reverted`), used when there is no data.
+/// * `token_abi` - The token's verified ABI, used to decode custom errors.
+///
+/// # Returns
+///
+/// * `String` - The `Error(string)` message, the panic code, the decoded custom error, or the raw
+///   selector of an unknown custom error.
+pub fn decode_revert_reason(output: &[u8], error: Option<&str>, token_abi: Option<&Abi>) -> String {
+    if output.len() < 4 {
+        return error.unwrap_or("reverted without a reason").to_string();
+    }
+    let (selector, data) = output.split_at(4);
+
+    if selector == ERROR_STRING_SELECTOR {
+        if let Ok(tokens) = ethers::abi::decode(&[ethers::abi::ParamType::String], data) {
+            if let Some(Token::String(reason)) = tokens.into_iter().next() {
+                return reason;
+            }
+        }
+    }
+
+    if selector == PANIC_SELECTOR {
+        if let Ok(tokens) = ethers::abi::decode(&[ethers::abi::ParamType::Uint(256)], data) {
+            if let Some(Token::Uint(code)) = tokens.into_iter().next() {
+                return format!("panic {:#x}", code);
+            }
+        }
+    }
+
+    for abi in known_abis(token_abi) {
+        for abi_error in abi.errors() {
+            if abi_error.signature()[..4] != *selector {
+                continue;
+            }
+            if let Ok(arguments) = abi_error.decode(data) {
+                return format_arguments(
+                    &abi_error.name,
+                    abi_error.inputs.iter().map(|input| input.name.as_str()),
+                    &arguments,
+                );
+            }
+        }
+    }
+
+    format!("custom error 0x{}", hex::encode(selector))
+}
+
+/// Returns the ABIs used for decoding: the token's own ABI first, then the ERC20 and Uniswap ABIs.
+fn known_abis(token_abi: Option<&Abi>) -> Vec<&Abi> {
+    let mut abis: Vec<&Abi> = token_abi.into_iter().collect();
+    abis.extend([
+        &*ERC20_ABI,
+        &*UNISWAP_PAIR_ABI,
+        &*UNISWAP_V2_ROUTER_ABI,
+        &*UNISWAP_V3_POOL_ABI,
+        &*UNISWAP_V3_ROUTER_ABI,
+    ]);
+    abis
+}
+
+/// Walks the call tree depth first, collecting every call and the events (and token transfers) of
+/// calls that succeeded.
+fn flatten_call_frame(
+    call_frame: &CallFrame,
+    depth: usize,
+    abis: &[&Abi],
+    calls: &mut Vec<TracedCall>,
+    events: &mut Vec<DecodedEvent>,
+    transfers: &mut Vec<TokenTransfer>,
+) {
+    calls.push(TracedCall {
+        depth,
+        call_type: call_frame.typ.clone(),
+        to: call_address(call_frame),
+        function: decode_function(&call_frame.input, abis),
+        error: call_frame.error.clone(),
+    });
+
+    if call_frame.error.is_none() {
+        for log in call_frame.logs.iter().flatten() {
+            let topics = log.topics.clone().unwrap_or_default();
+            let data = log.data.clone().unwrap_or_default().to_vec();
+            if let Some(transfer) = decode_transfer(log.address, &topics, &data) {
+                transfers.push(transfer);
+            }
+            events.push(DecodedEvent {
+                contract: log.address,
+                event: decode_event(topics, data, abis),
+            });
+        }
+    }
+
+    for call in call_frame.calls.iter().flatten() {
+        flatten_call_frame(call, depth + 1, abis, calls, events, transfers);
+    }
+}
+
+/// Decodes an ERC20 `Transfer(address,address,uint256)` event, `None` if the log is not one.
+///
+/// ERC721 transfers share the topic but index the token id, so they have four topics and are skipped.
+fn decode_transfer(
+    contract: Option<Address>,
+    topics: &[H256],
+    data: &[u8],
+) -> Option<TokenTransfer> {
+    let transfer_topic = ERC20_ABI.event("Transfer").ok()?.signature();
+    if topics.len() != 3 || topics[0] != transfer_topic || data.len() != 32 {
+        return None;
+    }
+
+    Some(TokenTransfer {
+        token: contract?,
+        from: Address::from(topics[1]),
+        to: Address::from(topics[2]),
+        value: U256::from_big_endian(data),
+    })
+}
+
+/// Returns the address a call was made to.
+fn call_address(call_frame: &CallFrame) -> Option<Address> {
+    match &call_frame.to {
+        Some(NameOrAddress::Address(address)) => Some(*address),
+        _ => None,
+    }
+}
+
+/// Returns the signature of the function a call's input selects, or the selector if unknown.
+fn decode_function(input: &[u8], abis: &[&Abi]) -> String {
+    if input.len() < 4 {
+        return "fallback()".to_string();
+    }
+    let selector = &input[..4];
+
+    abis.iter()
+        .flat_map(|abi| abi.functions())
+        .find(|function| function.short_signature() == selector)
+        .map(function_signature)
+        .unwrap_or_else(|| format!("0x{}", hex::encode(selector)))
+}
+
+/// Returns a function's signature without its outputs, e.g. `transfer(address,uint256)`.
+fn function_signature(function: &Function) -> String {
+    let inputs = function
+        .inputs
+        .iter()
+        .map(|input| input.kind.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{}({})", function.name, inputs)
+}
+
+/// Decodes an event against the known ABIs, or returns its topic if it is unknown.
+fn decode_event(topics: Vec<H256>, data: Vec<u8>, abis: &[&Abi]) -> String {
+    let topic = match topics.first() {
+        Some(topic) => *topic,
+        None => return "anonymous event".to_string(),
+    };
+
+    for event in abis.iter().flat_map(|abi| abi.events()) {
+        if event.signature() != topic {
+            continue;
+        }
+        let raw_log = RawLog {
+            topics: topics.clone(),
+            data: data.clone(),
+        };
+        if let Ok(log) = event.parse_log(raw_log) {
+            let arguments = log
+                .params
+                .iter()
+                .map(|param| param.value.clone())
+                .collect::<Vec<_>>();
+            return format_arguments(
+                &event.name,
+                log.params.iter().map(|param| param.name.as_str()),
+                &arguments,
+            );
+        }
+    }
+
+    format!("unknown event {:?}", topic)
+}
+
+/// Formats a decoded function, event or error as `Name(arg: value, ...)`.
+fn format_arguments<'a>(
+    name: &str,
+    argument_names: impl Iterator<Item = &'a str>,
+    arguments: &[Token],
+) -> String {
+    let arguments = argument_names
+        .zip(arguments)
+        .map(|(argument_name, value)| format!("{}: {}", argument_name, format_token(value)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{}({})", name, arguments)
+}
+
+/// Formats a decoded ABI value, with numbers in decimal.
+fn format_token(token: &Token) -> String {
+    match token {
+        Token::Address(address) => format!("{:?}", address),
+        Token::Uint(value) => value.to_string(),
+        Token::Int(value) => I256::from_raw(*value).to_string(),
+        Token::Bool(value) => value.to_string(),
+        Token::String(value) => value.clone(),
+        Token::Bytes(bytes) | Token::FixedBytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        Token::Array(tokens) | Token::FixedArray(tokens) | Token::Tuple(tokens) => format!(
+            "[{}]",
+            tokens
+                .iter()
+                .map(format_token)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Returns the call tracer options, with logs enabled so events can be decoded.
+fn call_tracer_options() -> GethDebugTracingOptions {
+    GethDebugTracingOptions {
+        tracer: Some(GethDebugTracerType::BuiltInTracer(
+            GethDebugBuiltInTracerType::CallTracer,
+        )),
+        tracer_config: Some(GethDebugTracerConfig::BuiltInTracer(
+            GethDebugBuiltInTracerConfig::CallTracer(CallConfig {
+                only_top_call: Some(false),
+                with_log: Some(true),
+            }),
+        )),
+        ..Default::default()
+    }
+}
+
+impl AnvilTestSimulator {
+    /// Traces a simulated transaction and decodes it into a [`TraceReport`].
+    ///
+    /// A transaction that failed to be sent is replayed against the current state, so this should be
+    /// called right after the step being traced.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - The simulated transaction.
+    /// * `operation` - The simulation step traced (`buy`, `transfer` or `sell`).
+    /// * `token_abi` - The token's verified ABI, `None` if the token is not verified.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<TraceReport>` - The decoded trace, or an error if the node could not trace it.
+    pub async fn trace_simulated_tx(
+        &self,
+        tx: &SimulatedTx,
+        operation: &str,
+        token_abi: Option<&Abi>,
+    ) -> Result<TraceReport> {
+        let trace = match tx {
+            SimulatedTx::Mined(tx_hash) => {
+                self.client
+                    .debug_trace_transaction(*tx_hash, call_tracer_options())
+                    .await?
+            }
+            SimulatedTx::Failed(request) => {
+                let mut request = *request.clone();
+                if request.from().is_none() {
+                    request.set_from(self.sender);
+                }
+                let tracing_options = GethDebugTracingCallOptions {
+                    tracing_options: call_tracer_options(),
+                    ..Default::default()
+                };
+                self.client
+                    .debug_trace_call(request, None, tracing_options)
+                    .await?
+            }
+        };
+
+        match trace {
+            GethTrace::Known(GethTraceFrame::CallTracer(call_frame)) => {
+                let trace_report = TraceReport::from_call_frame(operation, &call_frame, token_abi);
+                println!("trace: {}", trace_report.summary());
+                Ok(trace_report)
+            }
+            _ => Err(anyhow!("Unexpected trace format")),
+        }
+    }
+}
@@ -0,0 +1,410 @@
+//! This module discovers the effective trading limits a token enforces: the largest buy, sell and
+//! transfer it lets through, the most a single wallet can hold, and the cooldown between sells.
+//!
+//! Many scams let small buys through but block any sell above a tiny amount. Each limit is found on
+//! the anvil fork by probing trades of increasing size, binary searching between the largest amount
+//! that went through and the smallest that failed. Every probe runs inside an `evm_snapshot` that is
+//! reverted afterwards, so the wallet is left exactly as it was before probing.
+
+use anyhow::Result;
+use ethers::providers::Middleware;
+use ethers::types::{Address, U256};
+
+use crate::abi::erc20::ERC20;
+use crate::app_config::{
+    COOLDOWN_PROBE_SECONDS, MAX_BUY_PROBE_MULTIPLIER, SELL_TO_BUY_LIMIT_RATIO_THRESHOLD,
+    TRADE_LIMIT_PROBE_STEPS,
+};
+use crate::data::token_data::ERC20Token;
+use crate::dex::dex_data::TokenDexData;
+use crate::token_check::anvil::tx_trait::Txs;
+use crate::token_check::token_holder_check::u256_div_u256_to_f64;
+
+use super::simlator::AnvilTestSimulator;
+
+/// Effective trading limits discovered on the fork, as a percentage of the total supply (0.0 to 100.0).
+///
+/// A `None` limit means no limit was hit within the probed range.
+///
+/// # Fields
+///
+/// * `max_buy_percentage_of_supply` - The largest buy that went through.
+/// * `max_sell_percentage_of_supply` - The largest sell that went through.
+/// * `max_transfer_percentage_of_supply` - The largest wallet to wallet transfer that went through.
+/// * `max_wallet_percentage_of_supply` - The most a fresh wallet could receive.
+/// * `sell_cooldown_seconds` - How long a wallet had to wait between two sells.
+/// * `sell_limit_far_below_buy_limit` - Whether the largest sell is a small fraction of the largest
+///   buy, i.e. holders can buy far more than they can ever sell.
+#[derive(Clone, Debug, Default)]
+pub struct TradeLimits {
+    pub max_buy_percentage_of_supply: Option<f64>,
+    pub max_sell_percentage_of_supply: Option<f64>,
+    pub max_transfer_percentage_of_supply: Option<f64>,
+    pub max_wallet_percentage_of_supply: Option<f64>,
+    pub sell_cooldown_seconds: Option<u64>,
+    pub sell_limit_far_below_buy_limit: bool,
+}
+
+/// Returns `true` if the sell limit is below [`SELL_TO_BUY_LIMIT_RATIO_THRESHOLD`] of the largest buy.
+///
+/// # Arguments
+///
+/// * `largest_buy` - The most tokens received from a single buy.
+/// * `max_sell` - The largest sell that went through, `None` if sells were not limited.
+///
+/// # Returns
+///
+/// * `bool` - Whether the sell limit is far below the buy limit.
+pub fn is_sell_limit_far_below_buy_limit(largest_buy: U256, max_sell: Option<U256>) -> bool {
+    match max_sell {
+        Some(max_sell) if !largest_buy.is_zero() => u256_div_u256_to_f64(max_sell, largest_buy)
+            .map(|ratio| ratio < SELL_TO_BUY_LIMIT_RATIO_THRESHOLD)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Converts a token amount to a percentage of the total supply.
+fn percentage_of_supply(amount: Option<U256>, total_supply: U256) -> Option<f64> {
+    amount.and_then(|amount| {
+        u256_div_u256_to_f64(amount, total_supply)
+            .ok()
+            .map(|ratio| 100_f64 * ratio)
+    })
+}
+
+impl AnvilTestSimulator {
+    /// Probes the token's effective max buy, max sell, max transfer, max wallet and sell cooldown.
+    ///
+    /// This function performs the following steps, each inside a reverted snapshot:
+    /// 1. Max buy: tries buying with up to [`MAX_BUY_PROBE_MULTIPLIER`] times the test amount of ETH,
+    ///    binary searching down if the largest buy fails.
+    /// 2. Buys with the largest amount of ETH that went through, so the probes below have a large balance.
+    /// 3. Max sell: tries selling the full balance, binary searching down if it fails.
+    /// 4. Max transfer: tries transferring the full balance to a fresh wallet, binary searching down if
+    ///    it fails.
+    /// 5. Max wallet: if transfers are limited, keeps transferring the max transfer amount to a fresh
+    ///    wallet until a transfer fails. The result is accurate to within one max transfer.
+    /// 6. Sell cooldown: sells twice in a row and, if the second sell fails, moves the fork forward
+    ///    through [`COOLDOWN_PROBE_SECONDS`] until it goes through.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's top liquidity pair / pool.
+    /// * `token` - The token that has just been bought.
+    /// * `eth_amount_in` - The amount of ETH the test purchase was made with.
+    /// * `tokens_bought` - The amount of tokens the test purchase returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<TradeLimits>` - The limits found, as a percentage of the total supply. The fork is
+    ///   reverted to its state before probing whether or not a probe failed.
+    pub async fn probe_trade_limits(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+        tokens_bought: U256,
+    ) -> Result<TradeLimits> {
+        let snapshot_id = self.snapshot().await?;
+        let trade_limits = self
+            .run_trade_limit_probes(dex_data, token, eth_amount_in, tokens_bought)
+            .await;
+        self.revert_to_snapshot(snapshot_id).await?;
+        trade_limits
+    }
+
+    /// Runs the trade limit probes of [`AnvilTestSimulator::probe_trade_limits`] without reverting
+    /// the fork afterwards.
+    async fn run_trade_limit_probes(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+        tokens_bought: U256,
+    ) -> Result<TradeLimits> {
+        let token_contract = ERC20::new(token.address, self.signed_client.clone());
+        let total_supply = token_contract.total_supply().call().await?;
+
+        // 1. Max buy
+        println!("probing max buy...");
+        let (eth_for_largest_buy, largest_buy, max_buy) = self
+            .probe_max_buy(dex_data, token, eth_amount_in, tokens_bought)
+            .await?;
+
+        // 2. Hold as many tokens as possible for the remaining probes
+        if eth_for_largest_buy > eth_amount_in {
+            self.simulate_buying_token_on_dex(dex_data, token, eth_for_largest_buy)
+                .await?;
+        }
+        let holdings = self
+            .get_wallet_token_balance_by_address(token.address)
+            .await?;
+
+        // 3. Max sell
+        println!("probing max sell...");
+        let max_sell = self
+            .binary_search_limit(holdings, |amount| async move {
+                let sold = self
+                    .simulate_selling_token_on_dex(dex_data, token, amount)
+                    .await
+                    .is_ok_and(|sell_swap| !sell_swap.amount_out.is_zero());
+                Ok(sold)
+            })
+            .await?;
+
+        // 4. Max transfer
+        println!("probing max transfer...");
+        let max_transfer = self
+            .binary_search_limit(holdings, |amount| async move {
+                self.try_transfer(token.address, Address::random(), amount)
+                    .await
+            })
+            .await?;
+
+        // 5. Max wallet
+        println!("probing max wallet...");
+        let max_wallet = match max_transfer {
+            Some(max_transfer) if !max_transfer.is_zero() => {
+                self.probe_max_wallet(token.address, holdings, max_transfer)
+                    .await?
+            }
+            _ => None,
+        };
+
+        // 6. Sell cooldown
+        println!("probing sell cooldown...");
+        let sell_cooldown_seconds = self
+            .probe_sell_cooldown(dex_data, token, max_sell.unwrap_or(holdings))
+            .await?;
+
+        Ok(TradeLimits {
+            max_buy_percentage_of_supply: percentage_of_supply(max_buy, total_supply),
+            max_sell_percentage_of_supply: percentage_of_supply(max_sell, total_supply),
+            max_transfer_percentage_of_supply: percentage_of_supply(max_transfer, total_supply),
+            max_wallet_percentage_of_supply: percentage_of_supply(max_wallet, total_supply),
+            sell_cooldown_seconds,
+            sell_limit_far_below_buy_limit: is_sell_limit_far_below_buy_limit(
+                largest_buy,
+                max_sell,
+            ),
+        })
+    }
+
+    /// Binary searches for the largest amount (up to `upper_bound`) for which `try_amount` succeeds.
+    ///
+    /// The full `upper_bound` is tried first. Each attempt runs inside a reverted snapshot.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<U256>>` - `None` if `upper_bound` succeeded (no limit), otherwise the largest
+    ///   amount that succeeded (zero if none did).
+    async fn binary_search_limit<F, Fut>(
+        &self,
+        upper_bound: U256,
+        try_amount: F,
+    ) -> Result<Option<U256>>
+    where
+        F: Fn(U256) -> Fut,
+        Fut: std::future::Future<Output = Result<bool>>,
+    {
+        if self.succeeds_in_snapshot(&try_amount, upper_bound).await? {
+            return Ok(None);
+        }
+
+        let mut largest_success = U256::zero();
+        let mut smallest_failure = upper_bound;
+        for _ in 0..TRADE_LIMIT_PROBE_STEPS {
+            let amount = (largest_success + smallest_failure) / 2;
+            if amount.is_zero() || amount == largest_success {
+                break;
+            }
+
+            if self.succeeds_in_snapshot(&try_amount, amount).await? {
+                largest_success = amount;
+            } else {
+                smallest_failure = amount;
+            }
+        }
+
+        Ok(Some(largest_success))
+    }
+
+    /// Runs `try_amount` inside a snapshot that is reverted afterwards.
+    async fn succeeds_in_snapshot<F, Fut>(&self, try_amount: &F, amount: U256) -> Result<bool>
+    where
+        F: Fn(U256) -> Fut,
+        Fut: std::future::Future<Output = Result<bool>>,
+    {
+        let snapshot_id = self.snapshot().await?;
+        let succeeded = try_amount(amount).await?;
+        self.revert_to_snapshot(snapshot_id).await?;
+        Ok(succeeded)
+    }
+
+    /// Finds the largest buy that goes through, searching over the amount of ETH spent.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(U256, U256, Option<U256>)>` - The ETH spent on the largest buy, the tokens it returned,
+    ///   and the same token amount if buys were limited (`None` otherwise).
+    async fn probe_max_buy(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+        tokens_bought: U256,
+    ) -> Result<(U256, U256, Option<U256>)> {
+        // Leave half the wallet's ETH for gas and, for non-WETH pairs, acquiring the base token.
+        let eth_balance = self.client.get_balance(self.sender, None).await?;
+        let eth_upper_bound =
+            (eth_amount_in * U256::from(MAX_BUY_PROBE_MULTIPLIER)).min(eth_balance / 2);
+        if eth_upper_bound <= eth_amount_in {
+            return Ok((eth_amount_in, tokens_bought, None));
+        }
+
+        let try_buy = |eth_amount: U256| async move {
+            let bought = self
+                .simulate_buying_token_on_dex(dex_data, token, eth_amount)
+                .await
+                .map(|buy_swap| buy_swap.amount_out)
+                .unwrap_or_default();
+            Ok::<U256, anyhow::Error>(bought)
+        };
+
+        let snapshot_id = self.snapshot().await?;
+        let bought = try_buy(eth_upper_bound).await?;
+        self.revert_to_snapshot(snapshot_id).await?;
+        if !bought.is_zero() {
+            return Ok((eth_upper_bound, bought, None));
+        }
+
+        // The test purchase went through, so search between it and the upper bound.
+        let mut largest_success = (eth_amount_in, tokens_bought);
+        let mut smallest_failure = eth_upper_bound;
+        for _ in 0..TRADE_LIMIT_PROBE_STEPS {
+            let eth_amount = (largest_success.0 + smallest_failure) / 2;
+            if eth_amount == largest_success.0 {
+                break;
+            }
+
+            let snapshot_id = self.snapshot().await?;
+            let bought = try_buy(eth_amount).await?;
+            self.revert_to_snapshot(snapshot_id).await?;
+            if bought.is_zero() {
+                smallest_failure = eth_amount;
+            } else {
+                largest_success = (eth_amount, bought);
+            }
+        }
+
+        Ok((
+            largest_success.0,
+            largest_success.1,
+            Some(largest_success.1),
+        ))
+    }
+
+    /// Transfers `max_transfer` sized chunks of the wallet's balance to a fresh wallet until a
+    /// transfer fails or the balance runs out.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<U256>>` - The fresh wallet's balance when a transfer failed, `None` if the
+    ///   whole balance could be moved.
+    async fn probe_max_wallet(
+        &self,
+        token_address: Address,
+        holdings: U256,
+        max_transfer: U256,
+    ) -> Result<Option<U256>> {
+        let token_contract = ERC20::new(token_address, self.signed_client.clone());
+        let fresh_wallet = Address::random();
+        let snapshot_id = self.snapshot().await?;
+
+        let mut remaining = holdings;
+        let mut max_wallet = None;
+        while !remaining.is_zero() {
+            let amount = remaining.min(max_transfer);
+            if !self
+                .try_transfer(token_address, fresh_wallet, amount)
+                .await?
+            {
+                max_wallet = Some(token_contract.balance_of(fresh_wallet).call().await?);
+                break;
+            }
+            remaining -= amount;
+        }
+
+        self.revert_to_snapshot(snapshot_id).await?;
+        Ok(max_wallet)
+    }
+
+    /// Sells half of `amount` twice in a row, moving the fork forward until the second sell goes
+    /// through if it fails.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<u64>>` - The seconds waited before the second sell went through, `None` if it
+    ///   went through straight away, the first sell failed, or it never went through.
+    async fn probe_sell_cooldown(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount: U256,
+    ) -> Result<Option<u64>> {
+        let half = amount / 2;
+        if half.is_zero() {
+            return Ok(None);
+        }
+
+        let snapshot_id = self.snapshot().await?;
+        let try_sell = || async {
+            self.simulate_selling_token_on_dex(dex_data, token, half)
+                .await
+                .is_ok_and(|sell_swap| !sell_swap.amount_out.is_zero())
+        };
+
+        let mut sell_cooldown_seconds = None;
+        if try_sell().await && !try_sell().await {
+            let mut waited = 0;
+            for seconds in COOLDOWN_PROBE_SECONDS {
+                self.increase_time(seconds - waited).await?;
+                waited = seconds;
+                if try_sell().await {
+                    sell_cooldown_seconds = Some(seconds);
+                    break;
+                }
+            }
+        }
+
+        self.revert_to_snapshot(snapshot_id).await?;
+        Ok(sell_cooldown_seconds)
+    }
+
+    /// Attempts to transfer `amount` of the token to `to`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool>` - Whether the transfer went through and `to` received tokens.
+    async fn try_transfer(
+        &self,
+        token_address: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<bool> {
+        let token_contract = ERC20::new(token_address, self.signed_client.clone());
+        let balance_before = token_contract.balance_of(to).call().await?;
+
+        let receipt = match token_contract.transfer(to, amount).send().await {
+            Ok(pending_tx) => pending_tx.await?,
+            Err(_) => return Ok(false),
+        };
+        if receipt.and_then(|receipt| receipt.status) != Some(1.into()) {
+            return Ok(false);
+        }
+
+        let balance_after = token_contract.balance_of(to).call().await?;
+        Ok(balance_after > balance_before)
+    }
+}
@@ -1,152 +1,348 @@
-use std::sync::Arc;
-
-use crate::data::chain_data::CHAIN_DATA;
-use crate::data::dex::Dex;
 use crate::data::token_data::ERC20Token;
-use crate::env_config::Config;
+use crate::dex::dex_data::TokenDexData;
+use crate::dex::swap_venue::swap_venue;
+use crate::token_check::anvil::anvil_pool::ANVIL_POOL;
+use crate::token_check::anvil::fee_receivers::FeeReceiver;
+use crate::token_check::anvil::owner_privileges::OwnerPrivilegeCheck;
+use crate::token_check::anvil::rug_pull::RugPullCheck;
 use crate::token_check::anvil::simlator::AnvilTestSimulator;
+use crate::token_check::anvil::time_travel::TimeBombCheck;
+use crate::token_check::anvil::token_tax::SimulatedSwap;
+use crate::token_check::anvil::token_tax::{tax_percentage, TokenTax};
+use crate::token_check::anvil::trace_report::{SimulatedTx, TraceReport};
+use crate::token_check::anvil::trade_limits::TradeLimits;
 use crate::token_check::anvil::tx_trait::Txs;
-use ethers::providers::Middleware;
-use ethers::types::U256;
+use crate::utils::tx::eth_amount_for_usd;
+use ethers::abi::Abi;
+use ethers::types::{Address, U256};
 use log::error;
 
 /// Describes the validation status of a token after running simulated buy and sell operations.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub enum TokenStatus {
     /// The token is deemed valid.
     Legit,
     /// The token cannot be sold after simulation.
     CannotSell,
     /// The token cannot be bought in simulation, or buying encountered issues.
+    #[default]
     CannotBuy,
 }
 
+/// Everything measured while simulating a buy and sell of the token.
+///
+/// # Fields
+///
+/// * `status` - Whether the token could be bought and sold.
+/// * `tax` - Buy, sell and transfer taxes measured up to the point the simulation stopped.
+/// * `time_bomb` - Balance and sellability re-checked at several horizons after purchase.
+/// * `trade_limits` - Effective max buy, sell, transfer and wallet sizes, and the sell cooldown.
+/// * `owner_privileges` - Privileged functions called as the owner, and whether each made the token
+///   unsellable.
+/// * `rug_pull` - The liquidity the unlocked LP holders could pull, and the resulting price impact on
+///   our sell.
+/// * `traces` - Decoded call traces of the buy, transfer and sell, showing why a step reverted.
+/// * `fee_receivers` - The addresses other than the pair and our wallets that received tokens
+///   during the buy, transfer and sell.
+#[derive(Clone, Debug, Default)]
+pub struct TokenSimulation {
+    pub status: TokenStatus,
+    pub tax: TokenTax,
+    pub time_bomb: TimeBombCheck,
+    pub trade_limits: TradeLimits,
+    pub owner_privileges: OwnerPrivilegeCheck,
+    pub rug_pull: RugPullCheck,
+    pub traces: Vec<TraceReport>,
+    pub fee_receivers: Vec<FeeReceiver>,
+}
+
+impl AnvilTestSimulator {
+    /// Buys the token on the token's top dex, through the dex's swap venue.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's top liquidity pair / pool.
+    /// * `token` - The token to buy.
+    /// * `eth_amount_in` - The amount of ETH to spend.
+    ///
+    /// # Returns
+    ///
+    /// * `anyhow::Result<SimulatedSwap>` - The outcome of the purchase, or an error if the dex is not supported.
+    pub async fn simulate_buying_token_on_dex(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        eth_amount_in: U256,
+    ) -> anyhow::Result<SimulatedSwap> {
+        swap_venue(&dex_data.dex)
+            .ok_or_else(|| anyhow::anyhow!("Buying on {:?} is not supported", dex_data.dex))?
+            .buy(self, dex_data, token, eth_amount_in)
+            .await
+    }
+
+    /// Sells the token on the token's top dex, through the dex's swap venue.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_data` - The token's top liquidity pair / pool.
+    /// * `token` - The token to sell.
+    /// * `amount_to_sell` - The amount of the token to sell.
+    ///
+    /// # Returns
+    ///
+    /// * `anyhow::Result<SimulatedSwap>` - The outcome of the sale, or an error if the dex is not supported.
+    pub async fn simulate_selling_token_on_dex(
+        &self,
+        dex_data: &TokenDexData,
+        token: &ERC20Token,
+        amount_to_sell: U256,
+    ) -> anyhow::Result<SimulatedSwap> {
+        swap_venue(&dex_data.dex)
+            .ok_or_else(|| anyhow::anyhow!("Selling on {:?} is not supported", dex_data.dex))?
+            .sell(self, dex_data, token, amount_to_sell)
+            .await
+    }
+}
+
 impl ERC20Token {
     /// Takes a snapshot of the current blockchain state using anvil by simulating buy and sell operations.
     ///
+    /// The simulation runs on a forked anvil node checked out of the [`ANVIL_POOL`].
+    ///
     /// This function runs through several steps to check token behavior:
     /// 1. Optionally simulate adding liquidity based on the provided `TokenLiquid` variant.
-    /// 2. Attempt to buy the token. If the purchase fails or no tokens are received, the token is marked as unable to be bought.
-    /// 3. Simulate block mining (time elapse) to mimic a real blockchain environment.
+    /// 2. Attempt to buy `trade_size_usd` worth of the token, priced with the Chainlink ETH / USD feed
+    ///    at the forked block. If the purchase fails or no tokens are received, the token is marked as unable to be bought.
+    /// 3. Move the fork forward in time across several horizons, re-checking the balance and
+    ///    sellability at each one to detect tokens that turn into honeypots after a delay.
     /// 4. Check that the token balance remains stable after simulated time elapse.
-    /// 5. Execute a dummy transfer to further validate token stability.
-    /// 6. Lastly, attempt to sell the token. If the balance after sale is not zero, the token is marked as unsellable.
+    /// 5. Probe buys, sells and transfers of increasing size to discover max-tx, max-wallet and
+    ///    cooldown limits.
+    /// 6. Impersonate the owner, call each privileged function against our wallet and re-attempt
+    ///    the sell, to find owner actions that make the token unsellable.
+    /// 7. Impersonate the largest unlocked LP holders, pull their liquidity and re-quote the sell, to
+    ///    measure the worst case price impact of a rug pull.
+    /// 8. Execute a dummy transfer to further validate token stability.
+    /// 9. Lastly, attempt to sell the token. If the balance after sale is not zero, the token is marked as unsellable.
+    /// 10. Find the addresses other than the pair and our wallets that received tokens in the traced
+    ///     buy, transfer and sell: the receivers of the token's fees.
+    ///
+    /// Along the way the buy, sell and transfer taxes are measured by comparing the quoted (or sent)
+    /// amounts with the amounts that were actually received, and the buy, transfer and sell are
+    /// traced so a revert can be explained.
     ///
     /// # Arguments
     ///
-    /// * `liquidity_status` - The current liquidity condition of the token. It can be either a need to add liquidity
-    ///   using a provided transaction or an indication that there is enough liquidity.
+    /// * `trade_size_usd` - The size of the simulated purchase in USD.
+    /// * `privileged_account` - The token owner (or admin) to impersonate, `None` if there is none.
+    /// * `abi` - The token's verified ABI, `None` if the token is not verified.
+    /// * `unlocked_lp_holders` - The largest holders of unlocked liquidity, empty if unknown.
+    /// * `related_accounts` - The owner, admins and deployer, used to flag fee receivers tied to the team.
     ///
     /// # Returns
     ///
-    /// Returns a `TokenStatus` indicating the result of the validation simulation wrapped in an `anyhow::Result`.
+    /// Returns a `TokenSimulation` holding the `TokenStatus` of the validation simulation, together
+    /// with the `TokenTax`, `TimeBombCheck`, `TradeLimits`, `OwnerPrivilegeCheck`, `RugPullCheck`, traces and
+    /// fee receivers measured up to the point the simulation stopped, wrapped in an `anyhow::Result`.
     ///
     /// # Errors
     ///
     /// If any of the simulation steps fail (for example, during the sell simulation), the error will be propagated.
-    pub async fn validate_with_simulated_buy_sell(&self) -> anyhow::Result<TokenStatus> {
-        let config = Config::from_env();
-        // Launch a new anvil node for validation using the websocket URL.
-        let ws_url = if config.environment == "production" {
-            CHAIN_DATA.get_address(&self.chain).alchemy_url.clone()
-        } else {
-            CHAIN_DATA.get_address(&self.chain).ws_url.clone()
+    pub async fn validate_with_simulated_buy_sell(
+        &self,
+        trade_size_usd: f64,
+        privileged_account: Option<Address>,
+        abi: Option<&Abi>,
+        unlocked_lp_holders: &[Address],
+        related_accounts: &[Address],
+    ) -> anyhow::Result<TokenSimulation> {
+        // Check out a warm forked anvil node from the pool, and hand it back (reverted to its clean
+        // state) once the simulation is done, whether it succeeded or not.
+        let anvil = ANVIL_POOL.checkout(&self.chain).await?;
+        let simulation = match self
+            .simulate_buy_sell(
+                &anvil,
+                trade_size_usd,
+                privileged_account,
+                abi,
+                unlocked_lp_holders,
+            )
+            .await
+        {
+            Ok(simulation) => {
+                self.add_fee_receivers(&anvil, simulation, related_accounts)
+                    .await
+            }
+            Err(e) => Err(e),
         };
-        let anvil = AnvilTestSimulator::new(&ws_url, &self.chain).await?;
+        ANVIL_POOL.release(anvil).await;
+
+        simulation
+    }
+
+    /// Finds who received the token's fees in the traces of the simulation.
+    async fn add_fee_receivers(
+        &self,
+        anvil: &AnvilTestSimulator,
+        mut simulation: TokenSimulation,
+        related_accounts: &[Address],
+    ) -> anyhow::Result<TokenSimulation> {
+        if let Some(token_dex) = &self.token_dex {
+            simulation.fee_receivers = anvil
+                .find_fee_receivers(
+                    self.address,
+                    &self.chain,
+                    token_dex.pair_address,
+                    &simulation.traces,
+                    related_accounts,
+                )
+                .await?;
+            println!("fee receivers => {:?}", simulation.fee_receivers);
+        }
+
+        Ok(simulation)
+    }
 
+    /// Runs the buy / sell simulation described in [`ERC20Token::validate_with_simulated_buy_sell`]
+    /// on the given anvil node.
+    async fn simulate_buy_sell(
+        &self,
+        anvil: &AnvilTestSimulator,
+        trade_size_usd: f64,
+        privileged_account: Option<Address>,
+        abi: Option<&Abi>,
+        unlocked_lp_holders: &[Address],
+    ) -> anyhow::Result<TokenSimulation> {
         let top_dex_data = self
             .clone()
             .token_dex
             .expect("is_liquidity_locked: no token dex found");
 
         println!("validating token...");
+        let mut simulation = TokenSimulation::default();
 
         // Attempt to buy the token using the anvil simulator.
-        let buy_result = match top_dex_data.dex {
-            Dex::UniswapV2 => {
-                anvil
-                    .simulate_buying_token_on_uniswap_v2_for_weth(&top_dex_data, self)
-                    .await
-            }
-            Dex::UniswapV3 => {
-                anvil
-                    .simulate_buying_token_on_uniswap_v3_for_weth(&top_dex_data, self)
-                    .await
-            }
-            _ => return Ok(TokenStatus::CannotBuy),
-        };
+        let eth_amount_in = eth_amount_for_usd(trade_size_usd, &self.chain, &anvil.client).await?;
+        let buy_result = anvil
+            .simulate_buying_token_on_dex(&top_dex_data, self, eth_amount_in)
+            .await;
 
         if let Err(err) = buy_result {
             error!("Buy transaction failed with error: {:?}", err);
             // If buying fails, the token is considered not purchasable.
-            return Ok(TokenStatus::CannotBuy);
+            return Ok(simulation);
         }
 
         // Unwrap the token balance received after a successful simulated purchase.
-        let token_balance = buy_result?;
+        let buy_swap = buy_result?;
+        let token_balance = buy_swap.token_balance;
+        if let Some(tx) = &buy_swap.tx {
+            trace_step(anvil, tx, "buy", abi, &mut simulation.traces).await;
+        }
 
         println!("check token balance after purchase");
         if token_balance == U256::from(0) {
             println!("No tokens received after buy, reverting...");
             // If no tokens are received, mark as unable to buy.
-            return Ok(TokenStatus::CannotBuy);
+            return Ok(simulation);
         }
 
-        // Simulate block creation to mimic a passing of time.
-        println!("simulating creating blocks");
-        let _ = anvil
-            .signed_client
-            .provider()
-            .request::<_, String>("evm_mine", None::<()>)
-            .await?;
+        simulation.tax.buy_tax = buy_swap.tax_percentage();
+        println!("buy tax => {:?}%", simulation.tax.buy_tax);
+
+        // Move forward in time, re-checking balance and sellability at each horizon.
+        println!("simulating passing of time");
+        simulation.time_bomb = anvil.run_time_bomb_check(&top_dex_data, self).await?;
 
-        println!("check token balance after five minutes");
+        println!("check token balance after time travel");
         // Check that token balance after time elapse is not degraded.
         let balance_after_buy = anvil
             .get_wallet_token_balance_by_address(self.address)
             .await?;
         if balance_after_buy < token_balance {
-            println!("Tokens are dropping or going to zero after 5 mins...");
+            println!("Tokens are dropping or going to zero over time...");
             // If the token balance drops, mark as non-purchasable.
-            return Ok(TokenStatus::CannotBuy);
+            return Ok(simulation);
+        }
+
+        // Probe trades of increasing size to find the token's effective trading limits.
+        println!("probing trade limits");
+        simulation.trade_limits = match anvil
+            .probe_trade_limits(&top_dex_data, self, eth_amount_in, buy_swap.amount_out)
+            .await
+        {
+            Ok(trade_limits) => trade_limits,
+            Err(e) => {
+                error!("Trade limit probing failed with error: {:?}", e);
+                TradeLimits::default()
+            }
+        };
+
+        // Impersonate the owner and check whether any privileged function can trap holders.
+        if let (Some(privileged_account), Some(abi)) = (privileged_account, abi) {
+            println!("simulating owner privileges");
+            simulation.owner_privileges = match anvil
+                .run_owner_privilege_check(&top_dex_data, self, privileged_account, abi)
+                .await
+            {
+                Ok(owner_privileges) => owner_privileges,
+                Err(e) => {
+                    error!("Owner privilege check failed with error: {:?}", e);
+                    OwnerPrivilegeCheck::default()
+                }
+            };
+        }
+
+        // Pull the unlocked liquidity and measure what it does to our sell.
+        if !unlocked_lp_holders.is_empty() {
+            println!("simulating rug pull");
+            simulation.rug_pull = match anvil
+                .run_rug_pull_check(&top_dex_data, self, unlocked_lp_holders, balance_after_buy)
+                .await
+            {
+                Ok(rug_pull) => rug_pull,
+                Err(e) => {
+                    error!("Rug pull check failed with error: {:?}", e);
+                    RugPullCheck::default()
+                }
+            };
         }
 
         // Simulate a dummy transfer to further verify token stability.
         println!("do dummy transfer");
-        anvil
+        let transfer = anvil
             .do_dummy_transfer(self.address, balance_after_buy)
             .await?;
+        trace_step(anvil, &transfer.tx, "transfer", abi, &mut simulation.traces).await;
+
+        simulation.tax.transfer_tax =
+            tax_percentage(balance_after_buy, transfer.received_by_second_wallet);
+        println!("transfer tax => {:?}%", simulation.tax.transfer_tax);
 
         println!("check token balance after transfers");
-        // Verify that the token balance remains stable after the transfer.
+        // Verify that the token balance remains stable after the transfer. Tokens taken by a
+        // transfer tax are accounted for, anything beyond that is a drop.
         let balance_after_transfer = anvil
             .get_wallet_token_balance_by_address(self.address)
             .await?;
-        if balance_after_transfer < token_balance {
+        if balance_after_transfer.is_zero() || balance_after_transfer < transfer.received_back {
             println!("Tokens are dropping or going to zero after transfer...");
             // If token balance drops post-transfer, mark as non-purchasable.
-            return Ok(TokenStatus::CannotBuy);
+            return Ok(simulation);
         }
 
         // Attempt to sell the token.
         println!("simulate selling token for validation");
-        // Attempt to buy the token using the anvil simulator.
-        let sell_result = match top_dex_data.dex {
-            Dex::UniswapV2 => {
-                anvil
-                    .simulate_selling_token_on_uniswap_v2_for_weth(&top_dex_data, self)
-                    .await
-            }
-            Dex::UniswapV3 => {
-                anvil
-                    .simulate_selling_token_on_uniswap_v3_for_weth(&top_dex_data, self)
-                    .await
-            }
-            _ => return Ok(TokenStatus::CannotBuy),
-        };
+        let sell_result = anvil
+            .simulate_selling_token_on_dex(&top_dex_data, self, balance_after_transfer)
+            .await;
 
         match sell_result {
-            Ok(_) => {
+            Ok(sell_swap) => {
+                if let Some(tx) = &sell_swap.tx {
+                    trace_step(anvil, tx, "sell", abi, &mut simulation.traces).await;
+                }
+
                 // After a successful sell, ensure that the token balance becomes zero.
                 let balance_after_sell = anvil
                     .get_wallet_token_balance_by_address(self.address)
@@ -154,11 +350,16 @@ impl ERC20Token {
                 if balance_after_sell != U256::from(0) {
                     println!("Cannot sell {}, scam alert", self.name);
                     // If the balance is not zero after sale, the token is flagged as unsellable.
-                    return Ok(TokenStatus::CannotSell);
+                    simulation.status = TokenStatus::CannotSell;
+                    return Ok(simulation);
                 }
 
+                simulation.tax.sell_tax = sell_swap.tax_percentage();
+                println!("sell tax => {:?}%", simulation.tax.sell_tax);
+
                 println!("{} is legit", self.name);
-                Ok(TokenStatus::Legit)
+                simulation.status = TokenStatus::Legit;
+                Ok(simulation)
             }
             Err(err) => {
                 println!("Sell transaction failed: {:?}", err);
@@ -167,3 +368,18 @@ impl ERC20Token {
         }
     }
 }
+
+/// Traces a step of the simulation and adds it to `traces`. A step that cannot be traced is logged
+/// rather than failing the simulation.
+async fn trace_step(
+    anvil: &AnvilTestSimulator,
+    tx: &SimulatedTx,
+    operation: &str,
+    token_abi: Option<&Abi>,
+    traces: &mut Vec<TraceReport>,
+) {
+    match anvil.trace_simulated_tx(tx, operation, token_abi).await {
+        Ok(trace_report) => traces.push(trace_report),
+        Err(e) => error!("Failed to trace {}: {}", operation, e),
+    }
+}
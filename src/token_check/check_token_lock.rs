@@ -7,7 +7,7 @@ use ethers::prelude::*;
 use std::sync::Arc;
 
 use crate::{
-    app_config::{TOKEN_LOCKERS_BASE, TOKEN_LOCKERS_MAINNET},
+    data::address_labels::ADDRESS_LABELS,
     token_check::external_api::{
         etherscan_api::get_token_holder_list, thegraph::shared::fetch_lp_holders,
     },
@@ -31,8 +31,8 @@ pub struct TokenHolders {
 /// This asynchronous function retrieves the total liquidity token supply and the list of top holders
 /// (using either Etherscan or The Graph based on the chain). It then determines:
 ///  1. The top holder by liquidity.
-///  2. The total locked balance by summing the balances from addresses that are on the designated
-///     list of token locker addresses.
+///  2. The total locked balance by summing the balances of the addresses labelled as burn addresses
+///     or lockers in the address label registry.
 ///  3. Whether this locked balance meets or exceeds a given percentage (threshold) of the total supply.
 ///
 /// # Arguments
@@ -102,15 +102,9 @@ pub async fn is_liquidity_locked(
             };
         }
 
-        // Depending on the chain, sum up balances from all known locker addresses.
-        if token.chain == Chain::Mainnet {
-            if TOKEN_LOCKERS_MAINNET.contains(&info.holder.as_str()) {
-                locked_balance = locked_balance + info.quantity;
-            }
-        } else {
-            if TOKEN_LOCKERS_BASE.contains(&info.holder.as_str()) {
-                locked_balance = locked_balance + info.quantity;
-            }
+        // Sum up balances from the burn addresses and lockers of the token's chain.
+        if ADDRESS_LABELS.is_burned_or_locked(&token.chain, &info.holder) {
+            locked_balance += info.quantity;
         }
     }
 
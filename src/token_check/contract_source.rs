@@ -0,0 +1,192 @@
+//! This module provides a structured representation of verified contract source code.
+//!
+//! Etherscan returns the `SourceCode` of a verified contract in one of three formats:
+//!
+//! - **Single file**: the flattened Solidity source as plain text.
+//! - **Multi file**: a JSON object mapping file paths to `{ "content": ... }`.
+//! - **Standard JSON**: the full solc standard-JSON input wrapped in an extra pair of braces
+//!   (`{{ ... }}`), with the files under `sources` and the compiler settings under `settings`.
+//!
+//! This module parses all three formats into a list of source files with their paths, together with
+//! the compiler version, optimizer settings, constructor arguments and ABI of the verification.
+
+use anyhow::{anyhow, Result};
+use ethers::abi::Abi;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The format Etherscan returned the verified source code in.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SourceFormat {
+    /// The contract is not verified, no source code is available.
+    #[default]
+    Unverified,
+    /// A single flattened source file.
+    SingleFile,
+    /// A JSON object of file paths to file contents.
+    MultiFile,
+    /// Solc standard-JSON input.
+    StandardJson,
+}
+
+/// A single source file of a verified contract.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceFile {
+    /// The path of the file as it was verified (e.g. `contracts/Token.sol`).
+    pub path: String,
+    /// The content of the file.
+    pub content: String,
+}
+
+/// The verified source code of a contract together with its compilation metadata.
+///
+/// # Fields
+///
+/// * `contract_name` - The name of the verified contract.
+/// * `format` - The format Etherscan returned the source code in.
+/// * `files` - The source files, sorted by path.
+/// * `compiler_version` - The solc (or vyper) version, e.g. `v0.8.20+commit.a1b79de6`.
+/// * `optimization_used` - Whether the optimizer was enabled.
+/// * `optimizer_runs` - The number of optimizer runs.
+/// * `evm_version` - The EVM version targeted by the compiler.
+/// * `constructor_arguments` - The ABI encoded constructor arguments (hex, without `0x`).
+/// * `license_type` - The SPDX license of the contract.
+/// * `abi` - The contract ABI as a JSON string.
+#[derive(Clone, Debug, Default)]
+pub struct ContractSource {
+    pub contract_name: String,
+    pub format: SourceFormat,
+    pub files: Vec<SourceFile>,
+    pub compiler_version: String,
+    pub optimization_used: bool,
+    pub optimizer_runs: u32,
+    pub evm_version: String,
+    pub constructor_arguments: String,
+    pub license_type: String,
+    pub abi: String,
+}
+
+impl ContractSource {
+    /// Returns `true` if source code is available for the contract.
+    pub fn is_verified(&self) -> bool {
+        !self.files.is_empty()
+    }
+
+    /// Parses the contract ABI, returning `None` if the contract is not verified.
+    pub fn parsed_abi(&self) -> Option<Abi> {
+        serde_json::from_str(&self.abi).ok()
+    }
+
+    /// Returns the file declaring the verified contract, if it can be found.
+    pub fn main_file(&self) -> Option<&SourceFile> {
+        let declarations = [
+            format!("contract {} ", self.contract_name),
+            format!("contract {}{{", self.contract_name),
+        ];
+        self.files.iter().find(|file| {
+            declarations
+                .iter()
+                .any(|declaration| file.content.contains(declaration.as_str()))
+        })
+    }
+
+    /// Combines all source files into a single readable source, each file preceded by a
+    /// `// File: <path>` header.
+    ///
+    /// The file declaring the verified contract is placed first, so it is kept when the combined
+    /// source is truncated (e.g. before it is sent to the AI).
+    pub fn combined_source(&self) -> String {
+        if self.format == SourceFormat::SingleFile {
+            return self
+                .files
+                .first()
+                .map(|file| file.content.clone())
+                .unwrap_or_default();
+        }
+
+        let main_file = self.main_file();
+        let mut ordered_files: Vec<&SourceFile> = main_file.into_iter().collect();
+        ordered_files.extend(self.files.iter().filter(|file| Some(*file) != main_file));
+
+        ordered_files
+            .iter()
+            .map(|file| format!("// File: {}\n\n{}", file.path, file.content))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
+
+/// Standard-JSON (and multi file) payload, only the fields we need are deserialized.
+#[derive(Debug, Deserialize)]
+struct StandardJsonInput {
+    sources: BTreeMap<String, SourceContent>,
+}
+
+/// The content of a single file inside a multi file or standard-JSON payload.
+#[derive(Debug, Deserialize)]
+struct SourceContent {
+    content: String,
+}
+
+/// Parses the Etherscan `SourceCode` field into its format and source files.
+///
+/// # Arguments
+///
+/// * `source_code` - The raw `SourceCode` value returned by Etherscan.
+/// * `contract_name` - The verified contract name, used to name single file sources.
+///
+/// # Returns
+///
+/// A tuple of the detected `SourceFormat` and the list of source files.
+///
+/// # Errors
+///
+/// Returns an error if the payload looks like JSON but cannot be parsed.
+pub fn parse_source_code(
+    source_code: &str,
+    contract_name: &str,
+) -> Result<(SourceFormat, Vec<SourceFile>)> {
+    let trimmed = source_code.trim();
+
+    if trimmed.is_empty() {
+        return Ok((SourceFormat::Unverified, Vec::new()));
+    }
+
+    // Standard JSON input is wrapped in an extra pair of braces.
+    if trimmed.starts_with("{{") && trimmed.ends_with("}}") {
+        let input: StandardJsonInput = serde_json::from_str(&trimmed[1..trimmed.len() - 1])
+            .map_err(|error| anyhow!("could not parse standard json source => {}", error))?;
+        return Ok((SourceFormat::StandardJson, into_source_files(input.sources)));
+    }
+
+    // Multi file sources are a JSON object of paths to contents. Some verifications also nest the
+    // files under `sources`.
+    if trimmed.starts_with('{') {
+        if let Ok(input) = serde_json::from_str::<StandardJsonInput>(trimmed) {
+            return Ok((SourceFormat::MultiFile, into_source_files(input.sources)));
+        }
+
+        let sources: BTreeMap<String, SourceContent> = serde_json::from_str(trimmed)
+            .map_err(|error| anyhow!("could not parse multi file source => {}", error))?;
+        return Ok((SourceFormat::MultiFile, into_source_files(sources)));
+    }
+
+    Ok((
+        SourceFormat::SingleFile,
+        vec![SourceFile {
+            path: format!("{}.sol", contract_name),
+            content: source_code.to_string(),
+        }],
+    ))
+}
+
+/// Converts parsed `sources` into a list of `SourceFile`.
+fn into_source_files(sources: BTreeMap<String, SourceContent>) -> Vec<SourceFile> {
+    sources
+        .into_iter()
+        .map(|(path, source)| SourceFile {
+            path,
+            content: source.content,
+        })
+        .collect()
+}
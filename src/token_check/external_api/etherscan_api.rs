@@ -1,7 +1,8 @@
 //! This module provides asynchronous functions to interact with the Etherscan API.
 //!
 //! It enables fetching token holder lists, contract source code, contract owner information,
-//! and token information. The module uses `reqwest` for HTTP requests and `serde` for JSON deserialization.
+//! contracts deployed by a wallet, and token information. The module uses `reqwest` for HTTP
+//! requests and `serde` for JSON deserialization.
 //!
 //! Make sure to set the environment variables `ETHERSCAN_API_KEY` and `ETHERSCAN_API` before using these functions.
 
@@ -14,6 +15,7 @@ use serde::Deserialize;
 use crate::utils::type_conversion::{address_to_string, string_to_bool};
 
 use crate::token_check::check_token_lock::TokenHolders;
+use crate::token_check::contract_source::{parse_source_code, ContractSource};
 
 /// Represents the generic response from the Etherscan API.
 ///
@@ -64,37 +66,37 @@ struct ContractSourceCode {
     source_code: String,
 
     #[serde(rename = "ABI")]
-    _abi: String,
+    abi: String,
 
     #[serde(rename = "ContractName")]
-    _contract_name: String,
+    contract_name: String,
 
     #[serde(rename = "CompilerVersion")]
-    _compiler_version: String,
+    compiler_version: String,
 
     #[serde(rename = "OptimizationUsed")]
-    _optimization_used: String,
+    optimization_used: String,
 
     #[serde(rename = "Runs")]
-    _runs: String,
+    runs: String,
 
     #[serde(rename = "ConstructorArguments")]
-    _constructor_arguments: String,
+    constructor_arguments: String,
 
     #[serde(rename = "EVMVersion")]
-    _evm_version: String,
+    evm_version: String,
 
     #[serde(rename = "Library")]
     _library: String,
 
     #[serde(rename = "LicenseType")]
-    _license_type: String,
+    license_type: String,
 
     #[serde(rename = "Proxy")]
-    _proxy: String,
+    proxy: String,
 
     #[serde(rename = "Implementation")]
-    _implementation: String,
+    implementation: String,
 
     #[serde(rename = "SwarmSource")]
     _swarm_source: String,
@@ -265,14 +267,17 @@ pub async fn get_token_holder_list(
 
 /// Fetches the source code of a contract from the Etherscan API.
 ///
+/// Multi file and standard-JSON verifications are combined into a single readable source,
+/// see [`ContractSource::combined_source`].
+///
 /// # Arguments
 ///
 /// * `contract_address` - A string slice representing the contract address.
 ///
 /// # Returns
 ///
-/// A `Result` containing the contract source code as a `String` on success, or an error if
-/// the request or parsing fails.
+/// A `Result` containing the contract source code as a `String` on success (empty if the contract
+/// is not verified), or an error if the request or parsing fails.
 ///
 /// # Errors
 ///
@@ -281,6 +286,109 @@ pub async fn get_token_holder_list(
 /// - The API returns a non-success status.
 /// - The JSON response cannot be parsed.
 pub async fn get_source_code(contract_address: &str, chain: &Chain) -> Result<String> {
+    let contract_source = get_contract_source(contract_address, chain).await?;
+
+    Ok(contract_source.combined_source())
+}
+
+/// Fetches the verified source code of a contract from the Etherscan API as a structured
+/// `ContractSource`.
+///
+/// Single file, multi file and standard-JSON verifications are parsed into a list of source files,
+/// together with the compiler version, optimizer settings and constructor arguments.
+///
+/// # Arguments
+///
+/// * `contract_address` - A string slice representing the contract address.
+/// * `chain` - The chain the contract is deployed on.
+///
+/// # Returns
+///
+/// A `Result` containing the `ContractSource`, with `SourceFormat::Unverified` and no files if the
+/// contract is not verified.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The HTTP request fails.
+/// - The API returns a non-success status.
+/// - The JSON response or the source code payload cannot be parsed.
+pub async fn get_contract_source(contract_address: &str, chain: &Chain) -> Result<ContractSource> {
+    let result = match fetch_contract_source_code(contract_address, chain).await? {
+        Some(result) => result,
+        None => return Ok(ContractSource::default()),
+    };
+
+    let (format, files) = parse_source_code(&result.source_code, &result.contract_name)?;
+
+    Ok(ContractSource {
+        contract_name: result.contract_name,
+        format,
+        files,
+        compiler_version: result.compiler_version,
+        optimization_used: result.optimization_used == "1",
+        optimizer_runs: result.runs.parse().unwrap_or_default(),
+        evm_version: result.evm_version,
+        constructor_arguments: result.constructor_arguments,
+        license_type: result.license_type,
+        abi: result.abi,
+    })
+}
+
+/// Retrieves the implementation address Etherscan has linked to a verified proxy contract.
+///
+/// Etherscan marks a contract as a proxy (`Proxy == "1"`) once someone has run its
+/// "Is this a proxy?" verification, and records the implementation it resolved at that time.
+/// The value may therefore be stale, and on-chain storage should be preferred when available.
+///
+/// # Arguments
+///
+/// * `contract_address` - A string slice representing the contract address.
+/// * `chain` - The chain the contract is deployed on.
+///
+/// # Returns
+///
+/// A `Result` containing `Some(implementation)` if Etherscan flags the contract as a proxy,
+/// or `None` otherwise.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The HTTP request fails.
+/// - The API returns a non-success status.
+/// - The JSON response cannot be parsed.
+pub async fn get_proxy_implementation(
+    contract_address: &str,
+    chain: &Chain,
+) -> Result<Option<Address>> {
+    let contract_source_code = match fetch_contract_source_code(contract_address, chain).await? {
+        Some(result) => result,
+        None => return Ok(None),
+    };
+
+    if contract_source_code.proxy != "1" {
+        return Ok(None);
+    }
+
+    // Etherscan leaves the implementation empty when it could not resolve one.
+    match contract_source_code.implementation.parse::<Address>() {
+        Ok(implementation) => Ok(Some(implementation)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Internal helper that calls the Etherscan `getsourcecode` action and returns the first result.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The HTTP request fails.
+/// - The API returns a non-success status.
+/// - The JSON response cannot be parsed.
+async fn fetch_contract_source_code(
+    contract_address: &str,
+    chain: &Chain,
+) -> Result<Option<ContractSourceCode>> {
     let etherscan_api_key = get_etherscan_api_key()?;
 
     let chain_id = *chain as u64;
@@ -314,13 +422,7 @@ address={}&apikey={}",
         ));
     }
 
-    // Return the source code from the first item in the result, if available.
-    let source_code = match parsed.result.first() {
-        Some(result) => result.source_code.clone(),
-        None => String::new(),
-    };
-
-    Ok(source_code)
+    Ok(parsed.result.into_iter().next())
 }
 
 /// Retrieves the contract owner's address from the Etherscan API.
@@ -394,6 +496,74 @@ contractaddresses={}&apikey={}",
     }
 }
 
+/// Retrieves the addresses of all contracts deployed directly by a wallet.
+///
+/// Walks the wallet's normal transaction list and keeps the successful contract creation
+/// transactions (no `to` address, populated `contractAddress`). Contracts created through a
+/// factory are not included, and Etherscan caps the transaction list at 10,000 entries.
+///
+/// # Arguments
+///
+/// * `wallet_address` - A string slice representing the deployer wallet address.
+/// * `chain` - The chain to look the wallet up on.
+///
+/// # Returns
+///
+/// A `Result` containing the lowercase addresses of the deployed contracts, oldest first.
+/// An empty vector is returned if the wallet has no transactions.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The HTTP request fails.
+/// - The API returns a non-success status.
+/// - The JSON response cannot be parsed.
+pub async fn get_contracts_deployed_by(wallet_address: &str, chain: &Chain) -> Result<Vec<String>> {
+    let etherscan_api_key = get_etherscan_api_key()?;
+
+    let chain_id = *chain as u64;
+    let etherscan_api = get_etherscan_api()?;
+
+    // Build the URL for fetching the wallet's normal transactions.
+    let url = format!(
+        "{}?chainid={}&module=account&action=txlist&address={}&\
+startblock=0&endblock=99999999&sort=asc&apikey={}",
+        etherscan_api, chain_id, wallet_address, etherscan_api_key
+    );
+
+    let client = Client::new();
+    let response = client.get(&url).send().await?;
+
+    // Ensure the response is successful.
+    if !response.status().is_success() {
+        return Err(anyhow!("Request failed with status: {}", response.status()));
+    }
+
+    let parsed: EtherscanResponse<EtherscanTransaction> = response.json().await?;
+
+    // Etherscan reports an empty transaction list as status 0.
+    if parsed.status != "1" {
+        if parsed.message == "No transactions found" {
+            return Ok(Vec::new());
+        }
+
+        return Err(anyhow!(
+            "Etherscan returned status={}, message={}",
+            parsed.status,
+            parsed.message
+        ));
+    }
+
+    let deployed_contracts = parsed
+        .result
+        .into_iter()
+        .filter(|tx| tx.to.is_empty() && !tx.contract_address.is_empty() && tx.is_error == "0")
+        .map(|tx| tx.contract_address.to_lowercase())
+        .collect();
+
+    Ok(deployed_contracts)
+}
+
 /// Fetches token information from the Etherscan API and converts it into `TokenWebData`.
 ///
 /// # Arguments
@@ -502,3 +672,17 @@ struct EtherscanHolderEntry {
     #[serde(rename = "TokenHolderQuantity")]
     token_holder_quantity: String,
 }
+
+/// Internal struct representing a normal transaction as returned by the Etherscan `txlist` action.
+///
+/// Only the fields needed to identify contract creations are deserialized.
+#[derive(Debug, Deserialize)]
+struct EtherscanTransaction {
+    to: String,
+
+    #[serde(rename = "contractAddress")]
+    contract_address: String,
+
+    #[serde(rename = "isError")]
+    is_error: String,
+}
@@ -64,6 +64,13 @@ struct MoralisTokenHolder {
     _percentage_relative_to_total_supply: f64,
 }
 
+/// Internal representation of the holder statistics of a token as returned by the Moralis API.
+#[derive(Debug, Deserialize)]
+struct MoralisTokenHolderStats {
+    #[serde(rename = "totalHolders")]
+    total_holders: u64,
+}
+
 /// Metadata information for an ERC20 token retrieved from the Moralis API.
 #[derive(Debug, Deserialize)]
 pub struct MoralisTokenMetadata {
@@ -174,6 +181,48 @@ pub async fn get_token_holder_list(
     Ok(holders)
 }
 
+/// Retrieves the total number of holders of a token.
+///
+/// Unlike [`get_token_holder_list`], which returns a single page of the largest holders, this calls
+/// Moralis' holder statistics endpoint, which counts every address holding the token.
+///
+/// # Arguments
+///
+/// * `token_address` - A string slice representing the token's contract address.
+///
+/// # Returns
+///
+/// * `anyhow::Result<u64>` - The number of holders.
+///
+/// # Errors
+///
+/// Returns an error if the API call fails or the response cannot be parsed.
+pub async fn get_token_holder_count(token_address: &str, chain: &Chain) -> anyhow::Result<u64> {
+    let api_key = get_moralis_api_key()?;
+    let root_url = get_moralis_api()?;
+    let url = format!(
+        "{}/erc20/{}/holders?chain={}",
+        root_url,
+        token_address,
+        get_moralis_chain_id(chain)
+    );
+
+    let response = reqwest::Client::new()
+        .get(&url)
+        .header("accept", "application/json")
+        .header("X-API-Key", api_key)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let response_text = response.text().await?;
+        return Err(anyhow!("Request failed with: {}", response_text));
+    }
+
+    let holder_stats: MoralisTokenHolderStats = response.json().await?;
+    Ok(holder_stats.total_holders)
+}
+
 /// Retrieves token information such as social media profiles and website.
 ///
 /// This function fetches token metadata from the Moralis API and extracts the first entry.
@@ -1,10 +1,8 @@
 use crate::{
-    data::{dex::Dex, token_data::ERC20Token},
+    data::token_data::ERC20Token, dex::swap_venue::swap_venue,
     token_check::check_token_lock::TokenHolders,
 };
 
-use super::{uniswap_v2::fetch_uniswap_v2_lp_holders, uniswap_v3::fetch_uniswap_v3_positions};
-
 /// Retrieves TheGraph API key from the environment.
 ///
 /// The function expects the `THEGRAPH_API_KEY` environment variable to be set. If it's not set,
@@ -26,7 +24,7 @@ pub fn get_thegraph_api_key() -> anyhow::Result<String> {
 /// Fetches liquidity pool holders for a given token.
 ///
 /// This function retrieves the holders of liquidity pool tokens based on the token's
-/// associated DEX (Decentralized Exchange). It supports Uniswap V2 and Uniswap V3.
+/// associated DEX (Decentralized Exchange), through the DEX's swap venue.
 ///
 /// # Arguments
 ///
@@ -48,10 +46,9 @@ pub async fn fetch_lp_holders(token: &ERC20Token) -> anyhow::Result<Vec<TokenHol
         .token_dex
         .ok_or_else(|| anyhow::anyhow!("No token DEX information found"))?;
 
-    match token_dex.dex {
-        Dex::UniswapV2 => fetch_uniswap_v2_lp_holders(token_dex.pair_address).await,
-        Dex::UniswapV3 => fetch_uniswap_v3_positions(token_dex.pair_address).await,
+    match swap_venue(&token_dex.dex) {
+        Some(venue) => venue.lp_holders(&token_dex, &token.chain).await,
         // Return empty vector for unsupported DEXes
-        _ => Ok(Vec::<TokenHolders>::new()),
+        None => Ok(Vec::<TokenHolders>::new()),
     }
 }
@@ -2,7 +2,7 @@ use anyhow::anyhow;
 use log::info;
 
 use crate::{
-    app_config::AI_MODEL,
+    app_config::{AI_MODEL, SIMULATION_TRADE_SIZE_USD},
     data::{
         provider_manager::get_chain_provider,
         token_checklist_cache::get_token_checklist_from_cache,
@@ -38,7 +38,9 @@ pub async fn get_token_audit_and_reputation_score(
             // If not in cache, fetch token data and generate a new checklist
             if let Some(token_data) = get_core_token_data_by_address(token_address).await? {
                 let client = get_chain_provider(&token_data.chain).await?;
-                let checklist = generate_token_checklist(&token_data, &client).await?;
+                let checklist =
+                    generate_token_checklist(&token_data, &client, SIMULATION_TRADE_SIZE_USD)
+                        .await?;
                 checklist
             } else {
                 return Err(anyhow!(
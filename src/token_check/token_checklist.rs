@@ -1,14 +1,30 @@
-use super::anvil::validation::TokenStatus;
+use super::anvil::fee_receivers::FeeReceiver;
+use super::anvil::owner_privileges::OwnerPrivilegeCheck;
+use super::anvil::rug_pull::RugPullCheck;
+use super::anvil::time_travel::TimeBombCheck;
+use super::anvil::trace_report::TraceReport;
+use super::anvil::trade_limits::TradeLimits;
+use super::anvil::validation::{TokenSimulation, TokenStatus};
 use super::external_api::moralis;
+use super::token_deployer_check::{get_token_deployer_check, TokenDeployerCheck};
+use super::token_depth_check::{get_token_depth_check, TokenDepthCheck};
 use super::token_holder_check::get_token_holder_check;
-use super::token_liquidity_check::get_percentage_liquidity_locked_or_burned;
+use super::token_hook_check::{get_token_hook_check, TokenHookCheck};
+use super::token_liquidity_check::get_liquidity_lock;
+use super::token_lock_expiry_check::{get_token_lock_expiry_check, TokenLockExpiryCheck};
+use super::token_ownership_check::{
+    get_token_ownership_check, is_renounced_address, TokenOwnershipCheck,
+};
+use super::token_pool_check::{get_token_pool_check, TokenPoolCheck};
+use super::token_proxy_check::{get_token_proxy_check, TokenProxyCheck};
 use crate::app_config::AI_MODEL;
 use crate::data::token_data::ERC20Token;
 use crate::token_check::ai::ai_submission::check_code_with_ai;
-use crate::token_check::external_api::etherscan_api::{get_source_code, TokenWebData};
-use crate::token_check::token_holder_check::TokenHolderCheck;
+use crate::token_check::external_api::etherscan_api::{get_contract_source, TokenWebData};
+use crate::token_check::token_holder_check::{HolderDistribution, TokenHolderCheck};
 use crate::utils::type_conversion::address_to_string;
 use ethers::providers::{Provider, Ws};
+use log::warn;
 use std::sync::Arc;
 
 ///! This module implements token checking functionality to evaluate the
@@ -35,15 +51,53 @@ pub struct TokenCheckList {
     /// Explanation as to why suspicious code might be legitimate or not.
     pub reason_could_or_couldnt_justify_suspicious_code: String,
 
+    // Fields derived from proxy detection
+    /// Proxy pattern, implementation and upgrade authority of the token contract.
+    /// An upgradeable token can have its logic replaced at any time, which is a risk factor on its own.
+    pub proxy: TokenProxyCheck,
+
+    // Fields derived from ownership analysis
+    /// Who controls the token contract (owner, AccessControl admins), whether ownership is renounced
+    /// and how much of the supply the owner holds.
+    pub ownership: TokenOwnershipCheck,
+
+    // Fields derived from deployer reputation analysis
+    /// The wallet that deployed the token, its other deployments, how many of those we previously
+    /// found to be scams, and how much of the supply the deployer still holds.
+    pub deployer: TokenDeployerCheck,
+
     // Fields derived from token holder and liquidity analysis
     /// The percentage of total tokens held by the top token holder.
     pub top_holder_percentage_tokens_held: f64,
     /// The percentage of tokens that are locked or burned.
     pub percentage_of_tokens_locked_or_burned: f64,
+    /// How the circulating supply is distributed: the share of the top 10 and top 50 holders, the
+    /// Gini coefficient and HHI of the balances, the number of holders, the share held by contracts
+    /// versus EOAs, and the share held by the deployer and owner.
+    pub holder_distribution: HolderDistribution,
     /// The percentage of liquidity tokens that are locked or burned.
     pub percentage_liquidity_locked_or_burned: Option<f64>,
-    /// The liquidity in USD for the token.
+    /// The locks of the pool's LP tokens with when each expires, and the percentage of LP tokens still
+    /// locked 30 days to a year from now.
+    pub lp_lock_expiry: TokenLockExpiryCheck,
+    /// The liquidity in USD for the token, valued from the pool's on-chain reserves with Chainlink
+    /// prices, 0 if the pool could not be valued on-chain.
     pub liquidity_in_usd: f64,
+    /// Whether the pool could not be valued on-chain (e.g. a stale Chainlink price), in which case
+    /// `liquidity_in_usd` is 0 regardless of the pool's actual liquidity.
+    pub onchain_valuation_failed: bool,
+    /// The liquidity in USD for the token as reported by TheGraph, `None` if the pool was not found
+    /// on a subgraph.
+    pub subgraph_liquidity_in_usd: Option<f64>,
+    /// Every pool of the token with its liquidity, lock status and age, the total liquidity, how
+    /// fragmented it is, and whether an older pool was drained while the top pool is tiny or new.
+    pub pools: TokenPoolCheck,
+    /// The price impact of buying and selling the token through its top pool for trade sizes from
+    /// $100 to $100k, and the largest position that can realistically be sold.
+    pub depth: TokenDepthCheck,
+    /// The hook of the token's Uniswap V4 pool: the callbacks it runs and a review of its code.
+    /// `None` if the token's pool is not a V4 pool or has no hook.
+    pub uniswap_v4_hook: Option<TokenHookCheck>,
 
     // Fields derived from online presence checks
     /// Indicates whether the token has a website.
@@ -54,23 +108,61 @@ pub struct TokenCheckList {
     /// Represents whether the token is sellable based on simulation.
     /// `Some(true)` if sellable, `Some(false)` if not sellable, `None` if buy simulation failed.
     pub is_token_sellable: Option<bool>,
+
+    // Fields derived from the simulated buy/sell, `None` if that step of the simulation did not run
+    /// Percentage of the quoted amount taken when buying the token (0.0 to 100.0).
+    pub buy_tax_percentage: Option<f64>,
+    /// Percentage of the quoted amount taken when selling the token (0.0 to 100.0).
+    pub sell_tax_percentage: Option<f64>,
+    /// Percentage of the amount taken on a wallet to wallet transfer (0.0 to 100.0).
+    pub transfer_tax_percentage: Option<f64>,
+    /// Balance and sellability re-checked at several horizons after purchase (minutes to days),
+    /// and the horizon at which the token's behaviour changed, if it did.
+    pub time_bomb: TimeBombCheck,
+    /// Effective max buy, sell, transfer and wallet sizes (as a percentage of supply) and the sell
+    /// cooldown found by probing trades of increasing size, `None` where no limit was hit.
+    pub trade_limits: TradeLimits,
+    /// Privileged functions (fees, blacklist, pause, limits) the owner was impersonated to call during
+    /// the simulation, and whether each made the token unsellable.
+    pub owner_privileges: OwnerPrivilegeCheck,
+    /// The liquidity the largest unlocked LP holders could pull, and how much less a holder's sell
+    /// would return afterwards: the worst case if the team rugs.
+    pub rug_pull: RugPullCheck,
+    /// Decoded call traces of the simulated buy, transfer and sell: where a step reverted, the
+    /// revert reason or custom error, and the events emitted.
+    pub simulation_traces: Vec<TraceReport>,
+    /// Addresses other than the pair and our wallets that received tokens during the simulated
+    /// trades, whether each is a contract or tied to the team, and its share of the tokens traded.
+    pub fee_receivers: Vec<FeeReceiver>,
 }
 
 /// Generates the token checklist by performing a sequence of asynchronous validations.
 ///
 /// This function performs the following steps:
-/// 1. Retrieves the token's source code.
+/// 1. Detects whether the token is a proxy and retrieves the source code of the contract holding its
+///    logic (the implementation for proxies, the token itself otherwise).
 /// 2. Analyzes the source code using an AI model to determine if there is any potentially scammy behavior.
-/// 3. Obtains token holder details.
-/// 4. Retrieves liquidity information (if token is on a DEX).
-/// 5. Checks the percentage of liquidity locked or burned (if token is on a DEX).
-/// 6. Simulates buy/sell transactions to verify token sellability (if token is on a DEX).
-/// 7. Checks the token's online presence (e.g., website, Twitter, Discord).
+/// 3. Lists the token's pools on every DEX (if token is on a DEX), and obtains token holder details
+///    and how the circulating supply is distributed, leaving the pools out.
+/// 4. Determines who owns the contract and what privileges the owner has.
+/// 5. Evaluates the reputation of the wallet that deployed the token.
+/// 6. Retrieves liquidity information, compares it across the pools listed in step 3, measures the
+///    price impact of a ladder of trade sizes, and reviews the hook of the token's pool if it is a
+///    Uniswap V4 pool with a hook (if token is on a DEX).
+/// 7. Checks the percentage of liquidity locked or burned, who holds the unlocked liquidity, and
+///    when the locks expire (if token is on a DEX).
+/// 8. Simulates buy/sell transactions to verify token sellability, measure buy, sell and
+///    transfer taxes, detect tokens that stop being sellable after a delay, discover max-tx,
+///    max-wallet and cooldown limits, find owner actions that trap holders, and measure the impact
+///    of the unlocked liquidity being pulled, and find who receives the token's fees (if token is on
+///    a DEX).
+/// 9. Checks the token's online presence (e.g., website, Twitter, Discord).
 ///
 /// # Arguments
 ///
 /// * `token` - An instance of `ERC20Token` representing the token to be checked.
 /// * `client` - An `Arc`-wrapped Ethereum provider for interacting with the blockchain.
+/// * `trade_size_usd` - The size (in USD) of the simulated purchase, e.g. `SIMULATION_TRADE_SIZE_USD`.
 ///
 /// # Returns
 ///
@@ -82,13 +174,33 @@ pub struct TokenCheckList {
 pub async fn generate_token_checklist(
     token: &ERC20Token,
     client: &Arc<Provider<Ws>>,
+    trade_size_usd: f64,
 ) -> anyhow::Result<TokenCheckList> {
     // Convert the token address to a string format for API calls.
     let token_address = address_to_string(token.address);
 
-    // Step 1: Retrieve token source code.
-    println!("1. grabbing source code..");
-    let token_code = get_source_code(&token_address, &token.chain).await?;
+    // Step 1: Detect proxies and retrieve the source code of the contract holding the token logic.
+    println!("1. checking for proxy and grabbing source code..");
+    // The auxiliary checks below are reported as defaults when they fail, rather than failing the
+    // audit.
+    let proxy = get_token_proxy_check(token, client)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("proxy check failed => {}", e);
+            TokenProxyCheck::default()
+        });
+    let contract_source = match proxy.implementation_address {
+        Some(implementation) => {
+            println!(
+                "token is a {:?} proxy, reviewing implementation..",
+                proxy.proxy_type
+            );
+            let implementation_address = address_to_string(implementation);
+            get_contract_source(&implementation_address, &token.chain).await?
+        }
+        None => get_contract_source(&token_address, &token.chain).await?,
+    };
+    let token_code = contract_source.combined_source();
 
     // Step 2: Analyze the token's source code using an AI model.
     println!("2. checking source code..");
@@ -98,34 +210,112 @@ pub async fn generate_token_checklist(
 
     // Step 3: Perform token holder check. If no check is available, default values are used.
     println!("3. token holder check...");
-    let token_holder_check = match get_token_holder_check(&token, client).await? {
+    // The pools hold the token's liquidity, not a holder's position, so they are listed first.
+    let pools = match token.token_dex {
+        Some(_) => get_token_pool_check(token, client).await?,
+        None => TokenPoolCheck::default(),
+    };
+    let pool_addresses = pools
+        .pools
+        .iter()
+        .map(|pool| pool.pair_address)
+        .collect::<Vec<_>>();
+    let token_holder_check = match get_token_holder_check(token, &pool_addresses, client).await? {
         Some(check) => check,
         None => TokenHolderCheck::default(),
     };
 
+    // Step 4: Determine who controls the token contract.
+    println!("4. ownership check...");
+    let ownership = get_token_ownership_check(token, client)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("ownership check failed => {}", e);
+            TokenOwnershipCheck::default()
+        });
+
+    // Step 5: Evaluate the deployer's reputation. If the deployer is unknown, default values are used.
+    println!("5. deployer reputation check...");
+    let deployer = get_token_deployer_check(token, client)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("deployer check failed => {}", e);
+            None
+        })
+        .unwrap_or_default();
+
+    // The deployer and owner are only known now, so their holdings complete the holder distribution.
+    let mut holder_distribution = token_holder_check.distribution;
+    holder_distribution.deployer_and_owner_percentage = if ownership.owner_address.is_some()
+        && ownership.owner_address == deployer.deployer_address
+    {
+        deployer.deployer_percentage_tokens_held
+    } else {
+        deployer.deployer_percentage_tokens_held + ownership.owner_percentage_tokens_held
+    };
+
     // Default values if Token is NOT on a DEX
     let mut liquidity_in_usd = 0.0;
+    let mut subgraph_liquidity_in_usd: Option<f64> = None;
+    let mut onchain_valuation_failed = false;
+    let mut depth = TokenDepthCheck::default();
+    let mut uniswap_v4_hook: Option<TokenHookCheck> = None;
     let mut percentage_liquidity_locked_or_burned: Option<f64> = None;
+    let mut lp_lock_expiry = TokenLockExpiryCheck::default();
     let mut is_token_sellable: Option<bool> = None;
+    let mut simulation = TokenSimulation::default();
 
     // The following steps only execute if the token is on a DEX
     match token.clone().token_dex {
         Some(token_dex) => {
-            // Step 4: Retrieve liquidity information.
-            println!("4. getting liquidity...");
+            // Step 6: Retrieve liquidity information.
+            println!("6. getting liquidity...");
             liquidity_in_usd = token_dex.liquidity_in_usd;
+            subgraph_liquidity_in_usd = token_dex.subgraph_liquidity_in_usd;
+            onchain_valuation_failed = token_dex.onchain_valuation_failed;
+            depth = get_token_depth_check(token, client)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("depth check failed => {}", e);
+                    TokenDepthCheck::default()
+                });
+            uniswap_v4_hook = get_token_hook_check(token).await.unwrap_or_else(|e| {
+                warn!("hook check failed => {}", e);
+                None
+            });
 
-            // Step 5: Retrieve the percentage of liquidity that is locked or burned.
-            println!("5. getting % liquidity burned or locked...");
-            percentage_liquidity_locked_or_burned =
-                get_percentage_liquidity_locked_or_burned(&token, client).await?;
+            // Step 7: Retrieve the percentage of liquidity that is locked or burned.
+            println!("7. getting % liquidity burned or locked...");
+            let liquidity_lock = get_liquidity_lock(token, client).await?;
+            percentage_liquidity_locked_or_burned = liquidity_lock.percentage_locked_or_burned;
+            lp_lock_expiry = get_token_lock_expiry_check(token, client)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("lock expiry check failed => {}", e);
+                    TokenLockExpiryCheck::default()
+                });
 
-            // Step 6: Simulate a buy/sell to check token sellability.
-            println!("6. running buy / sell simulation with anvil...");
-            let token_status_from_simulated_buy_sell =
-                token.validate_with_simulated_buy_sell().await?;
+            // Step 8: Simulate a buy/sell to check token sellability.
+            println!("8. running buy / sell simulation with anvil...");
+            let related_accounts = ownership
+                .owner_address
+                .into_iter()
+                .chain(ownership.admin_role_holders.iter().copied())
+                .chain(deployer.deployer_address)
+                // A renounced owner is a burn address, which receives no fees.
+                .filter(|account| !is_renounced_address(*account))
+                .collect::<Vec<_>>();
+            simulation = token
+                .validate_with_simulated_buy_sell(
+                    trade_size_usd,
+                    ownership.privileged_account(),
+                    contract_source.parsed_abi().as_ref(),
+                    &liquidity_lock.unlocked_lp_holders,
+                    &related_accounts,
+                )
+                .await?;
 
-            is_token_sellable = match token_status_from_simulated_buy_sell {
+            is_token_sellable = match simulation.status {
                 TokenStatus::CannotSell => Some(false),
                 TokenStatus::Legit => Some(true),
                 TokenStatus::CannotBuy => None, // Cannot determine sellability if buying fails
@@ -136,8 +326,8 @@ pub async fn generate_token_checklist(
         }
     }
 
-    // Step 7: Check for online presence details of the token.
-    println!("7. getting online presence...");
+    // Step 9: Check for online presence details of the token.
+    println!("9. getting online presence...");
     let token_online_presence = match moralis::get_token_info(&token_address, &token.chain).await? {
         Some(online_presence) => online_presence,
         None => TokenWebData::default(),
@@ -152,15 +342,34 @@ pub async fn generate_token_checklist(
             .could_legitimately_justify_suspicious_code,
         reason_could_or_couldnt_justify_suspicious_code: token_code_check
             .reason_could_be_legitimate_or_not,
+        proxy,
+        ownership,
+        deployer,
         top_holder_percentage_tokens_held: token_holder_check.top_holder_percentage,
         percentage_of_tokens_locked_or_burned: token_holder_check
             .percentage_tokens_burned_or_locked,
+        holder_distribution,
         percentage_liquidity_locked_or_burned,
+        lp_lock_expiry,
         liquidity_in_usd,
+        onchain_valuation_failed,
+        subgraph_liquidity_in_usd,
+        pools,
+        depth,
+        uniswap_v4_hook,
         has_website: !token_online_presence.website.is_empty(),
         has_twitter_or_discord: !token_online_presence.twitter.is_empty()
             || !token_online_presence.discord.is_empty(),
         is_token_sellable,
+        buy_tax_percentage: simulation.tax.buy_tax,
+        sell_tax_percentage: simulation.tax.sell_tax,
+        transfer_tax_percentage: simulation.tax.transfer_tax,
+        time_bomb: simulation.time_bomb,
+        trade_limits: simulation.trade_limits,
+        owner_privileges: simulation.owner_privileges,
+        rug_pull: simulation.rug_pull,
+        simulation_traces: simulation.traces,
+        fee_receivers: simulation.fee_receivers,
     };
 
     Ok(token_checklist)
@@ -0,0 +1,180 @@
+//! This module implements the deployer reputation check.
+//!
+//! It identifies the wallet that deployed a token (using the Etherscan contract creation data),
+//! looks up the other contracts that wallet has deployed, and joins them against the stored audit
+//! results (the token checklist and token score caches) to count how many of them were previously
+//! found to be scams. It also records how much of the token supply the deployer still holds.
+
+use anyhow::Result;
+use ethers::prelude::*;
+use log::warn;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{
+    abi::erc20::ERC20,
+    data::{
+        token_checklist_cache::get_token_checklists_from_cache, token_data::ERC20Token,
+        token_score_cache::get_token_token_scores_from_cache,
+    },
+    token_check::{
+        external_api::etherscan_api::{get_contract_owner, get_contracts_deployed_by},
+        token_checklist::TokenCheckList,
+        token_holder_check::u256_div_u256_to_f64,
+        token_score::{get_token_score_with_rules_based_approch, TokenScore, TokenScoreAssessment},
+    },
+    utils::type_conversion::address_to_string,
+};
+
+/// Holds the results for the deployer reputation check.
+///
+/// # Fields
+///
+/// * `deployer_address` - The wallet that deployed the token contract.
+/// * `deployer_percentage_tokens_held` - The percentage of the total token supply the deployer still holds.
+/// * `previous_contracts_deployed` - Number of other contracts the deployer has created.
+/// * `previous_tokens_audited` - How many of those contracts we have stored audit results for.
+/// * `previous_scam_count` - How many of the audited contracts were found to be a scam or likely scam.
+/// * `previous_scam_addresses` - The addresses of those scams.
+#[derive(Clone, Debug, Default)]
+pub struct TokenDeployerCheck {
+    pub deployer_address: Option<Address>,
+    pub deployer_percentage_tokens_held: f64,
+    pub previous_contracts_deployed: usize,
+    pub previous_tokens_audited: usize,
+    pub previous_scam_count: usize,
+    pub previous_scam_addresses: Vec<String>,
+}
+
+impl TokenDeployerCheck {
+    /// Returns `true` if the deployer has previously deployed a token we found to be a scam.
+    pub fn has_scam_history(&self) -> bool {
+        self.previous_scam_count > 0
+    }
+}
+
+/// Retrieves the deployer of a token and evaluates its reputation.
+///
+/// This asynchronous function performs the following steps:
+///
+/// 1. Retrieves the contract creator from Etherscan.
+/// 2. Retrieves the other contracts the deployer has created.
+/// 3. Joins them against the stored audit results to count previous scams.
+/// 4. Computes the percentage of the token supply still held by the deployer.
+///
+/// # Arguments
+///
+/// * `token` - A reference to the ERC20 token data.
+/// * `client` - A shared reference (Arc) to the WebSocket provider used for asynchronous operations.
+///
+/// # Returns
+///
+/// * `Ok(Some(TokenDeployerCheck))` if the deployer could be identified.
+/// * `Ok(None)` if Etherscan has no contract creation information for the token, or could not be
+///   reached (e.g. rate limited).
+/// * `Err` if any of the asynchronous calls fail.
+pub async fn get_token_deployer_check(
+    token: &ERC20Token,
+    client: &Arc<Provider<Ws>>,
+) -> Result<Option<TokenDeployerCheck>> {
+    // Step 1: Identify the deployer.
+    let token_address = address_to_string(token.address);
+    let deployer_address = match get_contract_owner(&token_address, &token.chain).await {
+        Ok(Some(deployer)) => deployer.parse::<Address>()?,
+        Ok(None) => return Ok(None),
+        Err(error) => {
+            warn!("could not get contract creator from etherscan => {}", error);
+            return Ok(None);
+        }
+    };
+    let deployer = address_to_string(deployer_address);
+
+    // Step 2: Other contracts created by the deployer. A failure here should not fail the audit.
+    let previous_contracts: Vec<String> =
+        match get_contracts_deployed_by(&deployer, &token.chain).await {
+            Ok(deployed_contracts) => deployed_contracts
+                .into_iter()
+                .filter(|contract| *contract != token_address)
+                .collect(),
+            Err(error) => {
+                warn!(
+                    "could not get contracts deployed by {} => {}",
+                    deployer, error
+                );
+                Vec::new()
+            }
+        };
+
+    // Step 3: Join against the stored audit results.
+    let token_checklists = get_token_checklists_from_cache().await;
+    let token_scores = get_token_token_scores_from_cache().await;
+    let (previous_tokens_audited, previous_scam_addresses) =
+        find_previous_scams(&previous_contracts, &token_checklists, &token_scores);
+
+    // Step 4: Percentage of the supply held by the deployer.
+    let mut deployer_percentage_tokens_held = 0.0;
+    let total_supply = token.get_total_token_supply(client).await?;
+    if !total_supply.is_zero() {
+        let token_contract = ERC20::new(token.address, client.clone());
+        let deployer_balance = token_contract.balance_of(deployer_address).call().await?;
+        deployer_percentage_tokens_held =
+            100_f64 * u256_div_u256_to_f64(deployer_balance, total_supply)?;
+    }
+
+    println!(
+        "deployer {} has deployed {} other contracts, {} previous scams",
+        deployer,
+        previous_contracts.len(),
+        previous_scam_addresses.len()
+    );
+
+    Ok(Some(TokenDeployerCheck {
+        deployer_address: Some(deployer_address),
+        deployer_percentage_tokens_held,
+        previous_contracts_deployed: previous_contracts.len(),
+        previous_tokens_audited,
+        previous_scam_count: previous_scam_addresses.len(),
+        previous_scam_addresses,
+    }))
+}
+
+/// Joins previously deployed contracts against the stored audit results.
+///
+/// A contract counts as a previous scam if its stored AI score is "Likely Scam" or "Scam", or, when
+/// no score is stored, if the rules-based score of its stored checklist is.
+///
+/// # Arguments
+///
+/// * `previous_contracts` - Lowercase addresses of the contracts deployed by the deployer.
+/// * `token_checklists` - Stored token checklists, keyed by lowercase token address.
+/// * `token_scores` - Stored token scores, keyed by lowercase token address.
+///
+/// # Returns
+///
+/// A tuple of the number of contracts with stored audit results and the addresses of the scams.
+pub fn find_previous_scams(
+    previous_contracts: &[String],
+    token_checklists: &HashMap<String, TokenCheckList>,
+    token_scores: &HashMap<String, TokenScoreAssessment>,
+) -> (usize, Vec<String>) {
+    let mut previous_tokens_audited = 0;
+    let mut previous_scam_addresses = Vec::new();
+
+    for contract in previous_contracts {
+        let is_scam = match (token_scores.get(contract), token_checklists.get(contract)) {
+            (Some(token_score), _) => token_score.is_scam(),
+            (None, Some(token_checklist)) => matches!(
+                get_token_score_with_rules_based_approch(token_checklist.clone()),
+                TokenScore::Scam | TokenScore::LikelyScam
+            ),
+            (None, None) => continue,
+        };
+
+        previous_tokens_audited += 1;
+        if is_scam {
+            previous_scam_addresses.push(contract.clone());
+        }
+    }
+
+    (previous_tokens_audited, previous_scam_addresses)
+}
@@ -0,0 +1,284 @@
+//! This module measures how deep the token's pool is, from the price impact of a ladder of trade sizes.
+//!
+//! The pool's liquidity in USD alone does not tell whether a holder can exit a $5k position: a V3
+//! pool's liquidity can sit in ticks far from the current price. Buys and sells of each size of
+//! [`DEPTH_LADDER_TRADE_SIZES_USD`] are quoted through the pool's swap venue (the router's reserves
+//! math for V2 pairs, the quoter, which crosses the initialized ticks, for concentrated liquidity
+//! pools), and compared with the rate of a tiny reference trade to get each size's price impact.
+
+use anyhow::Result;
+use ethers::prelude::*;
+use ethers::utils::parse_units;
+use std::sync::Arc;
+
+use crate::{
+    abi::erc20::ERC20,
+    app_config::{
+        DEPTH_LADDER_TRADE_SIZES_USD, DEPTH_MAX_EXIT_PRICE_IMPACT_PERCENTAGE,
+        DEPTH_REFERENCE_TRADE_SIZE_USD,
+    },
+    data::token_data::ERC20Token,
+    dex::swap_venue::swap_venue,
+    utils::{tx::get_token_price_usd, type_conversion::u256_to_f64},
+};
+
+/// The price impact of buying and selling one trade size of the ladder.
+///
+/// # Fields
+///
+/// * `trade_size_usd` - The trade size in USD.
+/// * `buy_price_impact_percentage` - How much worse than the reference rate a buy of this size is
+///   filled (0.0 to 100.0), `None` if the quote failed, e.g. the pool cannot fill the trade.
+/// * `sell_price_impact_percentage` - The same for a sell of this size.
+#[derive(Clone, Debug, Default)]
+pub struct DepthLevel {
+    pub trade_size_usd: f64,
+    pub buy_price_impact_percentage: Option<f64>,
+    pub sell_price_impact_percentage: Option<f64>,
+}
+
+/// Holds the results of the depth check.
+///
+/// # Fields
+///
+/// * `levels` - The price impact of each trade size of the ladder, smallest first. Empty if the
+///   token's pool could not be quoted.
+/// * `max_exit_size_usd` - The largest trade size of the ladder that can be sold with less than
+///   [`DEPTH_MAX_EXIT_PRICE_IMPACT_PERCENTAGE`] price impact, `None` if not even the smallest can.
+#[derive(Clone, Debug, Default)]
+pub struct TokenDepthCheck {
+    pub levels: Vec<DepthLevel>,
+    pub max_exit_size_usd: Option<f64>,
+}
+
+impl TokenDepthCheck {
+    /// Builds the depth check from the ladder's levels, computing the largest size that can be sold.
+    pub fn from_levels(levels: Vec<DepthLevel>) -> Self {
+        let max_exit_size_usd = levels
+            .iter()
+            .filter(|level| {
+                level
+                    .sell_price_impact_percentage
+                    .is_some_and(|impact| impact < DEPTH_MAX_EXIT_PRICE_IMPACT_PERCENTAGE)
+            })
+            .map(|level| level.trade_size_usd)
+            .reduce(f64::max);
+
+        Self {
+            levels,
+            max_exit_size_usd,
+        }
+    }
+}
+
+/// Computes the price impact of a trade, comparing its rate with the rate of a reference trade small
+/// enough not to move the price. The pool fee applies to both trades, so it is not counted as impact.
+///
+/// # Arguments
+///
+/// * `reference_amount_in` - The amount swapped by the reference trade.
+/// * `reference_amount_out` - The amount the reference trade returns.
+/// * `amount_in` - The amount swapped by the trade.
+/// * `amount_out` - The amount the trade returns.
+///
+/// # Returns
+///
+/// * `f64` - The price impact (0.0 to 100.0).
+pub fn price_impact_percentage(
+    reference_amount_in: f64,
+    reference_amount_out: f64,
+    amount_in: f64,
+    amount_out: f64,
+) -> f64 {
+    if reference_amount_in <= 0.0 || reference_amount_out <= 0.0 || amount_in <= 0.0 {
+        return 100.0;
+    }
+    let reference_rate = reference_amount_out / reference_amount_in;
+    let rate = amount_out / amount_in;
+    (100_f64 * (1_f64 - rate / reference_rate)).clamp(0.0, 100.0)
+}
+
+/// Measures the depth of the token's pool from the price impact of a ladder of trade sizes.
+///
+/// This asynchronous function performs the following steps:
+///
+/// 1. Prices the pool's base token in USD with its Chainlink feed.
+/// 2. Quotes a reference buy and sell of [`DEPTH_REFERENCE_TRADE_SIZE_USD`], giving the pool's rate
+///    before any price impact and the token's price.
+/// 3. Quotes a buy and a sell of each size of [`DEPTH_LADDER_TRADE_SIZES_USD`], and computes their
+///    price impact against the reference rates.
+///
+/// # Arguments
+///
+/// * `token` - A reference to the ERC20 token data.
+/// * `client` - A shared reference (Arc) to the WebSocket provider used for asynchronous operations.
+///
+/// # Returns
+///
+/// * `Ok(TokenDepthCheck)` with the price impact of each trade size, without levels if the token is
+///   not on a supported DEX, the base token has no usable price, or its pool could not be quoted.
+/// * `Err` if the base token's decimals cannot be read.
+pub async fn get_token_depth_check(
+    token: &ERC20Token,
+    client: &Arc<Provider<Ws>>,
+) -> Result<TokenDepthCheck> {
+    let token_dex = match &token.token_dex {
+        Some(token_dex) => token_dex,
+        None => return Ok(TokenDepthCheck::default()),
+    };
+    let venue = match swap_venue(&token_dex.dex) {
+        Some(venue) => venue,
+        None => return Ok(TokenDepthCheck::default()),
+    };
+
+    // Step 1: Price the base token.
+    let base_token_address: Address = token_dex.base_token_address.parse()?;
+    let base_token_decimals = get_base_token_decimals(base_token_address, client).await?;
+    let base_token_price =
+        match get_token_price_usd(&token_dex.base_token_symbol, &token.chain, client).await {
+            // A zero price would turn every trade size into an infinite amount of base token.
+            Ok(price) if price.is_finite() && price > 0.0 => price,
+            result => {
+                println!(
+                    "could not price {} for depth check: {:?}",
+                    token_dex.base_token_symbol, result
+                );
+                return Ok(TokenDepthCheck::default());
+            }
+        };
+
+    let quote = |token_in: Address, token_out: Address, amount_in: U256| async move {
+        venue
+            .quote(
+                token_dex,
+                token_in,
+                token_out,
+                amount_in,
+                &token.chain,
+                client,
+            )
+            .await
+    };
+
+    // Step 2: Quote the reference trades.
+    let reference_buy_in = match to_units(
+        DEPTH_REFERENCE_TRADE_SIZE_USD / base_token_price,
+        base_token_decimals,
+    ) {
+        Some(amount_in) => amount_in,
+        None => return Ok(TokenDepthCheck::default()),
+    };
+    let reference_buy_out = match quote(base_token_address, token.address, reference_buy_in).await {
+        Ok(amount_out) if !amount_out.is_zero() => amount_out,
+        result => {
+            println!(
+                "could not quote reference buy for depth check: {:?}",
+                result
+            );
+            return Ok(TokenDepthCheck::default());
+        }
+    };
+    let tokens_per_usd =
+        units_to_f64(reference_buy_out, token.decimals) / DEPTH_REFERENCE_TRADE_SIZE_USD;
+
+    let reference_sell_in = match to_units(
+        DEPTH_REFERENCE_TRADE_SIZE_USD * tokens_per_usd,
+        token.decimals,
+    ) {
+        Some(amount_in) => amount_in,
+        None => return Ok(TokenDepthCheck::default()),
+    };
+    let reference_sell_out = match quote(token.address, base_token_address, reference_sell_in).await
+    {
+        Ok(amount_out) if !amount_out.is_zero() => amount_out,
+        result => {
+            println!(
+                "could not quote reference sell for depth check: {:?}",
+                result
+            );
+            return Ok(TokenDepthCheck::default());
+        }
+    };
+
+    // Step 3: Quote each trade size of the ladder.
+    let mut levels = Vec::with_capacity(DEPTH_LADDER_TRADE_SIZES_USD.len());
+    for trade_size_usd in DEPTH_LADDER_TRADE_SIZES_USD {
+        let buy_price_impact_percentage =
+            match to_units(trade_size_usd / base_token_price, base_token_decimals) {
+                Some(buy_in) => quote(base_token_address, token.address, buy_in)
+                    .await
+                    .ok()
+                    .map(|buy_out| {
+                        price_impact(reference_buy_in, reference_buy_out, buy_in, buy_out)
+                    }),
+                None => None,
+            };
+
+        let sell_price_impact_percentage =
+            match to_units(trade_size_usd * tokens_per_usd, token.decimals) {
+                Some(sell_in) => quote(token.address, base_token_address, sell_in)
+                    .await
+                    .ok()
+                    .map(|sell_out| {
+                        price_impact(reference_sell_in, reference_sell_out, sell_in, sell_out)
+                    }),
+                None => None,
+            };
+
+        levels.push(DepthLevel {
+            trade_size_usd,
+            buy_price_impact_percentage,
+            sell_price_impact_percentage,
+        });
+    }
+
+    Ok(TokenDepthCheck::from_levels(levels))
+}
+
+/// Computes a quoted trade's price impact from raw token amounts.
+fn price_impact(
+    reference_amount_in: U256,
+    reference_amount_out: U256,
+    amount_in: U256,
+    amount_out: U256,
+) -> f64 {
+    price_impact_percentage(
+        u256_to_f64(reference_amount_in).unwrap_or_default(),
+        u256_to_f64(reference_amount_out).unwrap_or_default(),
+        u256_to_f64(amount_in).unwrap_or_default(),
+        u256_to_f64(amount_out).unwrap_or_default(),
+    )
+}
+
+/// Returns the decimals of a base token, native ETH (the zero address) having 18.
+async fn get_base_token_decimals(
+    base_token_address: Address,
+    client: &Arc<Provider<Ws>>,
+) -> Result<u8> {
+    if base_token_address.is_zero() {
+        return Ok(18);
+    }
+    Ok(ERC20::new(base_token_address, client.clone())
+        .decimals()
+        .call()
+        .await?)
+}
+
+/// Converts an amount of tokens to its raw units, `None` if the amount is not a finite, non-negative
+/// number that fits in a `U256`.
+fn to_units(amount: f64, decimals: u8) -> Option<U256> {
+    if !amount.is_finite() || amount < 0.0 {
+        return None;
+    }
+    parse_units(
+        format!("{:.1$}", amount, decimals as usize),
+        decimals as u32,
+    )
+    .ok()
+    .map(Into::into)
+}
+
+/// Converts raw units of a token to an amount of tokens, losing precision beyond `f64`.
+fn units_to_f64(units: U256, decimals: u8) -> f64 {
+    u256_to_f64(units).unwrap_or(f64::MAX) / 10_f64.powi(decimals as i32)
+}
@@ -5,14 +5,20 @@
 //! the percentage of tokens burned or locked, and determines whether the top holder
 //! holds more than a defined threshold percentage of the total token supply.
 //!
+//! Beyond the single top holder, it measures how the circulating supply is distributed: the share of
+//! the top 10 and top 50 holders, the Gini coefficient and Herfindahl-Hirschman index of the
+//! balances, the number of holders, and the share held by contracts versus externally owned accounts.
+//!
 //! The functions here use asynchronous calls to fetch data and perform necessary computations.
 
 use anyhow::{anyhow, Result};
 use ethers::prelude::*;
+use futures::future::join_all;
 use std::sync::Arc;
 
 use crate::{
-    app_config::{TOKEN_HOLDER_THRESHOLD_PERCENTAGE, TOKEN_LOCKERS_BASE, TOKEN_LOCKERS_MAINNET},
+    app_config::{HOLDER_DISTRIBUTION_TOP_HOLDERS, TOKEN_HOLDER_THRESHOLD_PERCENTAGE},
+    data::address_labels::ADDRESS_LABELS,
     utils::type_conversion::{address_to_string, u256_to_f64},
 };
 
@@ -23,9 +29,11 @@ use crate::token_check::{check_token_lock::TokenHolders, external_api::moralis};
 ///
 /// # Fields
 ///
-/// * `top_holder_percentage` - The percentage of the total token supply owned by the largest (non-locked and non-burned) holder.
+/// * `top_holder_percentage` - The percentage of the total token supply owned by the largest holder that is not a burn address,
+///   locker, exchange wallet, bridge or router.
 /// * `percentage_tokens_burned_or_locked` - The percentage of the total token supply that is either burned or held in locked addresses.
 /// * `top_holder_more_than_10_percent_of_tokens` - A boolean indicator whether the top holder owns more than the threshold of tokens.
+/// * `distribution` - How the circulating supply is distributed across holders.
 #[derive(Debug, Default)]
 pub struct TokenHolderCheck {
     // pub creator_holder_percentage: f64, // Uncomment if implementation for creator holdings is added
@@ -33,6 +41,124 @@ pub struct TokenHolderCheck {
     pub percentage_tokens_burned_or_locked: f64,
     // pub creator_owns_more_than_10_percent_of_tokens: bool, // Uncomment if implementation for creator holdings is added
     pub top_holder_more_than_10_percent_of_tokens: bool,
+    pub distribution: HolderDistribution,
+}
+
+/// The balance of a circulating holder, i.e. not a burn address, locker, exchange wallet, bridge,
+/// router or one of the token's pools.
+///
+/// # Fields
+///
+/// * `address` - The holder.
+/// * `quantity` - The holder's balance.
+/// * `is_contract` - Whether the holder is a contract rather than an externally owned account, `None`
+///   if its code could not be retrieved.
+#[derive(Clone, Debug, Default)]
+pub struct HolderBalance {
+    pub address: Address,
+    pub quantity: U256,
+    pub is_contract: Option<bool>,
+}
+
+/// How the token's circulating supply is distributed across its holders.
+///
+/// The metrics are computed from the largest holders returned by the holder API (up to
+/// [`HOLDER_DISTRIBUTION_TOP_HOLDERS`]), leaving out burn addresses, lockers, exchange wallets,
+/// bridges, routers and the token's pools. Percentages are of the total supply.
+///
+/// # Fields
+///
+/// * `holder_count` - The number of addresses holding the token, `None` if it could not be retrieved.
+/// * `top_10_percentage` - The percentage of the supply held by the 10 largest holders.
+/// * `top_50_percentage` - The percentage of the supply held by the 50 largest holders.
+/// * `gini_coefficient` - How unequal the balances of the listed holders are, from 0.0 (all equal) to
+///   1.0 (one holder has everything).
+/// * `herfindahl_index` - The sum of the squared percentages of the supply held by each holder, from
+///   near 0 (dispersed) to 10,000 (a single holder has the whole supply). Above 2,500 is highly
+///   concentrated.
+/// * `contracts_percentage` - The percentage of the supply held by contracts.
+/// * `eoas_percentage` - The percentage of the supply held by externally owned accounts. Holders
+///   whose code could not be retrieved count in neither percentage.
+/// * `deployer_and_owner_percentage` - The percentage of the supply held by the token's deployer and
+///   owner together (counted once if they are the same wallet).
+#[derive(Clone, Debug, Default)]
+pub struct HolderDistribution {
+    pub holder_count: Option<u64>,
+    pub top_10_percentage: f64,
+    pub top_50_percentage: f64,
+    pub gini_coefficient: f64,
+    pub herfindahl_index: f64,
+    pub contracts_percentage: f64,
+    pub eoas_percentage: f64,
+    pub deployer_and_owner_percentage: f64,
+}
+
+impl HolderDistribution {
+    /// Computes the distribution metrics from the balances of the circulating holders, without the
+    /// holder count and the deployer and owner holdings.
+    ///
+    /// # Arguments
+    ///
+    /// * `balances` - The balances of the circulating holders, in any order.
+    /// * `total_supply` - The token's total supply.
+    pub fn from_balances(mut balances: Vec<HolderBalance>, total_supply: U256) -> Self {
+        if total_supply.is_zero() {
+            return Self::default();
+        }
+        balances.sort_by_key(|balance| std::cmp::Reverse(balance.quantity));
+
+        let percentage = |quantity: U256| -> f64 {
+            100_f64 * u256_div_u256_to_f64(quantity, total_supply).unwrap_or_default()
+        };
+        let percentages: Vec<f64> = balances
+            .iter()
+            .map(|balance| percentage(balance.quantity))
+            .collect();
+
+        let top_10_percentage = percentages.iter().take(10).sum();
+        let top_50_percentage = percentages.iter().take(50).sum();
+        let herfindahl_index = percentages.iter().map(|share| share.powi(2)).sum();
+        let contracts_percentage = balances
+            .iter()
+            .filter(|balance| balance.is_contract == Some(true))
+            .map(|balance| percentage(balance.quantity))
+            .sum();
+        let eoas_percentage = balances
+            .iter()
+            .filter(|balance| balance.is_contract == Some(false))
+            .map(|balance| percentage(balance.quantity))
+            .sum();
+
+        Self {
+            top_10_percentage,
+            top_50_percentage,
+            gini_coefficient: gini_coefficient(&percentages),
+            herfindahl_index,
+            contracts_percentage,
+            eoas_percentage,
+            ..Default::default()
+        }
+    }
+}
+
+/// Computes the Gini coefficient of a set of balances: 0.0 when all are equal, approaching 1.0 when a
+/// single balance holds everything.
+fn gini_coefficient(balances: &[f64]) -> f64 {
+    let total: f64 = balances.iter().sum();
+    if balances.is_empty() || total <= 0.0 {
+        return 0.0;
+    }
+
+    let mut sorted = balances.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let count = sorted.len() as f64;
+    let weighted_sum: f64 = sorted
+        .iter()
+        .enumerate()
+        .map(|(index, balance)| (index as f64 + 1.0) * balance)
+        .sum();
+
+    (2.0 * weighted_sum / (count * total) - (count + 1.0) / count).max(0.0)
 }
 
 /// Retrieves and computes token holder metrics for a given token.
@@ -41,14 +167,21 @@ pub struct TokenHolderCheck {
 ///
 /// 1. Retrieves the total token supply using the ERC20 token interface.
 /// 2. Fetches token holder data using the external Moralis API.
-/// 3. Sums up balances for tokens in "locked" addresses (depending on the current chain).
-/// 4. Identifies the top token holder among non-locked tokens.
+/// 3. Sums up balances for tokens held by burn addresses and lockers of the token's chain, from the
+///    address label registry.
+/// 4. Identifies the top token holder among the other holders, leaving out exchange wallets, bridges
+///    and routers, which hold tokens on behalf of many users.
 /// 5. Computes the percentage of tokens held by the top holder and the percentage that is burned or locked.
 /// 6. Determines if the top holder's balance exceeds a defined threshold.
+/// 7. Computes the distribution of the circulating supply, leaving out the token's pools, checking
+///    concurrently which holders are contracts and retrieving the number of holders. The deployer
+///    and owner holdings are filled in by the checklist, once the deployer and owner are known.
 ///
 /// # Arguments
 ///
 /// * `token` - A reference to the ERC20 token data.
+/// * `pool_addresses` - The token's pools on every DEX, whose tokens are liquidity rather than a
+///   holder's position.
 /// * `client` - A shared reference (Arc) to the WebSocket provider used for asynchronous operations.
 ///
 /// # Returns
@@ -59,6 +192,7 @@ pub struct TokenHolderCheck {
 pub async fn get_token_holder_check(
     token: &ERC20Token,
     // creator_address: &str,
+    pool_addresses: &[Address],
     client: &Arc<Provider<Ws>>,
 ) -> Result<Option<TokenHolderCheck>> {
     // Step 1: Get the total token supply.
@@ -79,33 +213,35 @@ pub async fn get_token_holder_check(
 
     // Sum up balances for locked or burned tokens.
     let mut burnt_or_locked_balance = U256::zero();
+    let mut circulating_holders: Vec<&TokenHolders> = Vec::new();
 
     for info in top_holders.iter() {
-        // Determine if the address is a known locked address.
-        let mut is_burned_or_locked = false;
-
-        if token.chain == Chain::Mainnet {
-            // On Mainnet, check against the main network's locked addresses.
-            if TOKEN_LOCKERS_MAINNET.contains(&info.holder.as_str()) {
-                burnt_or_locked_balance += info.quantity;
-                is_burned_or_locked = true;
-            }
-        } else {
-            // For other chains, check against the base network's locked addresses.
-            if TOKEN_LOCKERS_BASE.contains(&info.holder.as_str()) {
-                burnt_or_locked_balance += info.quantity;
-                is_burned_or_locked = true;
-            }
+        // Sum the balances of burn addresses and lockers.
+        if ADDRESS_LABELS.is_burned_or_locked(&token.chain, &info.holder) {
+            burnt_or_locked_balance += info.quantity;
+            continue;
         }
 
-        // Only consider non-locked addresses for the top holder.
-        if !is_burned_or_locked {
-            if top_holder.quantity < info.quantity {
-                top_holder = TokenHolders {
-                    holder: info.holder.clone(), // Using clone here since String implements Clone.
-                    quantity: info.quantity,
-                };
-            }
+        // Exchange wallets, bridges and routers hold tokens for many users, so they are not a
+        // single whale: only consider the other holders for the top holder.
+        if ADDRESS_LABELS.holds_for_others(&token.chain, &info.holder) {
+            continue;
+        }
+
+        // The pools' tokens are the liquidity, not a holder's position.
+        let is_pool = info
+            .holder
+            .parse::<Address>()
+            .is_ok_and(|holder| pool_addresses.contains(&holder));
+        if !is_pool {
+            circulating_holders.push(info);
+        }
+
+        if top_holder.quantity < info.quantity {
+            top_holder = TokenHolders {
+                holder: info.holder.clone(), // Using clone here since String implements Clone.
+                quantity: info.quantity,
+            };
         }
     }
 
@@ -123,6 +259,43 @@ pub async fn get_token_holder_check(
     let max_token_threshold =
         total_supply * U256::from(TOKEN_HOLDER_THRESHOLD_PERCENTAGE as u64) / U256::from(100_u64);
 
+    // Step 7: Compute the distribution of the circulating supply.
+    // A holder whose code cannot be retrieved is counted as neither a contract nor an EOA.
+    let balances = join_all(
+        circulating_holders
+            .into_iter()
+            .take(HOLDER_DISTRIBUTION_TOP_HOLDERS)
+            .filter_map(|info| Some((info.holder.parse::<Address>().ok()?, info.quantity)))
+            .map(|(address, quantity)| async move {
+                let is_contract = match client.get_code(address, None).await {
+                    Ok(code) => Some(!code.is_empty()),
+                    Err(e) => {
+                        println!(
+                            "could not retrieve code of holder {}: {}",
+                            address_to_string(address),
+                            e
+                        );
+                        None
+                    }
+                };
+                HolderBalance {
+                    address,
+                    quantity,
+                    is_contract,
+                }
+            }),
+    )
+    .await;
+    let mut distribution = HolderDistribution::from_balances(balances, total_supply);
+    distribution.holder_count =
+        match moralis::get_token_holder_count(&token_address, &token.chain).await {
+            Ok(holder_count) => Some(holder_count),
+            Err(e) => {
+                println!("could not retrieve holder count: {}", e);
+                None
+            }
+        };
+
     // Compute the check data using precise conversion from U256 divisions.
     let token_holder_check = TokenHolderCheck {
         // Uncomment and adjust if using creator holdings in the future:
@@ -131,6 +304,7 @@ pub async fn get_token_holder_check(
         percentage_tokens_burned_or_locked: 100_f64
             * u256_div_u256_to_f64(burnt_or_locked_balance, total_supply)?,
         top_holder_more_than_10_percent_of_tokens: top_holder.quantity > max_token_threshold,
+        distribution,
     };
 
     println!(
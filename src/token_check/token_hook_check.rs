@@ -0,0 +1,207 @@
+//! This module analyses the hook contract of a token's Uniswap V4 pool.
+//!
+//! A V4 pool can name a hook contract the pool manager calls around the pool's swaps and liquidity
+//! changes. The hook can revert swaps, take part of every trade or block withdrawals, which makes it
+//! a honeypot vector that reviewing the token's own code does not catch. Which callbacks a hook runs
+//! is fixed by the lowest 14 bits of its address, so the permissions are decoded from the address,
+//! and the hook's verified source code is reviewed by the AI model.
+
+use anyhow::Result;
+use ethers::types::Address;
+
+use crate::{
+    app_config::AI_MODEL,
+    data::{dex::Dex, token_data::ERC20Token},
+    dex::onchain::uniswap_v4::UNISWAP_V4_DYNAMIC_FEE_FLAG,
+    token_check::{
+        ai::ai_submission::check_hook_code_with_ai,
+        external_api::etherscan_api::get_contract_source,
+    },
+    utils::type_conversion::address_to_string,
+};
+
+/// Hook address bit allowing `beforeInitialize`.
+pub const BEFORE_INITIALIZE_FLAG: u16 = 1 << 13;
+/// Hook address bit allowing `afterInitialize`.
+pub const AFTER_INITIALIZE_FLAG: u16 = 1 << 12;
+/// Hook address bit allowing `beforeAddLiquidity`.
+pub const BEFORE_ADD_LIQUIDITY_FLAG: u16 = 1 << 11;
+/// Hook address bit allowing `afterAddLiquidity`.
+pub const AFTER_ADD_LIQUIDITY_FLAG: u16 = 1 << 10;
+/// Hook address bit allowing `beforeRemoveLiquidity`.
+pub const BEFORE_REMOVE_LIQUIDITY_FLAG: u16 = 1 << 9;
+/// Hook address bit allowing `afterRemoveLiquidity`.
+pub const AFTER_REMOVE_LIQUIDITY_FLAG: u16 = 1 << 8;
+/// Hook address bit allowing `beforeSwap`.
+pub const BEFORE_SWAP_FLAG: u16 = 1 << 7;
+/// Hook address bit allowing `afterSwap`.
+pub const AFTER_SWAP_FLAG: u16 = 1 << 6;
+/// Hook address bit allowing `beforeDonate`.
+pub const BEFORE_DONATE_FLAG: u16 = 1 << 5;
+/// Hook address bit allowing `afterDonate`.
+pub const AFTER_DONATE_FLAG: u16 = 1 << 4;
+/// Hook address bit allowing `beforeSwap` to return a delta, changing the amounts swapped.
+pub const BEFORE_SWAP_RETURNS_DELTA_FLAG: u16 = 1 << 3;
+/// Hook address bit allowing `afterSwap` to return a delta, changing the amount received.
+pub const AFTER_SWAP_RETURNS_DELTA_FLAG: u16 = 1 << 2;
+/// Hook address bit allowing `afterAddLiquidity` to return a delta.
+pub const AFTER_ADD_LIQUIDITY_RETURNS_DELTA_FLAG: u16 = 1 << 1;
+/// Hook address bit allowing `afterRemoveLiquidity` to return a delta.
+pub const AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA_FLAG: u16 = 1;
+
+/// The hook permissions, with the callback each one enables.
+const HOOK_PERMISSIONS: [(u16, &str); 14] = [
+    (BEFORE_INITIALIZE_FLAG, "beforeInitialize"),
+    (AFTER_INITIALIZE_FLAG, "afterInitialize"),
+    (BEFORE_ADD_LIQUIDITY_FLAG, "beforeAddLiquidity"),
+    (AFTER_ADD_LIQUIDITY_FLAG, "afterAddLiquidity"),
+    (BEFORE_REMOVE_LIQUIDITY_FLAG, "beforeRemoveLiquidity"),
+    (AFTER_REMOVE_LIQUIDITY_FLAG, "afterRemoveLiquidity"),
+    (BEFORE_SWAP_FLAG, "beforeSwap"),
+    (AFTER_SWAP_FLAG, "afterSwap"),
+    (BEFORE_DONATE_FLAG, "beforeDonate"),
+    (AFTER_DONATE_FLAG, "afterDonate"),
+    (BEFORE_SWAP_RETURNS_DELTA_FLAG, "beforeSwapReturnsDelta"),
+    (AFTER_SWAP_RETURNS_DELTA_FLAG, "afterSwapReturnsDelta"),
+    (
+        AFTER_ADD_LIQUIDITY_RETURNS_DELTA_FLAG,
+        "afterAddLiquidityReturnsDelta",
+    ),
+    (
+        AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA_FLAG,
+        "afterRemoveLiquidityReturnsDelta",
+    ),
+];
+
+/// The permission bits of a hook, the lowest 14 bits of its address.
+pub fn hook_permission_bits(hook_address: Address) -> u16 {
+    let bytes = hook_address.as_bytes();
+    u16::from_be_bytes([bytes[18], bytes[19]]) & 0x3fff
+}
+
+/// Returns the callbacks a hook address enables, in the order the pool manager defines them.
+///
+/// # Arguments
+///
+/// * `hook_address` - The hook contract of a V4 pool.
+///
+/// # Returns
+///
+/// * `Vec<String>` - The enabled callbacks, e.g. `beforeSwap`, empty for the zero address.
+pub fn enabled_hook_permissions(hook_address: Address) -> Vec<String> {
+    let bits = hook_permission_bits(hook_address);
+    HOOK_PERMISSIONS
+        .iter()
+        .filter(|(flag, _)| bits & flag != 0)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// Holds the results of the hook check.
+///
+/// # Fields
+///
+/// * `hook_address` - The hook contract of the token's V4 pool.
+/// * `enabled_permissions` - The callbacks the hook runs (`beforeSwap`, `afterSwapReturnsDelta`...).
+/// * `can_block_swaps` - Whether the hook runs before or after swaps, so it can revert them (e.g. only
+///   sells).
+/// * `can_take_swap_deltas` - Whether the hook can change the amounts of a swap, taking part of it.
+/// * `has_dynamic_fee` - Whether the hook sets the pool's LP fee, which it can raise at any time.
+/// * `can_block_liquidity_removal` - Whether the hook runs when liquidity is removed.
+/// * `is_verified` - Whether the hook's source code is verified, so it could be reviewed.
+/// * `possible_malicious_hook` - The AI review of the hook's code, `None` if it could not be reviewed.
+/// * `reason` - The reason given by the review.
+#[derive(Clone, Debug, Default)]
+pub struct TokenHookCheck {
+    pub hook_address: Address,
+    pub enabled_permissions: Vec<String>,
+    pub can_block_swaps: bool,
+    pub can_take_swap_deltas: bool,
+    pub has_dynamic_fee: bool,
+    pub can_block_liquidity_removal: bool,
+    pub is_verified: bool,
+    pub possible_malicious_hook: Option<bool>,
+    pub reason: Option<String>,
+}
+
+impl TokenHookCheck {
+    /// Builds the hook check from the hook address and the pool fee, without the source review.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook_address` - The hook contract of the pool.
+    /// * `pool_fee` - The fee of the pool key, flagged when the fee is dynamic.
+    pub fn from_pool(hook_address: Address, pool_fee: u32) -> Self {
+        let bits = hook_permission_bits(hook_address);
+        Self {
+            hook_address,
+            enabled_permissions: enabled_hook_permissions(hook_address),
+            can_block_swaps: bits & (BEFORE_SWAP_FLAG | AFTER_SWAP_FLAG) != 0,
+            can_take_swap_deltas: bits
+                & (BEFORE_SWAP_RETURNS_DELTA_FLAG | AFTER_SWAP_RETURNS_DELTA_FLAG)
+                != 0,
+            has_dynamic_fee: pool_fee == UNISWAP_V4_DYNAMIC_FEE_FLAG,
+            can_block_liquidity_removal: bits
+                & (BEFORE_REMOVE_LIQUIDITY_FLAG | AFTER_REMOVE_LIQUIDITY_FLAG)
+                != 0,
+            ..Default::default()
+        }
+    }
+
+    /// Returns `true` if the review of the hook's code found it malicious.
+    pub fn is_malicious(&self) -> bool {
+        self.possible_malicious_hook == Some(true)
+    }
+
+    /// Returns `true` if the hook can revert swaps or take part of them, whatever its code does today.
+    pub fn can_trap_traders(&self) -> bool {
+        self.can_block_swaps || self.can_take_swap_deltas
+    }
+}
+
+/// Analyses the hook of the token's Uniswap V4 pool.
+///
+/// This asynchronous function performs the following steps:
+///
+/// 1. Decodes the callbacks the hook runs from its address, and whether the pool fee is dynamic.
+/// 2. Retrieves the hook's verified source code.
+/// 3. Reviews the source code with the AI model, if it is verified.
+///
+/// # Arguments
+///
+/// * `token` - A reference to the ERC20 token data.
+///
+/// # Returns
+///
+/// * `Ok(Some(TokenHookCheck))` describing the hook.
+/// * `Ok(None)` if the token's pool is not a Uniswap V4 pool, or has no hook.
+/// * `Err` if retrieving the source code or the review fails.
+pub async fn get_token_hook_check(token: &ERC20Token) -> Result<Option<TokenHookCheck>> {
+    let token_dex = match &token.token_dex {
+        Some(token_dex) if matches!(token_dex.dex, Dex::UniswapV4) => token_dex,
+        _ => return Ok(None),
+    };
+    if token_dex.hooks.is_zero() {
+        return Ok(None);
+    }
+
+    // Step 1: The hook's permissions are part of its address.
+    let mut hook_check = TokenHookCheck::from_pool(token_dex.hooks, token_dex.fee);
+
+    // Step 2: Retrieve the hook's source code.
+    let hook_source =
+        get_contract_source(&address_to_string(token_dex.hooks), &token.chain).await?;
+    hook_check.is_verified = hook_source.is_verified();
+
+    // Step 3: Review the hook's code.
+    if hook_check.is_verified {
+        if let Some(code_check) =
+            check_hook_code_with_ai(hook_source.combined_source(), &AI_MODEL).await?
+        {
+            hook_check.possible_malicious_hook = Some(code_check.possible_malicious_hook);
+            hook_check.reason = Some(code_check.reason);
+        }
+    }
+
+    Ok(Some(hook_check))
+}
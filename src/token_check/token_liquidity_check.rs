@@ -1,39 +1,60 @@
 //! This module provides functionality to check the percentage of an ERC20 token's liquidity
 //! that is either locked or burned. It does this by fetching the total supply from the token,
 //! retrieving token holders from external APIs (either Moralis or Uniswap), and aggregating the
-//! locked balances based on a set of predefined "locker" addresses.
+//! locked balances of the burn addresses and lockers of the address label registry.
 //!
 //! The main function exposed by this module is `get_percentage_liquidity_locked_or_burned`.
+//! `get_liquidity_lock` additionally returns the largest holders whose liquidity is not locked, which
+//! are the wallets that could pull liquidity in a rug pull.
 
 use anyhow::Result;
 use ethers::prelude::*;
 use std::sync::Arc;
 
 use crate::{
-    app_config::{TOKEN_LOCKERS_BASE, TOKEN_LOCKERS_MAINNET},
+    app_config::RUG_PULL_MAX_LP_HOLDERS,
+    data::address_labels::ADDRESS_LABELS,
     token_check::{
-        check_token_lock::TokenHolders,
-        external_api::{moralis, thegraph::shared::fetch_lp_holders},
+        check_token_lock::TokenHolders, external_api::thegraph::shared::fetch_lp_holders,
         token_holder_check::u256_div_u256_to_f64,
     },
-    utils::type_conversion::address_to_string,
 };
 
 use crate::data::token_data::ERC20Token;
 
+/// How much of a token's liquidity is locked or burned, and who holds the rest.
+///
+/// # Fields
+///
+/// * `percentage_locked_or_burned` - The percentage of liquidity locked or burned, `None` if it could
+///   not be computed.
+/// * `unlocked_lp_holders` - The largest holders of liquidity that is not locked or burned, largest
+///   first, at most [`RUG_PULL_MAX_LP_HOLDERS`].
+#[derive(Clone, Debug, Default)]
+pub struct LiquidityLock {
+    pub percentage_locked_or_burned: Option<f64>,
+    pub unlocked_lp_holders: Vec<Address>,
+}
+
 /// Calculates the percentage of an ERC20 token's liquidity that is either locked or burned.
 ///
 /// This function performs the following steps:
 ///
-/// 1. Retrieves the total supply of the liquidity token by querying the blockchain.
-/// 2. Fetches the list of token holders:
-///    - For `Chain::Base`, the list is fetched via the Moralis API using the pair address.
-///    - Otherwise, the list is fetched from Uniswap's Graph API.
+/// 1. Retrieves the total supply of the liquidity token by querying the blockchain. For Uniswap V3
+///    and Aerodrome Slipstream pools this is the pool's active liquidity.
+/// 2. Fetches the list of token holders from the pool's swap venue:
+///    - For Uniswap V2 on `Chain::Base`, the list is fetched via the Moralis API using the pair address.
+///    - For Uniswap V2 on mainnet, the list is fetched from Uniswap's Graph API.
+///    - For Uniswap V3 and Aerodrome Slipstream, the holders are the owners of the position NFTs in
+///      range at the pool's current tick, read on-chain, with each position's liquidity.
+///    - Pools whose positions are not indexed (Uniswap V4) have no holders.
 /// 3. Iterates through each token holder to:
 ///    - Identify the top token holder based on balance (for informational purposes).
-///    - Sum the locked balances from known locker addresses (predefined in
-///      `TOKEN_LOCKERS_MAINNET` or `TOKEN_LOCKERS_BASE`).
-/// 4. Computes the percentage of liquidity locked relative to total supply.
+///    - Sum the locked balances of the burn addresses and lockers of the token's chain (from the
+///      address label registry, `address_labels.toml`), lockers of Uniswap V3 position NFTs
+///      included.
+/// 4. Computes the percentage of liquidity locked relative to total supply, which for Uniswap V3
+///    pools is the share of the in-range liquidity locked or burned.
 ///
 /// # Parameters
 ///
@@ -67,39 +88,57 @@ use crate::data::token_data::ERC20Token;
 /// # Notes
 ///
 /// - The function adjusts its behavior depending on the network configuration:
-///   for `Chain::Base`, it uses Moralis API; for other chains, it uses Uniswap's data.
-/// - Locked addresses are determined using the constants `TOKEN_LOCKERS_MAINNET` or `TOKEN_LOCKERS_BASE`,
-///   which include addresses associated with token locking or burning (for example, burn addresses).
+///   the holders come from the pool's swap venue: Moralis for `Chain::Base`, Uniswap's data for
+///   mainnet.
+/// - Locked addresses are the addresses labelled as burn addresses or lockers for the token's chain in
+///   the address label registry (`address_labels.toml`).
 ///
 pub async fn get_percentage_liquidity_locked_or_burned(
     token: &ERC20Token,
     client: &Arc<Provider<Ws>>,
 ) -> Result<Option<f64>> {
+    let liquidity_lock = get_liquidity_lock(token, client).await?;
+    Ok(liquidity_lock.percentage_locked_or_burned)
+}
+
+/// Calculates the percentage of liquidity locked or burned, as described in
+/// [`get_percentage_liquidity_locked_or_burned`], and collects the largest holders of the remaining,
+/// unlocked liquidity.
+///
+/// # Parameters
+///
+/// - `token`: A reference to an `ERC20Token` holding the token's properties and metadata.
+/// - `client`: An `Arc`-wrapped `Provider<Ws>` used for blockchain interaction.
+///
+/// # Returns
+///
+/// - `Ok(LiquidityLock)` with the percentage locked (`None` if no token holders are found or the top
+///   holder's balance is zero) and the unlocked LP holders.
+/// - An error of type `anyhow::Error` if any underlying call fails (e.g., network or API errors).
+pub async fn get_liquidity_lock(
+    token: &ERC20Token,
+    client: &Arc<Provider<Ws>>,
+) -> Result<LiquidityLock> {
     // Retrieve total supply for the liquidity token.
     let total_supply = token.get_total_liquidity_token_supply(client).await?;
 
-    // Retrieve the list of token holders based on the chain configuration.
-    let top_holders: Vec<TokenHolders> = if token.chain == Chain::Mainnet {
-        // For Chain::Mainnet, fetch token holders using Uniswap's Graph API.
-        fetch_lp_holders(token).await?
-    } else {
-        let token_dex = token
-            .clone()
-            .token_dex
-            .expect("is_liquidity_locked: no token dex found");
-        // For L2s, convert the pair address and use the Moralis API.
-        let pair_address = address_to_string(token_dex.pair_address);
-        moralis::get_token_holder_list(&pair_address, &token.chain).await?
-    };
+    // A drained pool (or one without LP tokens) has no liquidity to lock.
+    if total_supply.is_zero() {
+        return Ok(LiquidityLock::default());
+    }
+
+    // Retrieve the list of token holders from the pool's swap venue, which picks the API for the chain.
+    let top_holders: Vec<TokenHolders> = fetch_lp_holders(token).await?;
 
     // Return None if no token holders are found.
     if top_holders.is_empty() {
-        return Ok(None);
+        return Ok(LiquidityLock::default());
     }
 
     // Initialize locked balance counter and prepare to find the top holder.
     let mut locked_balance = U256::zero();
     let mut top_holder = TokenHolders::default();
+    let mut unlocked_holders: Vec<&TokenHolders> = Vec::new();
 
     // Process each token holder:
     // - Update the top holder if a higher balance is found.
@@ -115,20 +154,33 @@ pub async fn get_percentage_liquidity_locked_or_burned(
 
         // Depending on the chain, add to the locked balance if the holder is in the known list.
         // TODO - UPDATE plus update liquidity lockers
-        if token.chain == Chain::Mainnet {
-            if TOKEN_LOCKERS_MAINNET.contains(&info.holder.as_str()) {
-                locked_balance += info.quantity;
-            }
+        let is_locker = ADDRESS_LABELS.is_burned_or_locked(&token.chain, &info.holder);
+        if is_locker {
+            locked_balance += info.quantity;
         } else {
-            if TOKEN_LOCKERS_BASE.contains(&info.holder.as_str()) {
-                locked_balance += info.quantity;
-            }
+            unlocked_holders.push(info);
         }
     }
 
     // If the top holder's balance is zero, meaningful computation cannot continue.
     if top_holder.quantity == U256::zero() {
-        return Ok(None);
+        return Ok(LiquidityLock::default());
+    }
+
+    // Keep the largest unlocked holders. A holder can appear more than once (one entry per V3 position).
+    unlocked_holders.sort_by_key(|holder| std::cmp::Reverse(holder.quantity));
+    let mut unlocked_lp_holders: Vec<Address> = Vec::new();
+    for info in unlocked_holders {
+        let holder: Address = match info.holder.parse() {
+            Ok(holder) => holder,
+            Err(_) => continue,
+        };
+        if !unlocked_lp_holders.contains(&holder) {
+            unlocked_lp_holders.push(holder);
+        }
+        if unlocked_lp_holders.len() == RUG_PULL_MAX_LP_HOLDERS {
+            break;
+        }
     }
 
     // Log the top holder's details for debugging or audit purposes.
@@ -141,5 +193,8 @@ pub async fn get_percentage_liquidity_locked_or_burned(
     let percentage_of_liquidity_locked =
         100_f64 * u256_div_u256_to_f64(locked_balance, total_supply)?;
 
-    Ok(Some(percentage_of_liquidity_locked))
+    Ok(LiquidityLock {
+        percentage_locked_or_burned: Some(percentage_of_liquidity_locked),
+        unlocked_lp_holders,
+    })
 }
@@ -0,0 +1,279 @@
+//! This module decodes the LP token locks of the token's pool from the locker contracts, with when
+//! each lock expires.
+//!
+//! Finding the pool's LP tokens in a locker only tells that the liquidity is locked, not until when:
+//! a lock expiring tomorrow would count the same as a two-year lock. The supported lockers (UNCX,
+//! Team Finance, PinkLock) are queried for each lock of the pool's LP token, its remaining amount and
+//! its unlock date, and the share of the LP supply still locked at each of
+//! [`LOCK_EXPIRY_HORIZONS_DAYS`] is reported with the earliest unlock date.
+//!
+//! Only pools with an ERC20 LP token (Uniswap V2, Aerodrome volatile / stable pools) are locked
+//! this way: concentrated liquidity positions are NFTs, locked in other contracts.
+
+use anyhow::Result;
+use ethers::prelude::*;
+use std::sync::Arc;
+
+use crate::{
+    abi::{pinklock::PINKLOCK, team_finance_locker::TEAM_FINANCE_LOCKER, uncx_locker::UNCX_LOCKER},
+    app_config::LOCK_EXPIRY_HORIZONS_DAYS,
+    data::{chain_data::CHAIN_DATA, token_data::ERC20Token},
+    dex::swap_venue::swap_venue,
+    token_check::token_holder_check::u256_div_u256_to_f64,
+};
+
+/// A lock of LP tokens in a locker contract.
+///
+/// # Fields
+///
+/// * `locker` - The locker holding the LP tokens (e.g. "UNCX").
+/// * `amount` - The LP tokens still locked, in raw units.
+/// * `unlock_timestamp` - When the LP tokens can be withdrawn, as a unix timestamp.
+#[derive(Clone, Debug, Default)]
+pub struct LpLock {
+    pub locker: String,
+    pub amount: U256,
+    pub unlock_timestamp: u64,
+}
+
+/// The share of the LP supply still locked at a horizon.
+///
+/// # Fields
+///
+/// * `horizon_days` - Days from now.
+/// * `percentage_locked` - The percentage of the LP supply in locks that have not expired by then
+///   (0.0 to 100.0).
+#[derive(Clone, Debug, Default)]
+pub struct LockHorizon {
+    pub horizon_days: f64,
+    pub percentage_locked: f64,
+}
+
+/// Holds the results of the lock expiry check.
+///
+/// # Fields
+///
+/// * `locks` - The decoded locks of the pool's LP token, earliest unlock first, including expired
+///   locks that were not withdrawn yet.
+/// * `percentage_locked` - The percentage of the LP supply in locks that have not expired (0.0 to
+///   100.0). Burned LP tokens are not included.
+/// * `percentage_locked_by_horizon` - The percentage still locked at each of
+///   [`LOCK_EXPIRY_HORIZONS_DAYS`].
+/// * `earliest_unlock_timestamp` - The unlock date of the lock expiring first, as a unix timestamp,
+///   `None` if there are no locks. It is in the past if an expired lock was not withdrawn yet.
+/// * `days_until_earliest_unlock` - Days until the earliest unlock, 0.0 if it already passed.
+#[derive(Clone, Debug, Default)]
+pub struct TokenLockExpiryCheck {
+    pub locks: Vec<LpLock>,
+    pub percentage_locked: f64,
+    pub percentage_locked_by_horizon: Vec<LockHorizon>,
+    pub earliest_unlock_timestamp: Option<u64>,
+    pub days_until_earliest_unlock: Option<f64>,
+}
+
+impl TokenLockExpiryCheck {
+    /// Computes the locked percentages and the earliest unlock from the decoded locks.
+    ///
+    /// # Arguments
+    ///
+    /// * `locks` - The locks of the pool's LP token, in any order.
+    /// * `lp_supply` - The LP token's total supply.
+    /// * `now` - The current unix timestamp.
+    pub fn from_locks(mut locks: Vec<LpLock>, lp_supply: U256, now: u64) -> Self {
+        locks.retain(|lock| !lock.amount.is_zero());
+        locks.sort_by_key(|lock| lock.unlock_timestamp);
+
+        let percentage_locked_at = |timestamp: u64| -> f64 {
+            let locked = locks
+                .iter()
+                .filter(|lock| lock.unlock_timestamp > timestamp)
+                .fold(U256::zero(), |total, lock| {
+                    total.saturating_add(lock.amount)
+                });
+            if lp_supply.is_zero() {
+                return 0.0;
+            }
+            (100_f64 * u256_div_u256_to_f64(locked, lp_supply).unwrap_or_default()).min(100.0)
+        };
+
+        let percentage_locked = percentage_locked_at(now);
+        let percentage_locked_by_horizon = LOCK_EXPIRY_HORIZONS_DAYS
+            .iter()
+            .map(|&horizon_days| LockHorizon {
+                horizon_days,
+                percentage_locked: percentage_locked_at(
+                    now.saturating_add((horizon_days * 86_400_f64) as u64),
+                ),
+            })
+            .collect();
+
+        let earliest_unlock_timestamp = locks.first().map(|lock| lock.unlock_timestamp);
+        let days_until_earliest_unlock = earliest_unlock_timestamp
+            .map(|unlock_timestamp| unlock_timestamp.saturating_sub(now) as f64 / 86_400_f64);
+
+        Self {
+            locks,
+            percentage_locked,
+            percentage_locked_by_horizon,
+            earliest_unlock_timestamp,
+            days_until_earliest_unlock,
+        }
+    }
+}
+
+/// Decodes the LP token locks of the token's pool and when they expire.
+///
+/// This asynchronous function performs the following steps:
+///
+/// 1. Retrieves the total supply of the pool's LP token.
+/// 2. Queries each locker deployed on the token's chain for the locks of the LP token. A locker whose
+///    locks cannot be read is skipped.
+/// 3. Computes the share of the LP supply still locked now and at each horizon, from the latest
+///    block's timestamp.
+///
+/// # Arguments
+///
+/// * `token` - A reference to the ERC20 token data.
+/// * `client` - A shared reference (Arc) to the WebSocket provider used for asynchronous operations.
+///
+/// # Returns
+///
+/// * `Ok(TokenLockExpiryCheck)` with the pool's locks, without locks if the token is not on a DEX or
+///   its pool has no ERC20 LP token.
+/// * `Err` if the LP supply or the latest block cannot be retrieved.
+pub async fn get_token_lock_expiry_check(
+    token: &ERC20Token,
+    client: &Arc<Provider<Ws>>,
+) -> Result<TokenLockExpiryCheck> {
+    let lp_token = match &token.token_dex {
+        Some(token_dex)
+            if swap_venue(&token_dex.dex).is_some_and(|venue| venue.has_erc20_lp_token()) =>
+        {
+            token_dex.pair_address
+        }
+        _ => return Ok(TokenLockExpiryCheck::default()),
+    };
+    let lockers = match &CHAIN_DATA.get_address(&token.chain).lockers {
+        Some(lockers) => lockers,
+        None => return Ok(TokenLockExpiryCheck::default()),
+    };
+
+    // Step 1: Retrieve the LP supply.
+    let lp_supply = token.get_total_liquidity_token_supply(client).await?;
+
+    // Step 2: Decode the locks of each locker.
+    let mut locks = Vec::new();
+    if let Some(uncx) = &lockers.uncx {
+        match get_uncx_locks(uncx.parse()?, lp_token, client).await {
+            Ok(uncx_locks) => locks.extend(uncx_locks),
+            Err(e) => println!("could not read UNCX locks of {:?}: {}", lp_token, e),
+        }
+    }
+    if let Some(team_finance) = &lockers.team_finance {
+        match get_team_finance_locks(team_finance.parse()?, lp_token, client).await {
+            Ok(team_finance_locks) => locks.extend(team_finance_locks),
+            Err(e) => println!("could not read Team Finance locks of {:?}: {}", lp_token, e),
+        }
+    }
+    if let Some(pinklock) = &lockers.pinklock {
+        match get_pinklock_locks(pinklock.parse()?, lp_token, client).await {
+            Ok(pinklock_locks) => locks.extend(pinklock_locks),
+            Err(e) => println!("could not read PinkLock locks of {:?}: {}", lp_token, e),
+        }
+    }
+
+    // Step 3: Compute the locked percentages.
+    let latest_block = client
+        .get_block(BlockNumber::Latest)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Could not retrieve the latest block"))?;
+
+    Ok(TokenLockExpiryCheck::from_locks(
+        locks,
+        lp_supply,
+        latest_block.timestamp.as_u64(),
+    ))
+}
+
+/// Reads the locks of an LP token in the UNCX locker, which keeps an array of locks per LP token.
+async fn get_uncx_locks(
+    locker_address: Address,
+    lp_token: Address,
+    client: &Arc<Provider<Ws>>,
+) -> Result<Vec<LpLock>> {
+    let locker = UNCX_LOCKER::new(locker_address, client.clone());
+    let lock_count = locker.get_num_locks_for_token(lp_token).call().await?;
+
+    let mut locks = Vec::new();
+    for index in 0..lock_count.as_u64() {
+        let (_, amount, _, unlock_date, _, _) = locker
+            .token_locks(lp_token, U256::from(index))
+            .call()
+            .await?;
+        locks.push(LpLock {
+            locker: "UNCX".to_string(),
+            amount,
+            unlock_timestamp: unlock_date.low_u64(),
+        });
+    }
+
+    Ok(locks)
+}
+
+/// Reads the locks of an LP token in the Team Finance locker, skipping withdrawn deposits.
+async fn get_team_finance_locks(
+    locker_address: Address,
+    lp_token: Address,
+    client: &Arc<Provider<Ws>>,
+) -> Result<Vec<LpLock>> {
+    let locker = TEAM_FINANCE_LOCKER::new(locker_address, client.clone());
+    let deposit_ids = locker
+        .get_deposits_by_token_address(lp_token)
+        .call()
+        .await?;
+
+    let mut locks = Vec::new();
+    for deposit_id in deposit_ids {
+        let (_, _, amount, unlock_time, withdrawn) = locker.locked_token(deposit_id).call().await?;
+        if withdrawn {
+            continue;
+        }
+        locks.push(LpLock {
+            locker: "Team Finance".to_string(),
+            amount,
+            unlock_timestamp: unlock_time.low_u64(),
+        });
+    }
+
+    Ok(locks)
+}
+
+/// Reads the locks of an LP token in PinkLock. A vesting lock starts unlocking at its TGE date, which
+/// is taken as its unlock date, and only its amount not unlocked yet is counted.
+async fn get_pinklock_locks(
+    locker_address: Address,
+    lp_token: Address,
+    client: &Arc<Provider<Ws>>,
+) -> Result<Vec<LpLock>> {
+    let locker = PINKLOCK::new(locker_address, client.clone());
+    let lock_count = locker.total_lock_count_for_token(lp_token).call().await?;
+    if lock_count.is_zero() {
+        return Ok(Vec::new());
+    }
+
+    let pinklock_locks = locker
+        .get_locks_for_token(lp_token, U256::zero(), lock_count - 1)
+        .call()
+        .await?;
+
+    Ok(pinklock_locks
+        .into_iter()
+        .map(
+            |(_, _, _, amount, _, tge_date, _, _, _, unlocked_amount, _)| LpLock {
+                locker: "PinkLock".to_string(),
+                amount: amount.saturating_sub(unlocked_amount),
+                unlock_timestamp: tge_date.low_u64(),
+            },
+        )
+        .collect())
+}
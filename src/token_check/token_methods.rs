@@ -5,15 +5,15 @@ use std::sync::Arc;
 use crate::abi::erc20::ERC20;
 use crate::abi::uniswap_pair::UNISWAP_PAIR;
 use crate::abi::uniswap_pool::UNISWAP_V3_POOL;
-use crate::data::dex::Dex;
 use crate::data::token_data::ERC20Token;
 use crate::dex::dex_data::TokenDexData;
+use crate::dex::swap_venue::swap_venue;
 
 impl ERC20Token {
     /// Returns the total liquidity token supply for the token's associated DEX pair.
     ///
-    /// This method determines which DEX the token is paired with and calls the
-    /// appropriate function to retrieve the liquidity token supply.
+    /// This method looks up the swap venue of the DEX the token is paired on and
+    /// asks it for the liquidity token supply.
     ///
     /// # Arguments
     ///
@@ -32,10 +32,9 @@ impl ERC20Token {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("No token dex data found"))?;
 
-        match token_dex.dex {
-            Dex::UniswapV2 => get_total_liquidity_token_supply_uniswap_v2(token_dex, client).await,
-            Dex::UniswapV3 => get_total_liquidity_token_supply_uniswap_v3(token_dex, client).await,
-            _ => Ok(U256::zero()),
+        match swap_venue(&token_dex.dex) {
+            Some(venue) => venue.lp_supply(token_dex, client).await,
+            None => Ok(U256::zero()),
         }
     }
 
@@ -0,0 +1,258 @@
+//! This module implements the ownership and privilege check for token contracts.
+//!
+//! It answers "who controls this contract?" by querying `owner()`, `getOwner()` and the
+//! AccessControl `DEFAULT_ADMIN_ROLE`, detects renounced ownership (zero/dead address), classifies
+//! the owner as an EOA, a multisig (Safe), a timelock or another contract, and records how much of the
+//! token supply the owner holds.
+
+use anyhow::Result;
+use ethers::prelude::*;
+use log::warn;
+use std::sync::Arc;
+
+use crate::{
+    abi::{
+        access_control::ACCESS_CONTROL, erc20::ERC20, gnosis_safe::GNOSIS_SAFE, ownable::OWNABLE,
+        timelock_controller::TIMELOCK_CONTROLLER,
+    },
+    app_config::RENOUNCED_OWNER_ADDRESSES,
+    data::token_data::ERC20Token,
+    token_check::{
+        external_api::etherscan_api::get_contract_owner, token_holder_check::u256_div_u256_to_f64,
+    },
+    utils::type_conversion::address_to_string,
+};
+
+/// Maximum number of `DEFAULT_ADMIN_ROLE` holders that are enumerated.
+const MAX_ADMIN_ROLE_HOLDERS: u64 = 10;
+
+/// The kind of account that controls a token contract.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum OwnerType {
+    /// No `owner()`, `getOwner()` or AccessControl admin could be found.
+    #[default]
+    NoOwner,
+    /// Ownership (or the admin role) has been handed to the zero/dead address.
+    Renounced,
+    /// An externally owned account, i.e. a single private key.
+    Eoa,
+    /// A Safe (Gnosis Safe) multisig wallet.
+    Multisig,
+    /// A timelock controller, privileged calls are delayed by `getMinDelay()`.
+    Timelock,
+    /// Any other contract.
+    Contract,
+}
+
+/// Holds the results for the ownership check.
+///
+/// # Fields
+///
+/// * `owner_address` - The owner (or first admin role holder) of the token contract.
+/// * `owner_type` - What kind of account the owner is.
+/// * `is_renounced` - Whether ownership and the admin role have been renounced.
+/// * `admin_role_holders` - Accounts holding the AccessControl `DEFAULT_ADMIN_ROLE`.
+/// * `multisig_owner_count` - Number of signers if the owner is a Safe multisig.
+/// * `multisig_threshold` - Signatures required if the owner is a Safe multisig.
+/// * `timelock_min_delay_seconds` - Minimum delay if the owner is a timelock controller.
+/// * `owner_percentage_tokens_held` - The percentage of the total token supply held by the owner.
+#[derive(Clone, Debug, Default)]
+pub struct TokenOwnershipCheck {
+    pub owner_address: Option<Address>,
+    pub owner_type: OwnerType,
+    pub is_renounced: bool,
+    pub admin_role_holders: Vec<Address>,
+    pub multisig_owner_count: Option<usize>,
+    pub multisig_threshold: Option<u64>,
+    pub timelock_min_delay_seconds: Option<u64>,
+    pub owner_percentage_tokens_held: f64,
+}
+
+impl TokenOwnershipCheck {
+    /// Returns the account that can call privileged functions: the owner unless ownership has been
+    /// renounced, otherwise the first AccessControl admin.
+    pub fn privileged_account(&self) -> Option<Address> {
+        self.owner_address
+            .filter(|_| !self.is_renounced)
+            .or_else(|| self.admin_role_holders.first().copied())
+    }
+}
+
+/// Retrieves the owner of a token contract and analyzes its privileges.
+///
+/// This asynchronous function performs the following steps:
+///
+/// 1. Calls `owner()` and then `getOwner()` on the token.
+/// 2. Enumerates the AccessControl `DEFAULT_ADMIN_ROLE` holders, falling back to checking whether the
+///    deployer still holds the role when the contract is not enumerable.
+/// 3. Detects renounced ownership (zero/dead address).
+/// 4. Classifies the owner as an EOA, Safe multisig, timelock or other contract.
+/// 5. Computes the percentage of the token supply held by the owner.
+///
+/// # Arguments
+///
+/// * `token` - A reference to the ERC20 token data.
+/// * `client` - A shared reference (Arc) to the WebSocket provider used for asynchronous operations.
+///
+/// # Returns
+///
+/// * `Ok(TokenOwnershipCheck)` describing who controls the token, with `OwnerType::NoOwner` if no
+///   owner or admin could be found.
+/// * `Err` if any of the required on-chain calls fail.
+pub async fn get_token_ownership_check(
+    token: &ERC20Token,
+    client: &Arc<Provider<Ws>>,
+) -> Result<TokenOwnershipCheck> {
+    // Step 1: Ownable style owner.
+    let owner = get_ownable_owner(token.address, client).await;
+
+    // Step 2: AccessControl admin role.
+    let admin_role_holders = get_admin_role_holders(token, client).await;
+
+    // Step 3: Renounced ownership. An admin role holder can still act if `owner()` has been
+    // renounced, so the first account that has not been renounced is treated as the owner.
+    let candidates: Vec<Address> = owner
+        .into_iter()
+        .chain(admin_role_holders.iter().copied())
+        .collect();
+
+    let owner_address = match candidates
+        .iter()
+        .find(|candidate| !is_renounced_address(**candidate))
+    {
+        Some(owner_address) => *owner_address,
+        None => {
+            let owner_address = candidates.first().copied();
+            return Ok(TokenOwnershipCheck {
+                owner_address,
+                owner_type: match owner_address {
+                    Some(_) => OwnerType::Renounced,
+                    None => OwnerType::NoOwner,
+                },
+                is_renounced: owner_address.is_some(),
+                admin_role_holders,
+                ..Default::default()
+            });
+        }
+    };
+
+    // Step 4: Classify the owner account.
+    let mut ownership_check = TokenOwnershipCheck {
+        owner_address: Some(owner_address),
+        admin_role_holders,
+        ..Default::default()
+    };
+
+    let owner_code = client.get_code(owner_address, None).await?;
+    if owner_code.is_empty() {
+        ownership_check.owner_type = OwnerType::Eoa;
+    } else {
+        let safe = GNOSIS_SAFE::new(owner_address, client.clone());
+        let timelock = TIMELOCK_CONTROLLER::new(owner_address, client.clone());
+
+        if let (Ok(owners), Ok(threshold)) = (
+            safe.get_owners().call().await,
+            safe.get_threshold().call().await,
+        ) {
+            ownership_check.owner_type = OwnerType::Multisig;
+            ownership_check.multisig_owner_count = Some(owners.len());
+            ownership_check.multisig_threshold = Some(u64::try_from(threshold).unwrap_or(u64::MAX));
+        } else if let Ok(min_delay) = timelock.get_min_delay().call().await {
+            ownership_check.owner_type = OwnerType::Timelock;
+            ownership_check.timelock_min_delay_seconds =
+                Some(u64::try_from(min_delay).unwrap_or(u64::MAX));
+        } else {
+            ownership_check.owner_type = OwnerType::Contract;
+        }
+    }
+
+    // Step 5: Percentage of the supply held by the owner.
+    let total_supply = token.get_total_token_supply(client).await?;
+    if !total_supply.is_zero() {
+        let token_contract = ERC20::new(token.address, client.clone());
+        let owner_balance = token_contract.balance_of(owner_address).call().await?;
+        ownership_check.owner_percentage_tokens_held =
+            100_f64 * u256_div_u256_to_f64(owner_balance, total_supply)?;
+    }
+
+    println!(
+        "token owner is {} ({:?})",
+        address_to_string(owner_address),
+        ownership_check.owner_type
+    );
+
+    Ok(ownership_check)
+}
+
+/// Calls `owner()` and then `getOwner()` on a contract, returning `None` if neither is implemented.
+async fn get_ownable_owner(contract: Address, client: &Arc<Provider<Ws>>) -> Option<Address> {
+    let ownable = OWNABLE::new(contract, client.clone());
+
+    if let Ok(owner) = ownable.owner().call().await {
+        return Some(owner);
+    }
+
+    ownable.get_owner().call().await.ok()
+}
+
+/// Retrieves the holders of the AccessControl `DEFAULT_ADMIN_ROLE`.
+///
+/// Uses `getRoleMemberCount`/`getRoleMember` when the contract is `AccessControlEnumerable`.
+/// Otherwise checks whether the deployer (as reported by Etherscan) still holds the role.
+/// Returns an empty list if the contract does not use AccessControl.
+async fn get_admin_role_holders(token: &ERC20Token, client: &Arc<Provider<Ws>>) -> Vec<Address> {
+    let access_control = ACCESS_CONTROL::new(token.address, client.clone());
+
+    let admin_role = match access_control.default_admin_role().call().await {
+        Ok(admin_role) => admin_role,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut admin_role_holders = Vec::new();
+
+    // AccessControlEnumerable
+    if let Ok(member_count) = access_control
+        .get_role_member_count(admin_role)
+        .call()
+        .await
+    {
+        let member_count = member_count
+            .min(U256::from(MAX_ADMIN_ROLE_HOLDERS))
+            .as_u64();
+        for index in 0..member_count {
+            match access_control
+                .get_role_member(admin_role, U256::from(index))
+                .call()
+                .await
+            {
+                Ok(member) => admin_role_holders.push(member),
+                Err(error) => warn!("could not get admin role member {} => {}", index, error),
+            }
+        }
+        return admin_role_holders;
+    }
+
+    // Plain AccessControl, the role holders cannot be enumerated so check the deployer.
+    let token_address = address_to_string(token.address);
+    let deployer = match get_contract_owner(&token_address, &token.chain).await {
+        Ok(Some(deployer)) => deployer.parse::<Address>().ok(),
+        Ok(None) => None,
+        Err(error) => {
+            warn!("could not get contract deployer => {}", error);
+            None
+        }
+    };
+
+    if let Some(deployer) = deployer {
+        if let Ok(true) = access_control.has_role(admin_role, deployer).call().await {
+            admin_role_holders.push(deployer);
+        }
+    }
+
+    admin_role_holders
+}
+
+/// Returns `true` if the address is one ownership is handed to when it is renounced.
+pub fn is_renounced_address(address: Address) -> bool {
+    RENOUNCED_OWNER_ADDRESSES.contains(&address_to_string(address).as_str())
+}
@@ -0,0 +1,219 @@
+//! This module compares a token's liquidity across all of its pools.
+//!
+//! The token's top pool only tells part of the story: liquidity can be split across many pools, and a
+//! team that drained the original pool can open a new, small one, making the token look freshly
+//! launched while the holders of the old pool are left with nothing to sell into. Every pool of the
+//! token on the supported DEXes is listed with its liquidity, how much of it is locked or burned and
+//! its age, and the top pool being tiny or brand new while an older pool was drained is flagged.
+
+use anyhow::Result;
+use ethers::prelude::*;
+use std::sync::Arc;
+
+use crate::{
+    app_config::{DRAINED_POOL_MAX_LIQUIDITY_USD, NEW_POOL_MAX_AGE_DAYS, USD_LIQUIDITY_THRESHOLD},
+    data::{dex::Dex, token_data::ERC20Token},
+    dex::dex_data::{find_all_dex_pools_for_token, TokenDexData},
+    token_check::token_liquidity_check::get_liquidity_lock,
+};
+
+/// A pool of the token and its liquidity.
+///
+/// # Fields
+///
+/// * `dex` - The DEX the pool is on.
+/// * `pair_address` - The pair / pool (the pool manager for Uniswap V4 pools).
+/// * `base_token_symbol` - The base token the pool pairs the token against.
+/// * `liquidity_in_usd` - The pool's liquidity in USD.
+/// * `onchain_valuation_failed` - Whether the pool could not be valued, its liquidity reading 0.
+/// * `percentage_locked_or_burned` - The percentage of the pool's liquidity locked or burned, `None`
+///   if it could not be computed or the pool is drained.
+/// * `created_at_block` - The block the pool was created at, `None` if the DEX does not record it.
+/// * `age_in_days` - How long ago the pool was created, `None` if the creation block is unknown.
+/// * `is_drained` - Whether the pool holds less than [`DRAINED_POOL_MAX_LIQUIDITY_USD`]. A pool that
+///   could not be valued is not considered drained.
+#[derive(Clone, Debug, Default)]
+pub struct PoolLiquidity {
+    pub dex: Dex,
+    pub pair_address: Address,
+    pub base_token_symbol: String,
+    pub liquidity_in_usd: f64,
+    pub onchain_valuation_failed: bool,
+    pub percentage_locked_or_burned: Option<f64>,
+    pub created_at_block: Option<u64>,
+    pub age_in_days: Option<f64>,
+    pub is_drained: bool,
+}
+
+impl PoolLiquidity {
+    /// Builds the pool's liquidity from its dex data, without its lock status and age.
+    pub fn from_dex_data(dex_data: &TokenDexData) -> Self {
+        Self {
+            dex: dex_data.dex.clone(),
+            pair_address: dex_data.pair_address,
+            base_token_symbol: dex_data.base_token_symbol.clone(),
+            liquidity_in_usd: dex_data.liquidity_in_usd,
+            onchain_valuation_failed: dex_data.onchain_valuation_failed,
+            created_at_block: (dex_data.create_at_block_number > 0)
+                .then_some(dex_data.create_at_block_number),
+            is_drained: !dex_data.onchain_valuation_failed
+                && dex_data.liquidity_in_usd < DRAINED_POOL_MAX_LIQUIDITY_USD,
+            ..Default::default()
+        }
+    }
+}
+
+/// Holds the results of the pool check.
+///
+/// # Fields
+///
+/// * `pools` - The token's pools, highest liquidity first.
+/// * `total_liquidity_in_usd` - The liquidity of all pools, in USD.
+/// * `top_pool_share_percentage` - The share of the total liquidity held by the top pool (0.0 to
+///   100.0).
+/// * `fragmentation` - How spread the liquidity is across pools, from 0.0 (all in one pool) towards
+///   1.0 (evenly split across many pools): one minus the sum of the squared pool shares.
+/// * `top_pool_is_tiny_or_new` - Whether the top pool holds less than [`USD_LIQUIDITY_THRESHOLD`] or
+///   is younger than [`NEW_POOL_MAX_AGE_DAYS`].
+/// * `drained_older_pools` - Drained pools created before the top pool.
+#[derive(Clone, Debug, Default)]
+pub struct TokenPoolCheck {
+    pub pools: Vec<PoolLiquidity>,
+    pub total_liquidity_in_usd: f64,
+    pub top_pool_share_percentage: f64,
+    pub fragmentation: f64,
+    pub top_pool_is_tiny_or_new: bool,
+    pub drained_older_pools: Vec<Address>,
+}
+
+impl TokenPoolCheck {
+    /// Computes the liquidity metrics of the token's pools.
+    ///
+    /// # Arguments
+    ///
+    /// * `pools` - The token's pools, in any order.
+    pub fn from_pools(mut pools: Vec<PoolLiquidity>) -> Self {
+        pools.sort_by(|a, b| b.liquidity_in_usd.total_cmp(&a.liquidity_in_usd));
+
+        let total_liquidity_in_usd: f64 = pools.iter().map(|pool| pool.liquidity_in_usd).sum();
+        let top_pool = match pools.first() {
+            Some(top_pool) if total_liquidity_in_usd > 0.0 => top_pool,
+            _ => {
+                return Self {
+                    pools,
+                    ..Default::default()
+                }
+            }
+        };
+
+        let top_pool_share_percentage =
+            100_f64 * top_pool.liquidity_in_usd / total_liquidity_in_usd;
+        let fragmentation = 1_f64
+            - pools
+                .iter()
+                .map(|pool| (pool.liquidity_in_usd / total_liquidity_in_usd).powi(2))
+                .sum::<f64>();
+
+        let top_pool_is_tiny_or_new = top_pool.liquidity_in_usd < USD_LIQUIDITY_THRESHOLD
+            || top_pool
+                .age_in_days
+                .is_some_and(|age| age < NEW_POOL_MAX_AGE_DAYS);
+
+        // Pools of DEXes that do not record creation blocks cannot be compared by age.
+        let drained_older_pools = match top_pool.created_at_block {
+            Some(top_pool_created_at) => pools
+                .iter()
+                .filter(|pool| {
+                    pool.is_drained
+                        && pool
+                            .created_at_block
+                            .is_some_and(|created_at| created_at < top_pool_created_at)
+                })
+                .map(|pool| pool.pair_address)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Self {
+            pools,
+            total_liquidity_in_usd,
+            top_pool_share_percentage,
+            fragmentation,
+            top_pool_is_tiny_or_new,
+            drained_older_pools,
+        }
+    }
+
+    /// Returns `true` if the top pool is tiny or brand new while an older pool was drained: the
+    /// liquidity was likely pulled from the original pool and a new one opened in its place.
+    pub fn liquidity_moved_from_drained_pool(&self) -> bool {
+        self.top_pool_is_tiny_or_new && !self.drained_older_pools.is_empty()
+    }
+}
+
+/// Compares the token's liquidity across all of its pools.
+///
+/// This asynchronous function performs the following steps:
+///
+/// 1. Lists the token's pools on every supported DEX, drained pools included.
+/// 2. Computes how long ago each pool was created, from its creation block.
+/// 3. Checks the percentage of each pool's liquidity locked or burned, for pools that are not drained.
+/// 4. Computes the total liquidity, how fragmented it is and whether an older pool was drained.
+///
+/// # Arguments
+///
+/// * `token` - A reference to the ERC20 token data.
+/// * `client` - A shared reference (Arc) to the WebSocket provider used for asynchronous operations.
+///
+/// # Returns
+///
+/// * `Ok(TokenPoolCheck)` with the token's pools and liquidity metrics.
+/// * `Err` if listing the pools or reading the blocks fails. A pool whose lock status cannot be
+///   retrieved keeps `percentage_locked_or_burned` as `None` instead.
+pub async fn get_token_pool_check(
+    token: &ERC20Token,
+    client: &Arc<Provider<Ws>>,
+) -> Result<TokenPoolCheck> {
+    // Step 1: List the pools.
+    let pools = find_all_dex_pools_for_token(token.address, &token.chain).await?;
+
+    let latest_block = client
+        .get_block(BlockNumber::Latest)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Could not retrieve the latest block"))?;
+
+    let mut pool_liquidity = Vec::with_capacity(pools.len());
+    for dex_data in pools {
+        let mut pool = PoolLiquidity::from_dex_data(&dex_data);
+
+        // Step 2: Compute the pool's age.
+        if let Some(created_at_block) = pool.created_at_block {
+            if let Some(block) = client.get_block(created_at_block).await? {
+                let age_in_seconds = latest_block.timestamp.saturating_sub(block.timestamp);
+                pool.age_in_days = Some(age_in_seconds.as_u64() as f64 / 86_400_f64);
+            }
+        }
+
+        // Step 3: Check the pool's lock status, the lock check reading the token's pool.
+        if !pool.is_drained {
+            let pool_token = ERC20Token {
+                token_dex: Some(dex_data),
+                ..token.clone()
+            };
+            match get_liquidity_lock(&pool_token, client).await {
+                Ok(liquidity_lock) => {
+                    pool.percentage_locked_or_burned = liquidity_lock.percentage_locked_or_burned
+                }
+                Err(e) => println!(
+                    "could not check liquidity lock of pool {:?}: {}",
+                    pool.pair_address, e
+                ),
+            }
+        }
+
+        pool_liquidity.push(pool);
+    }
+
+    // Step 4: Compute the liquidity metrics.
+    Ok(TokenPoolCheck::from_pools(pool_liquidity))
+}
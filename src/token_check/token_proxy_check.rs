@@ -0,0 +1,304 @@
+//! This module implements proxy detection for token contracts.
+//!
+//! Upgradeable tokens keep their logic in a separate implementation contract and only expose a thin
+//! proxy stub at the token address. Reviewing the stub tells us nothing about how the token behaves,
+//! so this module reads the standard proxy storage slots on-chain (EIP-1967, EIP-1822 and the legacy
+//! OpenZeppelin slot), recognises EIP-1167 minimal clones from their bytecode, and falls back to the
+//! implementation Etherscan reports. It also resolves who is able to upgrade the proxy.
+
+use anyhow::Result;
+use ethers::prelude::*;
+use log::warn;
+use std::sync::Arc;
+
+use crate::{
+    abi::{ownable::OWNABLE, upgradeable_proxy::UPGRADEABLE_PROXY},
+    app_config::RENOUNCED_OWNER_ADDRESSES,
+    data::token_data::ERC20Token,
+    token_check::external_api::etherscan_api::get_proxy_implementation,
+    utils::type_conversion::{address_to_string, h256_to_address},
+};
+
+/// EIP-1967 implementation slot, `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`.
+pub const EIP1967_IMPLEMENTATION_SLOT: &str =
+    "0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+
+/// EIP-1967 admin slot, `bytes32(uint256(keccak256("eip1967.proxy.admin")) - 1)`.
+pub const EIP1967_ADMIN_SLOT: &str =
+    "0xb53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103";
+
+/// EIP-1967 beacon slot, `bytes32(uint256(keccak256("eip1967.proxy.beacon")) - 1)`.
+pub const EIP1967_BEACON_SLOT: &str =
+    "0xa3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50";
+
+/// EIP-1822 (UUPS) implementation slot, `keccak256("PROXIABLE")`.
+pub const EIP1822_PROXIABLE_SLOT: &str =
+    "0xc5f16f0fcc639fa48a6947836d9850f504798523bf8c9a3a87d5876cf622bcf7";
+
+/// Legacy OpenZeppelin (zos) implementation slot, `keccak256("org.zeppelinos.proxy.implementation")`.
+pub const OPENZEPPELIN_IMPLEMENTATION_SLOT: &str =
+    "0x7050c9e0f4ca769c69bd3a8ef740bc37934f8e2c036e5a723fd8ee048ed3f8c3";
+
+/// Legacy OpenZeppelin (zos) admin slot, `keccak256("org.zeppelinos.proxy.admin")`.
+pub const OPENZEPPELIN_ADMIN_SLOT: &str =
+    "0x10d6a54a4754c8869d6886b5f5d7fbfa5b4522237ea5c60d11bc4e7a1ff9390b";
+
+/// Runtime bytecode prefix of an EIP-1167 minimal proxy, followed by the 20 byte implementation address.
+const EIP1167_BYTECODE_PREFIX: [u8; 10] =
+    [0x36, 0x3d, 0x3d, 0x37, 0x3d, 0x3d, 0x3d, 0x36, 0x3d, 0x73];
+
+/// The proxy pattern a token contract uses.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ProxyType {
+    /// The token is not a proxy; its logic lives at the token address.
+    #[default]
+    NotProxy,
+    /// EIP-1967 transparent proxy, upgraded through a separate admin (usually a `ProxyAdmin` contract).
+    Transparent,
+    /// UUPS proxy (EIP-1822 / EIP-1967 without an admin), upgraded through the implementation itself.
+    Uups,
+    /// EIP-1967 beacon proxy, the implementation is read from a beacon contract.
+    Beacon,
+    /// EIP-1167 minimal proxy (clone), the implementation is fixed in the bytecode.
+    MinimalClone,
+    /// Legacy OpenZeppelin (zos) proxy.
+    LegacyOpenZeppelin,
+    /// Etherscan reports the contract as a proxy, but no standard storage slot is used.
+    Unknown,
+}
+
+/// Holds the results for the proxy check.
+///
+/// # Fields
+///
+/// * `proxy_type` - The detected proxy pattern, `ProxyType::NotProxy` for regular contracts.
+/// * `implementation_address` - The contract holding the logic the proxy delegates to.
+/// * `proxy_admin` - The admin stored in the EIP-1967 or legacy OpenZeppelin admin slot (transparent
+///   and legacy OpenZeppelin proxies only).
+/// * `beacon_address` - The beacon the implementation is read from (beacon proxies only).
+/// * `upgrade_authority` - The account that is ultimately able to change the implementation.
+/// * `is_upgradeable` - Whether the logic of the token can still be replaced.
+#[derive(Clone, Debug, Default)]
+pub struct TokenProxyCheck {
+    pub proxy_type: ProxyType,
+    pub implementation_address: Option<Address>,
+    pub proxy_admin: Option<Address>,
+    pub beacon_address: Option<Address>,
+    pub upgrade_authority: Option<Address>,
+    pub is_upgradeable: bool,
+}
+
+impl TokenProxyCheck {
+    /// Returns `true` if the token delegates its logic to another contract.
+    pub fn is_proxy(&self) -> bool {
+        self.proxy_type != ProxyType::NotProxy
+    }
+}
+
+/// Detects whether a token is a proxy and resolves its implementation and upgrade authority.
+///
+/// This asynchronous function performs the following steps:
+///
+/// 1. Checks the runtime bytecode for an EIP-1167 minimal proxy.
+/// 2. Reads the EIP-1967 beacon, implementation and admin slots.
+/// 3. Reads the EIP-1822 and legacy OpenZeppelin implementation slots.
+/// 4. Falls back to the implementation reported by Etherscan.
+/// 5. Resolves who can upgrade the proxy (the `ProxyAdmin` owner or the admin itself, the proxy owner
+///    or the beacon owner).
+///
+/// # Arguments
+///
+/// * `token` - A reference to the ERC20 token data.
+/// * `client` - A shared reference (Arc) to the WebSocket provider used for asynchronous operations.
+///
+/// # Returns
+///
+/// * `Ok(TokenProxyCheck)` describing the proxy, with `ProxyType::NotProxy` for regular contracts.
+/// * `Err` if any of the on-chain calls fail.
+pub async fn get_token_proxy_check(
+    token: &ERC20Token,
+    client: &Arc<Provider<Ws>>,
+) -> Result<TokenProxyCheck> {
+    // Step 1: EIP-1167 clones carry the implementation address in their bytecode.
+    let code = client.get_code(token.address, None).await?;
+    if let Some(implementation) = get_minimal_proxy_implementation(&code) {
+        return Ok(TokenProxyCheck {
+            proxy_type: ProxyType::MinimalClone,
+            implementation_address: Some(implementation),
+            // The implementation of a clone can never change.
+            is_upgradeable: false,
+            ..Default::default()
+        });
+    }
+
+    // Step 2: EIP-1967 beacon proxy.
+    if let Some(beacon) = read_address_slot(token.address, EIP1967_BEACON_SLOT, client).await? {
+        let beacon_contract = UPGRADEABLE_PROXY::new(beacon, client.clone());
+        let implementation = beacon_contract.implementation().call().await.ok();
+        let upgrade_authority = get_owner(beacon, client).await;
+
+        return Ok(TokenProxyCheck {
+            proxy_type: ProxyType::Beacon,
+            implementation_address: implementation,
+            beacon_address: Some(beacon),
+            upgrade_authority,
+            is_upgradeable: !is_renounced(upgrade_authority),
+            ..Default::default()
+        });
+    }
+
+    // Step 2 (continued): EIP-1967 transparent or UUPS proxy.
+    if let Some(implementation) =
+        read_address_slot(token.address, EIP1967_IMPLEMENTATION_SLOT, client).await?
+    {
+        let proxy_admin = read_address_slot(token.address, EIP1967_ADMIN_SLOT, client).await?;
+
+        let (proxy_type, upgrade_authority) = match proxy_admin {
+            Some(admin) => (
+                ProxyType::Transparent,
+                Some(resolve_admin_authority(admin, client).await?),
+            ),
+            None => (ProxyType::Uups, get_owner(token.address, client).await),
+        };
+
+        return Ok(TokenProxyCheck {
+            proxy_type,
+            implementation_address: Some(implementation),
+            proxy_admin,
+            upgrade_authority,
+            is_upgradeable: !is_renounced(upgrade_authority),
+            ..Default::default()
+        });
+    }
+
+    // Step 3: EIP-1822 and legacy OpenZeppelin slots.
+    if let Some(implementation) =
+        read_address_slot(token.address, EIP1822_PROXIABLE_SLOT, client).await?
+    {
+        let upgrade_authority = get_owner(token.address, client).await;
+        return Ok(TokenProxyCheck {
+            proxy_type: ProxyType::Uups,
+            implementation_address: Some(implementation),
+            upgrade_authority,
+            is_upgradeable: !is_renounced(upgrade_authority),
+            ..Default::default()
+        });
+    }
+
+    if let Some(implementation) =
+        read_address_slot(token.address, OPENZEPPELIN_IMPLEMENTATION_SLOT, client).await?
+    {
+        let proxy_admin = read_address_slot(token.address, OPENZEPPELIN_ADMIN_SLOT, client).await?;
+        let (upgrade_authority, is_upgradeable) = match proxy_admin {
+            Some(admin) => {
+                let upgrade_authority = Some(resolve_admin_authority(admin, client).await?);
+                (upgrade_authority, !is_renounced(upgrade_authority))
+            }
+            // Without an admin we cannot prove the upgrade path is closed.
+            None => (get_owner(token.address, client).await, true),
+        };
+
+        return Ok(TokenProxyCheck {
+            proxy_type: ProxyType::LegacyOpenZeppelin,
+            implementation_address: Some(implementation),
+            proxy_admin,
+            upgrade_authority,
+            is_upgradeable,
+            ..Default::default()
+        });
+    }
+
+    // Step 4: Non-standard proxies Etherscan has been able to resolve.
+    let token_address = address_to_string(token.address);
+    let etherscan_implementation =
+        match get_proxy_implementation(&token_address, &token.chain).await {
+            Ok(implementation) => implementation,
+            Err(error) => {
+                warn!(
+                    "could not get proxy implementation from etherscan => {}",
+                    error
+                );
+                None
+            }
+        };
+
+    if let Some(implementation) = etherscan_implementation {
+        let upgrade_authority = get_owner(token.address, client).await;
+        return Ok(TokenProxyCheck {
+            proxy_type: ProxyType::Unknown,
+            implementation_address: Some(implementation),
+            upgrade_authority,
+            is_upgradeable: true,
+            ..Default::default()
+        });
+    }
+
+    Ok(TokenProxyCheck::default())
+}
+
+/// Extracts the implementation address from EIP-1167 minimal proxy runtime bytecode.
+///
+/// # Arguments
+///
+/// * `code` - The runtime bytecode of the contract.
+///
+/// # Returns
+///
+/// * `Some(Address)` of the implementation if the bytecode is a minimal proxy, otherwise `None`.
+pub fn get_minimal_proxy_implementation(code: &Bytes) -> Option<Address> {
+    let prefix_len = EIP1167_BYTECODE_PREFIX.len();
+    if code.len() < prefix_len + 20 || code[..prefix_len] != EIP1167_BYTECODE_PREFIX {
+        return None;
+    }
+
+    Some(Address::from_slice(&code[prefix_len..prefix_len + 20]))
+}
+
+/// Reads an address stored in the given storage slot of a contract.
+///
+/// Returns `None` when the slot is empty (zero address).
+async fn read_address_slot(
+    contract: Address,
+    slot: &str,
+    client: &Arc<Provider<Ws>>,
+) -> Result<Option<Address>> {
+    let slot: H256 = slot.parse()?;
+    let value = client.get_storage_at(contract, slot, None).await?;
+    let address = h256_to_address(&value);
+
+    if address.is_zero() {
+        Ok(None)
+    } else {
+        Ok(Some(address))
+    }
+}
+
+/// Resolves the account controlling a transparent proxy admin.
+///
+/// The admin slot usually points at a `ProxyAdmin` contract, in which case its owner is the
+/// account able to upgrade. If the admin is an EOA it is the upgrade authority itself.
+async fn resolve_admin_authority(admin: Address, client: &Arc<Provider<Ws>>) -> Result<Address> {
+    let admin_code = client.get_code(admin, None).await?;
+    if admin_code.is_empty() {
+        return Ok(admin);
+    }
+
+    Ok(get_owner(admin, client).await.unwrap_or(admin))
+}
+
+/// Calls `owner()` on a contract, returning `None` if the contract is not `Ownable`.
+async fn get_owner(contract: Address, client: &Arc<Provider<Ws>>) -> Option<Address> {
+    let ownable = OWNABLE::new(contract, client.clone());
+    ownable.owner().call().await.ok()
+}
+
+/// Returns `true` if the upgrade authority has been handed to a burn address.
+///
+/// An unknown authority is treated as not renounced, since we cannot prove upgrades are impossible.
+fn is_renounced(upgrade_authority: Option<Address>) -> bool {
+    match upgrade_authority {
+        Some(authority) => RENOUNCED_OWNER_ADDRESSES
+            .contains(&address_to_string(authority).to_lowercase().as_str()),
+        None => false,
+    }
+}
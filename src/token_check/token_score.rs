@@ -5,8 +5,9 @@ use serde::Deserialize;
 
 use crate::{
     app_config::{
-        FINAL_DETERMINATION_PROMPT_UPDATED, LIQUIDITY_PERCENTAGE_LOCKED,
-        TOKEN_HOLDER_THRESHOLD_PERCENTAGE, USD_LIQUIDITY_THRESHOLD,
+        FINAL_DETERMINATION_PROMPT_UPDATED, HONEYPOT_TAX_PERCENTAGE, LIQUIDITY_PERCENTAGE_LOCKED,
+        TOKEN_HOLDER_THRESHOLD_PERCENTAGE, TOKEN_TAX_THRESHOLD_PERCENTAGE,
+        TOP_10_HOLDERS_THRESHOLD_PERCENTAGE, USD_LIQUIDITY_THRESHOLD,
     },
     token_check::ai::{
         ai_structs::PromptType,
@@ -32,6 +33,13 @@ pub struct TokenScoreAssessment {
     pub reason: String,
 }
 
+impl TokenScoreAssessment {
+    /// Returns `true` if the token was scored "1 - Likely Scam" or "0 - Scam".
+    pub fn is_scam(&self) -> bool {
+        self.token_score.contains("Scam")
+    }
+}
+
 /// Returns the token reputation score based on an AI evaluation of the provided token checklist.
 ///
 /// # Arguments
@@ -72,12 +80,40 @@ pub fn get_token_score_with_rules_based_approch(token_checklist: TokenCheckList)
         None | Some(true) => {}
     }
 
+    // check token does not stop being sellable some time after purchase
+    if token_checklist.time_bomb.is_time_bomb() {
+        return TokenScore::Scam;
+    }
+
+    // check the highest tax measured during simulation, a tax this high makes the token a honeypot
+    let highest_tax = [
+        token_checklist.buy_tax_percentage,
+        token_checklist.sell_tax_percentage,
+        token_checklist.transfer_tax_percentage,
+    ]
+    .into_iter()
+    .flatten()
+    .fold(0.0, f64::max);
+
+    if highest_tax >= HONEYPOT_TAX_PERCENTAGE {
+        return TokenScore::Scam;
+    }
+
     // check if total scam
     if token_checklist.possible_scam && !token_checklist.could_legitimately_justify_suspicious_code
     {
         return TokenScore::Scam;
     }
 
+    // check the hook of the token's uniswap v4 pool was not found to be malicious
+    if token_checklist
+        .uniswap_v4_hook
+        .as_ref()
+        .is_some_and(|hook| hook.is_malicious())
+    {
+        return TokenScore::Scam;
+    }
+
     // check that at least a high percetange ( typically 90 to 95%) of liquidity is locked or
     // burned
     let enough_liquidity_is_locked_or_burned =
@@ -95,16 +131,51 @@ pub fn get_token_score_with_rules_based_approch(token_checklist: TokenCheckList)
     let top_token_holder_check =
         token_checklist.top_holder_percentage_tokens_held < TOKEN_HOLDER_THRESHOLD_PERCENTAGE;
 
-    // check contract creator wallet only holdes small percentage of tokens
-    // let creator_token_holdings_check =
-    //     token_checklist.creator_percentage_tokens_held < TOKEN_HOLDER_THRESHOLD_PERCENTAGE;
+    // check the 10 largest holders together only hold a minority of the tokens
+    let holder_concentration_check =
+        token_checklist.holder_distribution.top_10_percentage < TOP_10_HOLDERS_THRESHOLD_PERCENTAGE;
+
+    // check contract creator and owner wallets only hold a small percentage of tokens
+    let creator_token_holdings_check = token_checklist
+        .holder_distribution
+        .deployer_and_owner_percentage
+        < TOKEN_HOLDER_THRESHOLD_PERCENTAGE;
+
+    // check contract creator has not deployed tokens we previously found to be scams
+    let creator_reputation_check = !token_checklist.deployer.has_scam_history();
+
+    // check token does not charge a high tax on buys, sells or transfers
+    let token_tax_check = highest_tax < TOKEN_TAX_THRESHOLD_PERCENTAGE;
+
+    // check holders can sell roughly as much as they can buy
+    let trade_limits_check = !token_checklist.trade_limits.sell_limit_far_below_buy_limit;
+
+    // check the token's liquidity was not pulled from an older pool and moved to a new, small one
+    let pool_migration_check = !token_checklist.pools.liquidity_moved_from_drained_pool();
 
     // if token is solidity code is clean
     if !token_checklist.possible_scam {
-        if enough_liquidity_is_locked_or_burned && top_token_holder_check
-        // && creator_token_holdings_check
+        if enough_liquidity_is_locked_or_burned
+            && top_token_holder_check
+            && holder_concentration_check
+            && creator_token_holdings_check
+            && creator_reputation_check
+            && token_tax_check
+            && trade_limits_check
+            && pool_migration_check
         {
-            if enough_liquidity {
+            // an upgradeable token can have its logic swapped out, and an owner (or a v4 pool hook)
+            // that can make the token unsellable can trap holders at any time, so none can be fully
+            // legit
+            let hook_can_trap_traders = token_checklist
+                .uniswap_v4_hook
+                .as_ref()
+                .is_some_and(|hook| hook.can_trap_traders());
+            if enough_liquidity
+                && !token_checklist.proxy.is_upgradeable
+                && !token_checklist.owner_privileges.can_trap_holders()
+                && !hook_can_trap_traders
+            {
                 return TokenScore::Legit;
             } else {
                 return TokenScore::LikelyLegit;
@@ -126,8 +197,14 @@ pub fn get_token_score_with_rules_based_approch(token_checklist: TokenCheckList)
 
     if token_checklist.possible_scam && token_checklist.could_legitimately_justify_suspicious_code {
         if enough_liquidity {
-            if enough_liquidity_is_locked_or_burned && top_token_holder_check
-            // && creator_token_holdings_check
+            if enough_liquidity_is_locked_or_burned
+                && top_token_holder_check
+                && holder_concentration_check
+                && creator_token_holdings_check
+                && creator_reputation_check
+                && token_tax_check
+                && trade_limits_check
+                && pool_migration_check
             {
                 if token_checklist.has_website && token_checklist.has_twitter_or_discord {
                     return TokenScore::LikelyLegit;
@@ -1,22 +1,29 @@
-//! Utility functions for handling transaction amounts, wallet management, block information,
+//! Utility functions for handling transaction amounts, block information,
 //! and fee calculations
 
+use crate::abi::aerodrome_router::{Route, AERODROME_ROUTER};
+use crate::abi::chainlink_aggregator::CHAINLINK_AGGREGATOR;
+use crate::abi::slipstream_quoter::{
+    QuoteExactInputSingleParams as SlipstreamQuoteExactInputSingleParams, SLIPSTREAM_QUOTER,
+};
 use crate::abi::uniswap_quoter::{QuoteExactInputSingleParams, UNISWAP_QUOTER};
 use crate::abi::uniswap_router_v2::UNISWAP_V2_ROUTER;
+use crate::abi::uniswap_v4_quoter::{PoolKey, QuoteExactSingleParams, UNISWAP_V4_QUOTER};
+use crate::app_config::CHAINLINK_STALENESS_GRACE_SECONDS;
+use crate::chainlink::chainlink_feed_map::{
+    get_chainlink_heartbeat_seconds, get_chainlink_price_feed_for_token_,
+};
 use crate::data::chain_data::CHAIN_DATA;
 use anyhow::{anyhow, Context, Result};
-use ethers::core::k256::ecdsa::SigningKey;
-use ethers::types::{Address, Block, BlockNumber, Chain, H256, U256, U64};
+use ethers::types::{Address, Block, BlockNumber, Chain, H256, I256, U256, U64};
 use ethers::utils::format_units;
 use ethers::{
     providers::{Middleware, Provider, Ws},
-    signers::{LocalWallet, Signer, Wallet},
     types::Eip1559TransactionRequest,
 };
 use rand::Rng;
 use std::cmp::min;
 use std::env;
-use std::str::FromStr;
 use std::sync::Arc;
 
 /// Enum representing the possible slippage tolerances for a transaction.
@@ -124,6 +131,149 @@ pub async fn get_amount_out_uniswap_v3(
     Ok(amount_out_min)
 }
 
+/// Calculates the output amount of a swap on an Aerodrome volatile or stable pool, applying a
+/// specified slippage tolerance.
+///
+/// # Arguments
+///
+/// * `token_in` - The address of the input token.
+/// * `token_out` - The address of the output token.
+/// * `stable` - Whether the pool is a stable pool.
+/// * `amount_in` - The amount of input tokens.
+/// * `slippage` - The slippage tolerance.
+/// * `chain` - The blockchain network.
+/// * `client` - The provider client.
+///
+/// # Returns
+///
+/// * [`anyhow::Result<U256>`] - The amount of output tokens expected, or an error if Aerodrome is not
+///   deployed on the chain.
+pub async fn get_amount_out_aerodrome(
+    token_in: Address,
+    token_out: Address,
+    stable: bool,
+    amount_in: U256,
+    slippage: TxSlippage,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> anyhow::Result<U256> {
+    let aerodrome = CHAIN_DATA
+        .get_address(chain)
+        .aerodrome
+        .as_ref()
+        .ok_or_else(|| anyhow!("Aerodrome is not deployed on {:?}", chain))?;
+    let router = AERODROME_ROUTER::new(aerodrome.router.parse::<Address>()?, client.clone());
+
+    let route = Route {
+        from: token_in,
+        to: token_out,
+        stable,
+        factory: aerodrome.pool_factory.parse()?,
+    };
+    let amounts = router
+        .get_amounts_out(amount_in, vec![route])
+        .call()
+        .await?;
+    let base_amount_out = amounts
+        .last()
+        .copied()
+        .ok_or_else(|| anyhow!("Aerodrome router returned no amounts"))?;
+
+    Ok(amount_out_adjusted_for_slippage(base_amount_out, slippage))
+}
+
+/// Calculates the output amount of a swap on an Aerodrome Slipstream pool using its quoter,
+/// applying a specified slippage tolerance.
+///
+/// # Arguments
+///
+/// * `token_in` - The address of the input token.
+/// * `token_out` - The address of the output token.
+/// * `tick_spacing` - The tick spacing identifying the pool.
+/// * `amount_in` - The amount of input tokens.
+/// * `slippage` - The slippage tolerance.
+/// * `chain` - The blockchain network.
+/// * `client` - The provider client.
+///
+/// # Returns
+///
+/// * [`anyhow::Result<U256>`] - The amount of output tokens expected, or an error if Aerodrome is not
+///   deployed on the chain.
+pub async fn get_amount_out_aerodrome_slipstream(
+    token_in: Address,
+    token_out: Address,
+    tick_spacing: i32,
+    amount_in: U256,
+    slippage: TxSlippage,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> anyhow::Result<U256> {
+    let aerodrome = CHAIN_DATA
+        .get_address(chain)
+        .aerodrome
+        .as_ref()
+        .ok_or_else(|| anyhow!("Aerodrome is not deployed on {:?}", chain))?;
+    let quoter = SLIPSTREAM_QUOTER::new(
+        aerodrome.slipstream_quoter.parse::<Address>()?,
+        client.clone(),
+    );
+
+    let quote_params = SlipstreamQuoteExactInputSingleParams {
+        token_in,
+        token_out,
+        amount_in,
+        tick_spacing,
+        sqrt_price_limit_x96: U256::zero(), // No price limit
+    };
+    let (amount_out, _, _, _) = quoter.quote_exact_input_single(quote_params).call().await?;
+
+    Ok(amount_out_adjusted_for_slippage(amount_out, slippage))
+}
+
+/// Calculates the output amount of a swap on a Uniswap V4 pool using the V4 quoter, applying a
+/// specified slippage tolerance. The pool's hook runs during the quote, so a hook that blocks or taxes
+/// the swap is reflected in the result.
+///
+/// # Arguments
+///
+/// * `pool_key` - The key identifying the pool.
+/// * `token_in` - The address of the input currency, the zero address for native ETH.
+/// * `amount_in` - The amount of input tokens.
+/// * `slippage` - The slippage tolerance.
+/// * `chain` - The blockchain network.
+/// * `client` - The provider client.
+///
+/// # Returns
+///
+/// * [`anyhow::Result<U256>`] - The amount of output tokens expected, or an error if Uniswap V4 is not
+///   deployed on the chain.
+pub async fn get_amount_out_uniswap_v4(
+    pool_key: PoolKey,
+    token_in: Address,
+    amount_in: U256,
+    slippage: TxSlippage,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> anyhow::Result<U256> {
+    let uniswap_v4 = CHAIN_DATA
+        .get_address(chain)
+        .uniswap_v4
+        .as_ref()
+        .ok_or_else(|| anyhow!("Uniswap V4 is not deployed on {:?}", chain))?;
+    let quoter = UNISWAP_V4_QUOTER::new(uniswap_v4.quoter.parse::<Address>()?, client.clone());
+
+    let quote_params = QuoteExactSingleParams {
+        zero_for_one: token_in == pool_key.currency_0,
+        pool_key,
+        exact_amount: u128::try_from(amount_in)
+            .map_err(|_| anyhow!("Amount {} too large for a V4 swap", amount_in))?,
+        hook_data: Default::default(),
+    };
+    let (amount_out, _) = quoter.quote_exact_input_single(quote_params).call().await?;
+
+    Ok(amount_out_adjusted_for_slippage(amount_out, slippage))
+}
+
 pub fn amount_out_adjusted_for_slippage(base_amount: U256, slippage: TxSlippage) -> U256 {
     // Adjust the base output amount based on the provided slippage tolerance.
     match slippage {
@@ -136,29 +286,6 @@ pub fn amount_out_adjusted_for_slippage(base_amount: U256, slippage: TxSlippage)
     }
 }
 
-/// Returns a wallet instance using the private key specified in the environment variable
-/// `PRIVATE_KEY`.
-///
-/// # Errors
-/// Fails if the environment variable `PRIVATE_KEY` is not set or if the key parsing fails.
-pub fn get_wallet(chain: &Chain) -> anyhow::Result<Wallet<SigningKey>> {
-    // Retrieve PRIVATE_KEY from environment, providing context on failure.
-    let private_key = env::var("PRIVATE_KEY").context("PRIVATE_KEY not found in .env file")?;
-    let wallet = LocalWallet::from_str(&private_key)?.with_chain_id(*chain as u64);
-    Ok(wallet)
-}
-
-/// Returns a test wallet instance using the private key specified in `PRIVATE_KEY_2`.
-///
-/// # Errors
-/// Fails if the environment variable `PRIVATE_KEY_2` is not set or if the key parsing fails.
-pub fn get_test_wallet(chain: &Chain) -> anyhow::Result<Wallet<SigningKey>> {
-    // Retrieve PRIVATE_KEY_2 from environment, providing context on failure.
-    let private_key = env::var("PRIVATE_KEY_2").context("PRIVATE_KEY_2 not found in .env file")?;
-    let wallet = LocalWallet::from_str(&private_key)?.with_chain_id(*chain as u64);
-    Ok(wallet)
-}
-
 /// Retrieves the latest block from the given provider along with its block number.
 ///
 /// # Parameters
@@ -268,20 +395,176 @@ pub fn amount_of_token_to_purchase() -> anyhow::Result<U256> {
     Ok(amount_in)
 }
 
-/// Retrieves the token purchase amount for testing purposes from `TOKEN_TO_BUY_FOR_ANVIL_TEST`.
+/// Converts a USD amount into wei, given an ETH / USD price as reported by a Chainlink feed.
+///
+/// # Arguments
+///
+/// * `amount_usd` - The amount in USD, rounded to the cent.
+/// * `eth_usd_price` - The price of 1 ETH in USD, scaled by `10^price_decimals`.
+/// * `price_decimals` - The number of decimals the price is reported with.
+///
+/// # Returns
+///
+/// * `Result<U256>` - The amount of ETH in wei, or an error if the amount or price is not positive.
+pub fn usd_to_wei(amount_usd: f64, eth_usd_price: U256, price_decimals: u8) -> Result<U256> {
+    if amount_usd <= 0.0 || eth_usd_price.is_zero() {
+        return Err(anyhow!(
+            "Cannot convert ${} at an ETH price of {}",
+            amount_usd,
+            eth_usd_price
+        ));
+    }
+
+    // Work in cents to stay in integer arithmetic.
+    let amount_cents = U256::from((amount_usd * 100.0).round() as u64);
+    let wei = amount_cents * U256::exp10(price_decimals as usize) * U256::exp10(18)
+        / (eth_usd_price * U256::from(100));
+    Ok(wei)
+}
+
+/// Returns the amount of ETH to spend on a simulated purchase worth `amount_usd`.
+///
+/// The ETH / USD price is read from the chain's Chainlink feed through `client`, so on a fork it is
+/// the price at the forked block.
+///
+/// # Arguments
+///
+/// * `amount_usd` - The trade size in USD.
+/// * `chain` - The chain the simulation runs on.
+/// * `client` - The provider used to read the price feed.
 ///
 /// # Returns
-/// The test purchase amount as a `U256` value in wei.
 ///
-/// # Errors
-/// Fails if the environment variable is not set or if the value cannot be parsed.
-pub fn test_amount_of_token_to_purchase() -> anyhow::Result<U256> {
-    // Retrieve the amount string from the environment with inline error context.
-    let amount_to_buy = env::var("TOKEN_TO_BUY_FOR_ANVIL_TEST")
-        .context("TOKEN_TO_BUY_FOR_ANVIL_TEST is not set in .env")?;
-    let amount_in = ethers::utils::parse_ether(amount_to_buy)?;
-    // The following commented out code can be used for additional logging if necessary.
-    let purchase_amount = format_units(amount_in, "ether")?;
-    println!("buying {} of token", purchase_amount);
+/// * `Result<U256>` - The amount of ETH in wei.
+pub async fn eth_amount_for_usd(
+    amount_usd: f64,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> Result<U256> {
+    let feed_address: Address = get_chainlink_price_feed_for_token_("ETH", chain)
+        .await?
+        .parse()?;
+    let price_feed = CHAINLINK_AGGREGATOR::new(feed_address, client.clone());
+
+    let (_, answer, _, _, _) = price_feed.latest_round_data().call().await?;
+    if answer <= I256::zero() {
+        return Err(anyhow!("Invalid ETH / USD price {} from Chainlink", answer));
+    }
+    let price_decimals = price_feed.decimals().call().await?;
+
+    let amount_in = usd_to_wei(amount_usd, answer.into_raw(), price_decimals)?;
+    println!(
+        "buying ${} of token ({} ETH)",
+        amount_usd,
+        format_units(amount_in, "ether")?
+    );
     Ok(amount_in)
 }
+
+/// A USD price read from a Chainlink feed.
+///
+/// # Fields
+///
+/// * `price` - The price in USD.
+/// * `updated_at` - Timestamp of the feed's latest round.
+/// * `age_seconds` - How long before the latest block the round was updated.
+/// * `heartbeat_seconds` - The feed's heartbeat, the longest it goes without a new round.
+/// * `is_stale` - Whether the round is older than the heartbeat plus
+///   [`CHAINLINK_STALENESS_GRACE_SECONDS`], meaning the feed stopped updating.
+#[derive(Clone, Debug, Default)]
+pub struct ChainlinkPrice {
+    pub price: f64,
+    pub updated_at: u64,
+    pub age_seconds: u64,
+    pub heartbeat_seconds: u64,
+    pub is_stale: bool,
+}
+
+/// Reads the USD price of a token from its Chainlink USD feed, and checks the feed's latest round
+/// against its heartbeat. Wrapped ETH is priced with the ETH feed.
+///
+/// The round's age is measured against the latest block rather than the system clock, so on a fork
+/// it is the age at the forked block.
+///
+/// # Arguments
+///
+/// * `token_symbol` - The token's symbol, e.g. `WETH` or `USDC`.
+/// * `chain` - The chain the token is on.
+/// * `client` - The provider used to read the price feed.
+///
+/// # Returns
+///
+/// * `Result<ChainlinkPrice>` - The price and how fresh it is, or an error if there is no feed for
+///   the token or its answer is invalid.
+pub async fn get_chainlink_price_usd(
+    token_symbol: &str,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> Result<ChainlinkPrice> {
+    let feed_symbol = match token_symbol {
+        "WETH" => "ETH",
+        symbol => symbol,
+    };
+    let feed_address: Address = get_chainlink_price_feed_for_token_(feed_symbol, chain)
+        .await?
+        .parse()?;
+    let price_feed = CHAINLINK_AGGREGATOR::new(feed_address, client.clone());
+
+    let (_, answer, _, updated_at, _) = price_feed.latest_round_data().call().await?;
+    if answer <= I256::zero() {
+        return Err(anyhow!(
+            "Invalid {} / USD price {} from Chainlink",
+            feed_symbol,
+            answer
+        ));
+    }
+    let price_decimals = price_feed.decimals().call().await?;
+    let price = format_units(answer.into_raw(), price_decimals as u32)?.parse::<f64>()?;
+
+    let latest_block = client
+        .get_block(BlockNumber::Latest)
+        .await?
+        .ok_or_else(|| anyhow!("Could not retrieve the latest block"))?;
+    let updated_at = updated_at.as_u64();
+    let age_seconds = latest_block.timestamp.as_u64().saturating_sub(updated_at);
+    let heartbeat_seconds = get_chainlink_heartbeat_seconds(feed_symbol, chain);
+
+    Ok(ChainlinkPrice {
+        price,
+        updated_at,
+        age_seconds,
+        heartbeat_seconds,
+        is_stale: age_seconds > heartbeat_seconds + CHAINLINK_STALENESS_GRACE_SECONDS,
+    })
+}
+
+/// Reads the USD price of a token from its Chainlink USD feed, as described in
+/// [`get_chainlink_price_usd`], refusing prices the feed has stopped updating.
+///
+/// # Arguments
+///
+/// * `token_symbol` - The token's symbol, e.g. `WETH` or `USDC`.
+/// * `chain` - The chain the token is on.
+/// * `client` - The provider used to read the price feed.
+///
+/// # Returns
+///
+/// * `Result<f64>` - The price in USD, or an error if there is no feed for the token or its price is
+///   stale.
+pub async fn get_token_price_usd(
+    token_symbol: &str,
+    chain: &Chain,
+    client: &Arc<Provider<Ws>>,
+) -> Result<f64> {
+    let price = get_chainlink_price_usd(token_symbol, chain, client).await?;
+    if price.is_stale {
+        return Err(anyhow!(
+            "Stale {} / USD price from Chainlink: last updated {}s ago, heartbeat {}s",
+            token_symbol,
+            price.age_seconds,
+            price.heartbeat_seconds
+        ));
+    }
+
+    Ok(price.price)
+}
@@ -0,0 +1,80 @@
+use ethers::types::{Address, Chain};
+use tokencheck_backend::data::address_labels::{
+    AddressCategory, AddressLabelRegistry, ADDRESS_LABELS,
+};
+
+const LABELS: &str = r#"
+[[mainnet]]
+address = "0x000000000000000000000000000000000000dEaD"
+name = "Dead address"
+category = "burn"
+
+[[mainnet]]
+address = "0x28C6c06298d514Db089934071355E5743bf21d60"
+name = "Binance 14"
+category = "exchange"
+
+[[base]]
+address = "0xc4E637D37113192F4F1F060DaEbD7758De7F4131"
+name = "UNCX V2 locker"
+category = "locker"
+"#;
+
+#[test]
+fn test_labels_are_per_chain_and_case_insensitive() {
+    let registry = AddressLabelRegistry::from_toml_str(LABELS).unwrap();
+
+    let label = registry
+        .get_holder(&Chain::Base, "0xc4e637d37113192f4f1f060daebd7758de7f4131")
+        .unwrap();
+    assert_eq!(label.name, "UNCX V2 locker");
+    assert_eq!(label.category, AddressCategory::Locker);
+    assert!(
+        registry.is_burned_or_locked(&Chain::Base, "0xc4e637d37113192f4f1f060daebd7758de7f4131")
+    );
+    assert!(!registry.is_burned_or_locked(
+        &Chain::Mainnet,
+        "0xc4e637d37113192f4f1f060daebd7758de7f4131"
+    ));
+}
+
+#[test]
+fn test_exchange_wallets_hold_for_others() {
+    let registry = AddressLabelRegistry::from_toml_str(LABELS).unwrap();
+
+    let binance = "0x28c6c06298d514db089934071355e5743bf21d60";
+    assert!(registry.holds_for_others(&Chain::Mainnet, binance));
+    assert!(!registry.is_burned_or_locked(&Chain::Mainnet, binance));
+    assert!(registry.is_burned_or_locked(
+        &Chain::Mainnet,
+        "0x000000000000000000000000000000000000dead"
+    ));
+    assert!(registry
+        .get(&Chain::Mainnet, Address::repeat_byte(1))
+        .is_none());
+    assert!(registry
+        .get_holder(&Chain::Mainnet, "not an address")
+        .is_none());
+}
+
+#[test]
+fn test_unknown_chain_is_rejected() {
+    let labels = r#"
+[[not_a_chain]]
+address = "0x000000000000000000000000000000000000dEaD"
+name = "Dead address"
+category = "burn"
+"#;
+
+    assert!(AddressLabelRegistry::from_toml_str(labels).is_err());
+}
+
+#[test]
+fn test_address_labels_file_loads() {
+    for chain in [Chain::Mainnet, Chain::Base] {
+        assert!(ADDRESS_LABELS
+            .is_burned_or_locked(&chain, "0x000000000000000000000000000000000000dead"));
+        assert!(ADDRESS_LABELS
+            .is_burned_or_locked(&chain, "0x0000000000000000000000000000000000000000"));
+    }
+}
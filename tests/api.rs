@@ -1,7 +1,10 @@
 use dotenv::dotenv;
 use ethers::types::Chain;
+use tokencheck_backend::token_check::contract_source::{
+    parse_source_code, ContractSource, SourceFormat,
+};
 use tokencheck_backend::token_check::external_api::etherscan_api::{
-    get_source_code, EtherscanResponse, TokenInfo,
+    get_contract_source, get_source_code, EtherscanResponse, TokenInfo,
 };
 
 // TEST ON BASE
@@ -77,3 +80,89 @@ async fn test_parse() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_parse_source_code_formats() -> anyhow::Result<()> {
+    // single file
+    let single_file = "pragma solidity ^0.8.0;\ncontract Token {}";
+    let (format, files) = parse_source_code(single_file, "Token")?;
+    assert_eq!(format, SourceFormat::SingleFile);
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path, "Token.sol");
+    assert_eq!(files[0].content, single_file);
+
+    // multi file
+    let multi_file = r#"{
+        "contracts/Token.sol": { "content": "import \"./Ownable.sol\";\ncontract Token is Ownable {}" },
+        "contracts/Ownable.sol": { "content": "contract Ownable {}" }
+    }"#;
+    let (format, files) = parse_source_code(multi_file, "Token")?;
+    assert_eq!(format, SourceFormat::MultiFile);
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].path, "contracts/Ownable.sol");
+
+    // standard json, wrapped in an extra pair of braces
+    let standard_json = r#"{{
+        "language": "Solidity",
+        "sources": {
+            "contracts/Token.sol": { "content": "contract Token {}" }
+        },
+        "settings": { "optimizer": { "enabled": true, "runs": 200 } }
+    }}"#;
+    let (format, files) = parse_source_code(standard_json, "Token")?;
+    assert_eq!(format, SourceFormat::StandardJson);
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].content, "contract Token {}");
+
+    // unverified
+    let (format, files) = parse_source_code("", "")?;
+    assert_eq!(format, SourceFormat::Unverified);
+    assert!(files.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_combined_source_puts_main_contract_first() -> anyhow::Result<()> {
+    let multi_file = r#"{
+        "contracts/Token.sol": { "content": "contract Token is Ownable {}" },
+        "contracts/Ownable.sol": { "content": "contract Ownable {}" }
+    }"#;
+    let (format, files) = parse_source_code(multi_file, "Token")?;
+
+    let contract_source = ContractSource {
+        contract_name: "Token".to_string(),
+        format,
+        files,
+        ..Default::default()
+    };
+
+    assert!(contract_source
+        .combined_source()
+        .starts_with("// File: contracts/Token.sol"));
+
+    Ok(())
+}
+
+// TEST ON BASE
+#[tokio::test]
+#[ignore]
+async fn test_etherscan_contract_source() -> anyhow::Result<()> {
+    dotenv().ok();
+    const VIRTUALS: &str = "0x0b3e328455c4059EEb9e3f84b5543F74E24e7E1b";
+
+    let contract_source = get_contract_source(VIRTUALS, &Chain::Base).await?;
+    for file in contract_source.files.iter() {
+        println!("file => {}", file.path);
+    }
+    println!(
+        "compiler => {}, optimizer => {} ({} runs)",
+        contract_source.compiler_version,
+        contract_source.optimization_used,
+        contract_source.optimizer_runs
+    );
+
+    assert!(contract_source.is_verified());
+
+    Ok(())
+}
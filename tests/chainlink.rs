@@ -0,0 +1,17 @@
+use ethers::types::Chain;
+use tokencheck_backend::chainlink::chainlink_feed_map::{
+    get_chainlink_heartbeat_seconds, CHAINLINK_DEFAULT_HEARTBEAT_SECONDS,
+};
+
+#[test]
+fn test_chainlink_heartbeats() {
+    assert_eq!(
+        get_chainlink_heartbeat_seconds("ETH", &Chain::Mainnet),
+        3_600
+    );
+    assert_eq!(get_chainlink_heartbeat_seconds("eth", &Chain::Base), 1_200);
+    assert_eq!(
+        get_chainlink_heartbeat_seconds("USDC", &Chain::Mainnet),
+        CHAINLINK_DEFAULT_HEARTBEAT_SECONDS
+    );
+}
@@ -1,7 +1,7 @@
 use anyhow::anyhow;
 use dotenv::dotenv;
 use log::info;
-use tokencheck_backend::app_config::AI_MODEL;
+use tokencheck_backend::app_config::{AI_MODEL, SIMULATION_TRADE_SIZE_USD};
 use tokencheck_backend::data::provider_manager::get_chain_provider;
 use tokencheck_backend::data::token_checklist_cache::get_token_checklist_from_cache;
 use tokencheck_backend::data::token_data::get_core_token_data_by_address;
@@ -31,7 +31,9 @@ async fn test_checklist_is_generated_with_link() -> anyhow::Result<()> {
         None => {
             if let Some(token_data) = get_core_token_data_by_address(link).await? {
                 let client = get_chain_provider(&token_data.chain).await?;
-                let checklist = generate_token_checklist(&token_data, &client).await?;
+                let checklist =
+                    generate_token_checklist(&token_data, &client, SIMULATION_TRADE_SIZE_USD)
+                        .await?;
                 checklist
             } else {
                 return Err(anyhow!(
@@ -58,7 +60,9 @@ async fn test_checklist_and_token_score_generation_with_link() -> anyhow::Result
         None => {
             if let Some(token_data) = get_core_token_data_by_address(&link).await? {
                 let client = get_chain_provider(&token_data.chain).await?;
-                let checklist = generate_token_checklist(&token_data, &client).await?;
+                let checklist =
+                    generate_token_checklist(&token_data, &client, SIMULATION_TRADE_SIZE_USD)
+                        .await?;
                 checklist
             } else {
                 return Err(anyhow!(
@@ -110,7 +114,9 @@ async fn test_checklist_and_token_score_generation() -> anyhow::Result<()> {
             None => {
                 if let Some(token_data) = get_core_token_data_by_address(&token).await? {
                     let client = get_chain_provider(&token_data.chain).await?;
-                    let checklist = generate_token_checklist(&token_data, &client).await?;
+                    let checklist =
+                        generate_token_checklist(&token_data, &client, SIMULATION_TRADE_SIZE_USD)
+                            .await?;
                     checklist
                 } else {
                     return Err(anyhow!(
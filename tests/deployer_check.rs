@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use tokencheck_backend::token_check::token_checklist::TokenCheckList;
+use tokencheck_backend::token_check::token_deployer_check::find_previous_scams;
+use tokencheck_backend::token_check::token_score::TokenScoreAssessment;
+
+const SCAM_TOKEN: &str = "0x1111111111111111111111111111111111111111";
+const LEGIT_TOKEN: &str = "0x2222222222222222222222222222222222222222";
+const HONEYPOT_TOKEN: &str = "0x3333333333333333333333333333333333333333";
+const UNAUDITED_TOKEN: &str = "0x4444444444444444444444444444444444444444";
+
+#[test]
+fn test_previous_scams_are_joined_against_stored_audits() {
+    let mut token_scores = HashMap::new();
+    token_scores.insert(
+        SCAM_TOKEN.to_string(),
+        TokenScoreAssessment {
+            token_score: "0 - Scam".to_string(),
+            reason: String::new(),
+        },
+    );
+    token_scores.insert(
+        LEGIT_TOKEN.to_string(),
+        TokenScoreAssessment {
+            token_score: "4 - Legit".to_string(),
+            reason: String::new(),
+        },
+    );
+
+    // no stored score, falls back to the rules based score of the stored checklist
+    let mut token_checklists = HashMap::new();
+    token_checklists.insert(
+        HONEYPOT_TOKEN.to_string(),
+        TokenCheckList {
+            is_token_sellable: Some(false),
+            ..Default::default()
+        },
+    );
+
+    let previous_contracts = vec![
+        SCAM_TOKEN.to_string(),
+        LEGIT_TOKEN.to_string(),
+        HONEYPOT_TOKEN.to_string(),
+        UNAUDITED_TOKEN.to_string(),
+    ];
+
+    let (previous_tokens_audited, previous_scam_addresses) =
+        find_previous_scams(&previous_contracts, &token_checklists, &token_scores);
+
+    assert_eq!(previous_tokens_audited, 3);
+    assert_eq!(
+        previous_scam_addresses,
+        vec![SCAM_TOKEN.to_string(), HONEYPOT_TOKEN.to_string()]
+    );
+}
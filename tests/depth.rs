@@ -0,0 +1,46 @@
+use tokencheck_backend::token_check::token_depth_check::{
+    price_impact_percentage, DepthLevel, TokenDepthCheck,
+};
+
+fn level(trade_size_usd: f64, sell_price_impact_percentage: Option<f64>) -> DepthLevel {
+    DepthLevel {
+        trade_size_usd,
+        buy_price_impact_percentage: sell_price_impact_percentage,
+        sell_price_impact_percentage,
+    }
+}
+
+#[test]
+fn test_price_impact_is_measured_against_the_reference_rate() {
+    // The reference trade gets 2 out per 1 in, the trade 1.5 out per 1 in.
+    assert_eq!(price_impact_percentage(1.0, 2.0, 100.0, 150.0), 25.0);
+    // A trade filled at the reference rate has no impact.
+    assert_eq!(price_impact_percentage(1.0, 2.0, 100.0, 200.0), 0.0);
+    // A trade that returns nothing loses everything.
+    assert_eq!(price_impact_percentage(1.0, 2.0, 100.0, 0.0), 100.0);
+}
+
+#[test]
+fn test_uniswap_v2_price_impact() {
+    // Constant product pair of 100 / 100 with a 0.3% fee: out = in * 997 * r / (r * 1000 + in * 997).
+    let amount_out =
+        |amount_in: f64| amount_in * 997.0 * 100.0 / (100.0 * 1000.0 + amount_in * 997.0);
+    let impact = price_impact_percentage(0.001, amount_out(0.001), 10.0, amount_out(10.0));
+
+    // Swapping 10% of the reserve moves the price by about 9%.
+    assert!((impact - 9.07).abs() < 0.01, "impact was {}", impact);
+}
+
+#[test]
+fn test_max_exit_size_is_the_largest_size_sold_below_the_threshold() {
+    let check = TokenDepthCheck::from_levels(vec![
+        level(100.0, Some(0.5)),
+        level(1_000.0, Some(4.0)),
+        level(10_000.0, Some(35.0)),
+        level(100_000.0, None),
+    ]);
+    assert_eq!(check.max_exit_size_usd, Some(1_000.0));
+
+    let check = TokenDepthCheck::from_levels(vec![level(100.0, Some(60.0)), level(1_000.0, None)]);
+    assert_eq!(check.max_exit_size_usd, None);
+}
@@ -0,0 +1,106 @@
+use ethers::types::{Address, Chain, U256};
+use tokencheck_backend::token_check::anvil::fee_receivers::fee_receivers_from_transfers;
+use tokencheck_backend::token_check::anvil::trace_report::TokenTransfer;
+
+fn transfer(token: Address, from: Address, to: Address, value: u64) -> TokenTransfer {
+    TokenTransfer {
+        token,
+        from,
+        to,
+        value: U256::from(value),
+    }
+}
+
+#[test]
+fn test_fee_receivers_from_transfers() {
+    let token = Address::repeat_byte(0x01);
+    let pair = Address::repeat_byte(0x02);
+    let wallet = Address::repeat_byte(0x03);
+    let marketing = Address::repeat_byte(0x04);
+    let owner = Address::repeat_byte(0x05);
+    let base_token = Address::repeat_byte(0x06);
+
+    let transfers = vec![
+        // buy: 5% to the token contract
+        transfer(token, pair, token, 50),
+        transfer(token, pair, wallet, 950),
+        // the base token paid for it is not a fee
+        transfer(base_token, wallet, pair, 1_000),
+        // sell: 2% to a marketing wallet, 3% to the owner
+        transfer(token, wallet, marketing, 20),
+        transfer(token, wallet, owner, 30),
+        transfer(token, wallet, pair, 950),
+        // the token contract swapping its fees is not a receiver
+        transfer(token, token, pair, 50),
+        // burns are not fees
+        transfer(token, token, Address::zero(), 1),
+    ];
+
+    let fee_receivers = fee_receivers_from_transfers(
+        token,
+        &Chain::Mainnet,
+        &transfers,
+        &[pair, wallet],
+        &[owner],
+    );
+
+    assert_eq!(fee_receivers.len(), 3);
+
+    // largest first
+    assert_eq!(fee_receivers[0].address, token);
+    assert!(fee_receivers[0].is_token_contract);
+    assert!(!fee_receivers[0].is_owner_related);
+    assert_eq!(fee_receivers[0].amount_received, U256::from(50));
+    assert_eq!(fee_receivers[0].share_percentage, Some(2.5));
+
+    assert_eq!(fee_receivers[1].address, owner);
+    assert!(fee_receivers[1].is_owner_related);
+    assert_eq!(fee_receivers[1].share_percentage, Some(1.5));
+
+    assert_eq!(fee_receivers[2].address, marketing);
+    assert!(!fee_receivers[2].is_owner_related);
+    assert_eq!(fee_receivers[2].share_percentage, Some(1.0));
+}
+
+#[test]
+fn test_no_fee_receivers() {
+    let token = Address::repeat_byte(0x01);
+    let pair = Address::repeat_byte(0x02);
+    let wallet = Address::repeat_byte(0x03);
+
+    let transfers = vec![
+        transfer(token, pair, wallet, 1_000),
+        transfer(token, wallet, pair, 1_000),
+    ];
+
+    assert!(
+        fee_receivers_from_transfers(token, &Chain::Mainnet, &transfers, &[pair, wallet], &[])
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_burns_to_dead_address_are_not_fees() {
+    let token = Address::repeat_byte(0x01);
+    let pair = Address::repeat_byte(0x02);
+    let wallet = Address::repeat_byte(0x03);
+    let dead: Address = "0x000000000000000000000000000000000000dEaD"
+        .parse()
+        .unwrap();
+
+    let transfers = vec![
+        // buy: 1% burned to the dead address
+        transfer(token, pair, dead, 10),
+        transfer(token, pair, wallet, 990),
+        transfer(token, wallet, pair, 990),
+    ];
+
+    assert!(fee_receivers_from_transfers(
+        token,
+        &Chain::Mainnet,
+        &transfers,
+        &[pair, wallet],
+        &[dead]
+    )
+    .is_empty());
+}
@@ -3,6 +3,10 @@ use ethers::{abi::Address, types::Chain};
 use tokencheck_backend::{
     dex::{
         dex_data::find_top_dex_for_token,
+        onchain::{
+            uniswap_v2::get_top_uniswap_v2_pair_by_token_and_chain_onchain,
+            uniswap_v3::get_top_uniswap_v3_pool_by_token_and_chain_onchain,
+        },
         thegraph::{
             shared::SubGraph, uniswap_v2::get_top_uniswap_v2_pair_for_token,
             uniswap_v3::get_top_uniswap_v3_pool_for_token,
@@ -45,6 +49,37 @@ async fn test_top_pool() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[ignore]
+async fn test_top_pool_onchain_without_thegraph() -> anyhow::Result<()> {
+    dotenv().ok();
+
+    for token in WHITELIST_TOKENS_MAINNET {
+        let token_address: Address = token.parse()?;
+        let v2_pair =
+            get_top_uniswap_v2_pair_by_token_and_chain_onchain(token_address, &Chain::Mainnet)
+                .await?;
+        let v3_pool =
+            get_top_uniswap_v3_pool_by_token_and_chain_onchain(token_address, &Chain::Mainnet)
+                .await?;
+        assert!(
+            v2_pair.is_some() || v3_pool.is_some(),
+            "no pool found on-chain for {}",
+            token
+        );
+
+        for pool in v2_pair.iter().chain(v3_pool.iter()) {
+            assert!(pool.token_0 == token_address || pool.token_1 == token_address);
+            println!(
+                "{:?} Pool: {:?} (Fee: {}) TVL: ${}",
+                pool.dex, pool.pair_address, pool.fee, pool.liquidity_in_usd
+            );
+        }
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 #[ignore]
 async fn test_link_uniswap_v3_top_pool() -> anyhow::Result<()> {
@@ -0,0 +1,72 @@
+use ethers::types::{Address, U256};
+use tokencheck_backend::token_check::token_holder_check::{HolderBalance, HolderDistribution};
+
+fn balance(byte: u8, quantity: u64, is_contract: bool) -> HolderBalance {
+    HolderBalance {
+        address: Address::repeat_byte(byte),
+        quantity: U256::from(quantity),
+        is_contract: Some(is_contract),
+    }
+}
+
+#[test]
+fn test_equal_balances_are_not_concentrated() {
+    let balances = (1..=20).map(|byte| balance(byte, 10, false)).collect();
+
+    let distribution = HolderDistribution::from_balances(balances, U256::from(1_000));
+
+    assert!((distribution.top_10_percentage - 10.0).abs() < 1e-9);
+    assert!((distribution.top_50_percentage - 20.0).abs() < 1e-9);
+    assert!(distribution.gini_coefficient.abs() < 1e-9);
+    assert!((distribution.herfindahl_index - 20.0).abs() < 1e-9);
+    assert!((distribution.eoas_percentage - 20.0).abs() < 1e-9);
+    assert_eq!(distribution.contracts_percentage, 0.0);
+}
+
+#[test]
+fn test_whale_dominates_distribution() {
+    let balances = vec![
+        balance(1, 10, false),
+        balance(2, 600, true),
+        balance(3, 10, false),
+        balance(4, 10, false),
+    ];
+
+    let distribution = HolderDistribution::from_balances(balances, U256::from(1_000));
+
+    assert!((distribution.top_10_percentage - 63.0).abs() < 1e-9);
+    assert!((distribution.herfindahl_index - 3_603.0).abs() < 1e-9);
+    assert!(distribution.gini_coefficient > 0.7);
+    assert!((distribution.contracts_percentage - 60.0).abs() < 1e-9);
+    assert!((distribution.eoas_percentage - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_holders_of_unknown_code_are_neither_contracts_nor_eoas() {
+    let balances = vec![
+        balance(1, 100, false),
+        balance(2, 200, true),
+        HolderBalance {
+            address: Address::repeat_byte(3),
+            quantity: U256::from(300),
+            is_contract: None,
+        },
+    ];
+
+    let distribution = HolderDistribution::from_balances(balances, U256::from(1_000));
+
+    assert!((distribution.top_10_percentage - 60.0).abs() < 1e-9);
+    assert!((distribution.contracts_percentage - 20.0).abs() < 1e-9);
+    assert!((distribution.eoas_percentage - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_no_balances_or_supply() {
+    let distribution = HolderDistribution::from_balances(Vec::new(), U256::from(1_000));
+    assert_eq!(distribution.top_10_percentage, 0.0);
+    assert_eq!(distribution.gini_coefficient, 0.0);
+
+    let distribution = HolderDistribution::from_balances(vec![balance(1, 10, false)], U256::zero());
+    assert_eq!(distribution.top_10_percentage, 0.0);
+    assert_eq!(distribution.holder_count, None);
+}
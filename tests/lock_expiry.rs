@@ -0,0 +1,61 @@
+use ethers::types::U256;
+use tokencheck_backend::token_check::token_lock_expiry_check::{LpLock, TokenLockExpiryCheck};
+
+const NOW: u64 = 1_700_000_000;
+const DAY: u64 = 86_400;
+
+fn lock(amount: u64, unlock_timestamp: u64) -> LpLock {
+    LpLock {
+        locker: "UNCX".to_string(),
+        amount: U256::from(amount),
+        unlock_timestamp,
+    }
+}
+
+#[test]
+fn test_locked_percentage_by_horizon() {
+    let check = TokenLockExpiryCheck::from_locks(
+        vec![lock(500, NOW + 400 * DAY), lock(300, NOW + 10 * DAY)],
+        U256::from(1_000),
+        NOW,
+    );
+
+    assert_eq!(check.percentage_locked, 80.0);
+    let horizons: Vec<(f64, f64)> = check
+        .percentage_locked_by_horizon
+        .iter()
+        .map(|horizon| (horizon.horizon_days, horizon.percentage_locked))
+        .collect();
+    assert_eq!(
+        horizons,
+        vec![(30.0, 50.0), (90.0, 50.0), (180.0, 50.0), (365.0, 50.0)]
+    );
+    assert_eq!(check.earliest_unlock_timestamp, Some(NOW + 10 * DAY));
+    assert_eq!(check.days_until_earliest_unlock, Some(10.0));
+}
+
+#[test]
+fn test_expired_lock_is_not_locked() {
+    let check = TokenLockExpiryCheck::from_locks(
+        vec![lock(900, NOW - DAY), lock(0, NOW + 400 * DAY)],
+        U256::from(1_000),
+        NOW,
+    );
+
+    assert_eq!(check.locks.len(), 1);
+    assert_eq!(check.percentage_locked, 0.0);
+    assert_eq!(check.earliest_unlock_timestamp, Some(NOW - DAY));
+    assert_eq!(check.days_until_earliest_unlock, Some(0.0));
+}
+
+#[test]
+fn test_no_locks() {
+    let check = TokenLockExpiryCheck::from_locks(Vec::new(), U256::from(1_000), NOW);
+
+    assert_eq!(check.percentage_locked, 0.0);
+    assert!(check
+        .percentage_locked_by_horizon
+        .iter()
+        .all(|horizon| horizon.percentage_locked == 0.0));
+    assert_eq!(check.earliest_unlock_timestamp, None);
+}
@@ -88,7 +88,13 @@ async fn get_holder_analysis() -> anyhow::Result<()> {
     //     None => panic!("Opps..could not unwrap!"),
     // };
 
-    let token_holder_analysis = get_token_holder_check(&info.token, &info.client)
+    let pool_addresses = info
+        .token
+        .token_dex
+        .iter()
+        .map(|token_dex| token_dex.pair_address)
+        .collect::<Vec<_>>();
+    let token_holder_analysis = get_token_holder_check(&info.token, &pool_addresses, &info.client)
         .await?
         .unwrap();
 
@@ -0,0 +1,137 @@
+use ethers::abi::{Abi, Token};
+use ethers::types::{Address, U256};
+use tokencheck_backend::token_check::anvil::owner_privileges::{
+    classify_privileged_function, privileged_call_arguments, PrivilegedAction,
+};
+
+const TOKEN_ABI: &str = r#"[
+    {"type":"function","name":"setFees","stateMutability":"nonpayable","inputs":[{"name":"buyFee","type":"uint256"},{"name":"sellFee","type":"uint256"}],"outputs":[]},
+    {"type":"function","name":"setBots","stateMutability":"nonpayable","inputs":[{"name":"bots","type":"address[]"},{"name":"isBot","type":"bool"}],"outputs":[]},
+    {"type":"function","name":"setTradingEnabled","stateMutability":"nonpayable","inputs":[{"name":"enabled","type":"bool"}],"outputs":[]},
+    {"type":"function","name":"pause","stateMutability":"nonpayable","inputs":[],"outputs":[]},
+    {"type":"function","name":"setMaxTxAmount","stateMutability":"nonpayable","inputs":[{"name":"amount","type":"uint256"}],"outputs":[]},
+    {"type":"function","name":"setFeeWallet","stateMutability":"nonpayable","inputs":[{"name":"wallet","type":"address"}],"outputs":[]},
+    {"type":"function","name":"unpause","stateMutability":"nonpayable","inputs":[],"outputs":[]},
+    {"type":"function","name":"excludeFromFee","stateMutability":"nonpayable","inputs":[{"name":"account","type":"address"}],"outputs":[]},
+    {"type":"function","name":"buyFee","stateMutability":"view","inputs":[],"outputs":[{"name":"","type":"uint256"}]},
+    {"type":"function","name":"setBankAccount","stateMutability":"nonpayable","inputs":[{"name":"account","type":"address"}],"outputs":[]},
+    {"type":"function","name":"setRobotRouter","stateMutability":"nonpayable","inputs":[{"name":"router","type":"address"}],"outputs":[]},
+    {"type":"function","name":"setPriceFeed","stateMutability":"nonpayable","inputs":[{"name":"feed","type":"uint256"}],"outputs":[]},
+    {"type":"function","name":"enableTrading","stateMutability":"nonpayable","inputs":[],"outputs":[]},
+    {"type":"function","name":"openTrading","stateMutability":"nonpayable","inputs":[],"outputs":[]},
+    {"type":"function","name":"disableTrading","stateMutability":"nonpayable","inputs":[],"outputs":[]},
+    {"type":"function","name":"blacklistAddress","stateMutability":"nonpayable","inputs":[{"name":"account","type":"address"}],"outputs":[]},
+    {"type":"function","name":"set_max_wallet","stateMutability":"nonpayable","inputs":[{"name":"amount","type":"uint256"}],"outputs":[]},
+    {"type":"function","name":"setMaxTXAmount","stateMutability":"nonpayable","inputs":[{"name":"amount","type":"uint256"}],"outputs":[]}
+]"#;
+
+#[test]
+fn test_classify_privileged_functions() -> anyhow::Result<()> {
+    let abi: Abi = serde_json::from_str(TOKEN_ABI)?;
+    let classify = |name: &str| classify_privileged_function(&abi.function(name).unwrap().clone());
+
+    assert_eq!(classify("setFees"), Some(PrivilegedAction::SetFees));
+    assert_eq!(classify("setBots"), Some(PrivilegedAction::Blacklist));
+    assert_eq!(
+        classify("setTradingEnabled"),
+        Some(PrivilegedAction::PauseTrading)
+    );
+    assert_eq!(classify("pause"), Some(PrivilegedAction::PauseTrading));
+    assert_eq!(
+        classify("setMaxTxAmount"),
+        Some(PrivilegedAction::LimitTransactions)
+    );
+
+    // fee receivers, functions lifting restrictions and view functions are not privileged actions
+    assert_eq!(classify("setFeeWallet"), None);
+    assert_eq!(classify("unpause"), None);
+    assert_eq!(classify("excludeFromFee"), None);
+    assert_eq!(classify("buyFee"), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_classify_privileged_functions_on_word_boundaries() -> anyhow::Result<()> {
+    let abi: Abi = serde_json::from_str(TOKEN_ABI)?;
+    let classify = |name: &str| classify_privileged_function(&abi.function(name).unwrap().clone());
+
+    // "ban", "bot" and "fee" inside longer words are not privileged actions
+    assert_eq!(classify("setBankAccount"), None);
+    assert_eq!(classify("setRobotRouter"), None);
+    assert_eq!(classify("setPriceFeed"), None);
+
+    // switches that can only turn trading on do not pause it
+    assert_eq!(classify("enableTrading"), None);
+    assert_eq!(classify("openTrading"), None);
+    assert_eq!(
+        classify("disableTrading"),
+        Some(PrivilegedAction::PauseTrading)
+    );
+
+    // camelCase, snake_case and acronyms are split into words
+    assert_eq!(
+        classify("blacklistAddress"),
+        Some(PrivilegedAction::Blacklist)
+    );
+    assert_eq!(
+        classify("set_max_wallet"),
+        Some(PrivilegedAction::LimitTransactions)
+    );
+    assert_eq!(
+        classify("setMaxTXAmount"),
+        Some(PrivilegedAction::LimitTransactions)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_privileged_call_arguments() -> anyhow::Result<()> {
+    let abi: Abi = serde_json::from_str(TOKEN_ABI)?;
+    let wallet = Address::random();
+
+    // blacklist our wallet
+    let set_bots = abi.function("setBots")?;
+    assert_eq!(
+        privileged_call_arguments(set_bots, &PrivilegedAction::Blacklist, wallet),
+        vec![vec![
+            Token::Array(vec![Token::Address(wallet)]),
+            Token::Bool(true)
+        ]]
+    );
+
+    // disable trading
+    let set_trading_enabled = abi.function("setTradingEnabled")?;
+    assert_eq!(
+        privileged_call_arguments(set_trading_enabled, &PrivilegedAction::PauseTrading, wallet),
+        vec![vec![Token::Bool(false)]]
+    );
+
+    // fees are tried from the highest value down
+    let set_fees = abi.function("setFees")?;
+    let fee_arguments = privileged_call_arguments(set_fees, &PrivilegedAction::SetFees, wallet);
+    assert_eq!(
+        fee_arguments.first(),
+        Some(&vec![
+            Token::Uint(U256::from(10_000)),
+            Token::Uint(U256::from(10_000))
+        ])
+    );
+
+    // max transaction is set to zero first
+    let set_max_tx_amount = abi.function("setMaxTxAmount")?;
+    assert_eq!(
+        privileged_call_arguments(
+            set_max_tx_amount,
+            &PrivilegedAction::LimitTransactions,
+            wallet
+        ),
+        vec![
+            vec![Token::Uint(U256::zero())],
+            vec![Token::Uint(U256::one())]
+        ]
+    );
+
+    Ok(())
+}
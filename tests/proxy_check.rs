@@ -0,0 +1,79 @@
+use dotenv::dotenv;
+use ethers::types::{Address, Bytes, Chain, H256};
+use ethers::utils::keccak256;
+use tokencheck_backend::data::provider_manager::get_chain_provider;
+use tokencheck_backend::data::token_data::ERC20Token;
+use tokencheck_backend::token_check::token_proxy_check::{
+    get_minimal_proxy_implementation, get_token_proxy_check, ProxyType, OPENZEPPELIN_ADMIN_SLOT,
+    OPENZEPPELIN_IMPLEMENTATION_SLOT,
+};
+
+/// USDC on mainnet, an EIP-1967 style upgradeable proxy.
+const USDC_MAINNET: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+/// LINK on mainnet, a regular (non proxy) token.
+const LINK_MAINNET: &str = "0x514910771af9ca656af840dff83e8264ecf986ca";
+
+#[test]
+fn test_minimal_proxy_implementation_is_read_from_bytecode() -> anyhow::Result<()> {
+    let implementation: Address = "0xbebebebebebebebebebebebebebebebebebebebe".parse()?;
+
+    // EIP-1167 runtime bytecode with the implementation address embedded after the prefix
+    let code: Bytes = format!(
+        "0x363d3d373d3d3d363d73{}5af43d82803e903d91602b57fd5bf3",
+        hex::encode(implementation.as_bytes())
+    )
+    .parse()?;
+
+    assert_eq!(
+        get_minimal_proxy_implementation(&code),
+        Some(implementation)
+    );
+
+    // regular contract bytecode is not a clone
+    let code: Bytes = "0x6080604052348015600f57600080fd5b50".parse()?;
+    assert_eq!(get_minimal_proxy_implementation(&code), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_legacy_openzeppelin_slots() -> anyhow::Result<()> {
+    assert_eq!(
+        OPENZEPPELIN_IMPLEMENTATION_SLOT.parse::<H256>()?,
+        H256::from(keccak256("org.zeppelinos.proxy.implementation"))
+    );
+    assert_eq!(
+        OPENZEPPELIN_ADMIN_SLOT.parse::<H256>()?,
+        H256::from(keccak256("org.zeppelinos.proxy.admin"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_proxy_check_mainnet() -> anyhow::Result<()> {
+    dotenv().ok();
+    let client = get_chain_provider(&Chain::Mainnet).await?;
+
+    let usdc = ERC20Token {
+        chain: Chain::Mainnet,
+        address: USDC_MAINNET.parse()?,
+        ..Default::default()
+    };
+    let proxy = get_token_proxy_check(&usdc, &client).await?;
+    println!("usdc proxy check => {:#?}", proxy);
+    assert!(proxy.is_proxy());
+    assert!(proxy.implementation_address.is_some());
+
+    let link = ERC20Token {
+        chain: Chain::Mainnet,
+        address: LINK_MAINNET.parse()?,
+        ..Default::default()
+    };
+    let proxy = get_token_proxy_check(&link, &client).await?;
+    println!("link proxy check => {:#?}", proxy);
+    assert_eq!(proxy.proxy_type, ProxyType::NotProxy);
+
+    Ok(())
+}
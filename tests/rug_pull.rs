@@ -0,0 +1,26 @@
+use ethers::types::U256;
+use tokencheck_backend::token_check::anvil::rug_pull::percentage_decrease;
+
+#[test]
+fn test_percentage_decrease() {
+    let before = U256::from(1_000_000_u64);
+
+    // all liquidity pulled
+    assert_eq!(percentage_decrease(before, U256::zero()), Some(100.0));
+
+    // a quarter pulled
+    assert_eq!(
+        percentage_decrease(before, U256::from(750_000_u64)),
+        Some(25.0)
+    );
+
+    // nothing pulled, or the amount went up
+    assert_eq!(percentage_decrease(before, before), Some(0.0));
+    assert_eq!(
+        percentage_decrease(before, U256::from(2_000_000_u64)),
+        Some(0.0)
+    );
+
+    // nothing to compare against
+    assert_eq!(percentage_decrease(U256::zero(), U256::zero()), None);
+}
@@ -0,0 +1,21 @@
+use tokencheck_backend::app_config::DEXES;
+use tokencheck_backend::data::dex::Dex;
+use tokencheck_backend::dex::swap_venue::swap_venue;
+
+#[test]
+fn test_enabled_dexes_have_a_swap_venue() {
+    for dex in DEXES {
+        assert!(swap_venue(&dex).is_some(), "{:?} has no swap venue", dex);
+    }
+}
+
+#[test]
+fn test_unsupported_dex_has_no_swap_venue() {
+    assert!(swap_venue(&Dex::Curve).is_none());
+}
+
+#[test]
+fn test_aerodrome_pools_have_a_swap_venue() {
+    assert!(swap_venue(&Dex::Aerodrome).is_some());
+    assert!(swap_venue(&Dex::AerodromeSlipstream).is_some());
+}
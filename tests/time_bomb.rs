@@ -0,0 +1,53 @@
+use tokencheck_backend::token_check::anvil::time_travel::{TimeBombCheck, TimeHorizonCheck};
+
+fn horizon(label: &str, seconds_after_purchase: u64, can_sell: bool) -> TimeHorizonCheck {
+    TimeHorizonCheck {
+        horizon: label.to_string(),
+        seconds_after_purchase,
+        balance_intact: true,
+        can_sell,
+        sell_tax: if can_sell { Some(1.0) } else { None },
+    }
+}
+
+#[test]
+fn test_time_bomb_detection() {
+    // sellable throughout
+    let check = TimeBombCheck::from_horizons(vec![
+        horizon("immediately", 0, true),
+        horizon("1 hour", 3_600, true),
+        horizon("1 day", 86_400, true),
+    ]);
+    assert_eq!(check.behaviour_changed_at, None);
+    assert!(!check.is_time_bomb());
+
+    // stops being sellable after a day
+    let check = TimeBombCheck::from_horizons(vec![
+        horizon("immediately", 0, true),
+        horizon("1 hour", 3_600, true),
+        horizon("1 day", 86_400, false),
+    ]);
+    assert_eq!(check.behaviour_changed_at, Some("1 day".to_string()));
+    assert!(check.is_time_bomb());
+
+    // sell tax jumps to honeypot levels after an hour
+    let mut taxed = horizon("1 hour", 3_600, true);
+    taxed.sell_tax = Some(99.0);
+    let check = TimeBombCheck::from_horizons(vec![horizon("immediately", 0, true), taxed]);
+    assert_eq!(check.behaviour_changed_at, Some("1 hour".to_string()));
+    assert!(check.is_time_bomb());
+
+    // balance drained over time
+    let mut drained = horizon("3 days", 259_200, true);
+    drained.balance_intact = false;
+    let check = TimeBombCheck::from_horizons(vec![horizon("immediately", 0, true), drained]);
+    assert!(check.is_time_bomb());
+
+    // sell cooldown, only sellable after 5 minutes
+    let check = TimeBombCheck::from_horizons(vec![
+        horizon("immediately", 0, false),
+        horizon("5 minutes", 300, true),
+    ]);
+    assert_eq!(check.behaviour_changed_at, Some("5 minutes".to_string()));
+    assert!(!check.is_time_bomb());
+}
@@ -4,7 +4,7 @@ use ethers::providers::{Provider, Ws};
 use ethers::types::{Address, Chain};
 use std::sync::Arc;
 use tokencheck_backend::abi::erc20::ERC20;
-use tokencheck_backend::app_config::AI_MODEL;
+use tokencheck_backend::app_config::{AI_MODEL, SIMULATION_TRADE_SIZE_USD};
 use tokencheck_backend::data::chain_data::CHAIN_DATA;
 use tokencheck_backend::data::token_data::ERC20Token;
 use tokencheck_backend::dex::dex_data::find_top_dex_for_token;
@@ -41,7 +41,8 @@ async fn test_generate_checklist_base() -> anyhow::Result<()> {
     const VIRTUALS: &str = "0x0b3e328455c4059eeb9e3f84b5543f74e24e7e1b";
     let data = setup(SCAM, &Chain::Base).await?;
 
-    let token_checklist = generate_token_checklist(&data.token, &data.client).await?;
+    let token_checklist =
+        generate_token_checklist(&data.token, &data.client, SIMULATION_TRADE_SIZE_USD).await?;
 
     println!("token checklist => {:#?}", token_checklist);
 
@@ -57,7 +58,8 @@ async fn test_generate_checklist_mainnet() -> anyhow::Result<()> {
     for token in WHITELIST_TOKENS_MAINNET {
         let data = setup(token, &Chain::Mainnet).await?;
 
-        let token_checklist = generate_token_checklist(&data.token, &data.client).await?;
+        let token_checklist =
+            generate_token_checklist(&data.token, &data.client, SIMULATION_TRADE_SIZE_USD).await?;
 
         println!("token checklist => {:#?}", token_checklist);
 
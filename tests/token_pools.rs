@@ -0,0 +1,78 @@
+use ethers::types::Address;
+use tokencheck_backend::data::dex::Dex;
+use tokencheck_backend::token_check::token_pool_check::{PoolLiquidity, TokenPoolCheck};
+
+fn pool(
+    byte: u8,
+    liquidity_in_usd: f64,
+    created_at_block: Option<u64>,
+    age_in_days: Option<f64>,
+) -> PoolLiquidity {
+    PoolLiquidity {
+        dex: Dex::UniswapV2,
+        pair_address: Address::repeat_byte(byte),
+        base_token_symbol: "WETH".to_string(),
+        liquidity_in_usd,
+        onchain_valuation_failed: false,
+        percentage_locked_or_burned: None,
+        created_at_block,
+        age_in_days,
+        is_drained: liquidity_in_usd < 100.0,
+    }
+}
+
+#[test]
+fn test_single_pool_is_not_fragmented() {
+    let check = TokenPoolCheck::from_pools(vec![pool(1, 50_000.0, Some(100), Some(30.0))]);
+
+    assert_eq!(check.total_liquidity_in_usd, 50_000.0);
+    assert_eq!(check.top_pool_share_percentage, 100.0);
+    assert_eq!(check.fragmentation, 0.0);
+    assert!(!check.top_pool_is_tiny_or_new);
+    assert!(!check.liquidity_moved_from_drained_pool());
+}
+
+#[test]
+fn test_liquidity_split_across_pools() {
+    let check = TokenPoolCheck::from_pools(vec![
+        pool(1, 25_000.0, Some(100), Some(30.0)),
+        pool(2, 75_000.0, Some(200), Some(20.0)),
+    ]);
+
+    assert_eq!(check.pools[0].pair_address, Address::repeat_byte(2));
+    assert_eq!(check.total_liquidity_in_usd, 100_000.0);
+    assert_eq!(check.top_pool_share_percentage, 75.0);
+    assert!((check.fragmentation - 0.375).abs() < 1e-9);
+}
+
+#[test]
+fn test_new_pool_after_older_pool_was_drained_is_flagged() {
+    let check = TokenPoolCheck::from_pools(vec![
+        pool(1, 10.0, Some(100), Some(30.0)),
+        pool(2, 5_000.0, Some(200), Some(0.1)),
+    ]);
+
+    assert!(check.top_pool_is_tiny_or_new);
+    assert_eq!(check.drained_older_pools, vec![Address::repeat_byte(1)]);
+    assert!(check.liquidity_moved_from_drained_pool());
+}
+
+#[test]
+fn test_drained_newer_pool_is_not_flagged() {
+    let check = TokenPoolCheck::from_pools(vec![
+        pool(1, 5_000.0, Some(100), Some(30.0)),
+        pool(2, 10.0, Some(200), Some(0.1)),
+    ]);
+
+    assert!(check.drained_older_pools.is_empty());
+    assert!(!check.liquidity_moved_from_drained_pool());
+}
+
+#[test]
+fn test_pools_without_liquidity() {
+    let check = TokenPoolCheck::from_pools(vec![pool(1, 0.0, None, None)]);
+
+    assert_eq!(check.pools.len(), 1);
+    assert_eq!(check.total_liquidity_in_usd, 0.0);
+    assert!(!check.liquidity_moved_from_drained_pool());
+}
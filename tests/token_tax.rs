@@ -0,0 +1,29 @@
+use ethers::types::U256;
+use tokencheck_backend::token_check::anvil::token_tax::{tax_percentage, SimulatedSwap};
+
+#[test]
+fn test_tax_percentage() {
+    // 49% sell tax
+    let swap = SimulatedSwap {
+        expected_amount_out: U256::from(1_000_000_u64),
+        amount_out: U256::from(510_000_u64),
+        token_balance: U256::zero(),
+        tx: None,
+    };
+    assert_eq!(swap.tax_percentage(), Some(49.0));
+
+    // no tax
+    assert_eq!(
+        tax_percentage(U256::from(1_000_u64), U256::from(1_000_u64)),
+        Some(0.0)
+    );
+
+    // receiving more than quoted (e.g. reflections) is not a negative tax
+    assert_eq!(
+        tax_percentage(U256::from(1_000_u64), U256::from(1_200_u64)),
+        Some(0.0)
+    );
+
+    // nothing quoted, tax cannot be determined
+    assert_eq!(tax_percentage(U256::zero(), U256::zero()), None);
+}
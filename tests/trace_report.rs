@@ -0,0 +1,79 @@
+use ethers::abi::{encode, Token};
+use ethers::types::{Address, Bytes, CallFrame, U256};
+use tokencheck_backend::token_check::anvil::trace_report::{decode_revert_reason, TraceReport};
+
+/// Encodes revert data: the 4 byte selector followed by the ABI encoded arguments.
+fn revert_data(selector: [u8; 4], arguments: &[Token]) -> Vec<u8> {
+    let mut data = selector.to_vec();
+    data.extend(encode(arguments));
+    data
+}
+
+#[test]
+fn test_decode_revert_reason() {
+    // Error(string)
+    let output = revert_data(
+        [0x08, 0xc3, 0x79, 0xa0],
+        &[Token::String("blacklisted".to_string())],
+    );
+    assert_eq!(decode_revert_reason(&output, None, None), "blacklisted");
+
+    // Panic(uint256), 0x11 is an arithmetic overflow
+    let output = revert_data([0x4e, 0x48, 0x7b, 0x71], &[Token::Uint(U256::from(0x11))]);
+    assert_eq!(decode_revert_reason(&output, None, None), "panic 0x11");
+
+    // no revert data falls back to the tracer's error
+    assert_eq!(
+        decode_revert_reason(&[], Some("execution reverted"), None),
+        "execution reverted"
+    );
+
+    // an unknown custom error keeps its selector
+    let reason = decode_revert_reason(&[0xde, 0xad, 0xbe, 0xef], None, None);
+    assert!(reason.contains("deadbeef"), "{}", reason);
+}
+
+#[test]
+fn test_trace_report_from_call_frame() {
+    let token = Address::repeat_byte(0x11);
+    let pair = Address::repeat_byte(0x22);
+
+    // transfer(address,uint256)
+    let mut input = vec![0xa9, 0x05, 0x9c, 0xbb];
+    input.extend(encode(&[
+        Token::Address(pair),
+        Token::Uint(U256::from(100)),
+    ]));
+
+    let revert = CallFrame {
+        typ: "CALL".to_string(),
+        to: Some(token.into()),
+        input: Bytes::from(input),
+        output: Some(Bytes::from(revert_data(
+            [0x08, 0xc3, 0x79, 0xa0],
+            &[Token::String("blacklisted".to_string())],
+        ))),
+        error: Some("execution reverted".to_string()),
+        ..Default::default()
+    };
+    let root = CallFrame {
+        typ: "CALL".to_string(),
+        to: Some(pair.into()),
+        error: Some("execution reverted".to_string()),
+        calls: Some(vec![revert]),
+        ..Default::default()
+    };
+
+    let trace_report = TraceReport::from_call_frame("sell", &root, None);
+    assert!(trace_report.reverted);
+    assert_eq!(trace_report.calls.len(), 2);
+    assert_eq!(trace_report.revert_reason.as_deref(), Some("blacklisted"));
+    assert!(trace_report
+        .revert_location
+        .as_deref()
+        .unwrap()
+        .starts_with("transfer(address,uint256)"));
+    assert!(trace_report
+        .summary()
+        .starts_with("sell reverted in transfer"));
+}
@@ -0,0 +1,34 @@
+use ethers::types::U256;
+use tokencheck_backend::token_check::anvil::trade_limits::is_sell_limit_far_below_buy_limit;
+
+#[test]
+fn test_sell_limit_far_below_buy_limit() {
+    let largest_buy = U256::from(1_000_000_u64);
+
+    // sells not limited
+    assert!(!is_sell_limit_far_below_buy_limit(largest_buy, None));
+
+    // can sell half of what can be bought
+    assert!(!is_sell_limit_far_below_buy_limit(
+        largest_buy,
+        Some(U256::from(500_000_u64))
+    ));
+
+    // can only sell 1% of what can be bought
+    assert!(is_sell_limit_far_below_buy_limit(
+        largest_buy,
+        Some(U256::from(10_000_u64))
+    ));
+
+    // cannot sell at all
+    assert!(is_sell_limit_far_below_buy_limit(
+        largest_buy,
+        Some(U256::zero())
+    ));
+
+    // nothing bought, cannot compare
+    assert!(!is_sell_limit_far_below_buy_limit(
+        U256::zero(),
+        Some(U256::zero())
+    ));
+}
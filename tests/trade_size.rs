@@ -0,0 +1,25 @@
+use ethers::types::U256;
+use ethers::utils::parse_ether;
+use tokencheck_backend::utils::tx::usd_to_wei;
+
+#[test]
+fn test_usd_to_wei() {
+    // ETH at $2,000.00, reported with 8 decimals like the Chainlink ETH / USD feeds
+    let eth_usd_price = U256::from(2_000_u64) * U256::exp10(8);
+
+    // $100 buys 0.05 ETH
+    assert_eq!(
+        usd_to_wei(100.0, eth_usd_price, 8).unwrap(),
+        parse_ether("0.05").unwrap()
+    );
+
+    // cents are kept
+    assert_eq!(
+        usd_to_wei(0.5, eth_usd_price, 8).unwrap(),
+        parse_ether("0.00025").unwrap()
+    );
+
+    // nothing to buy, or no price
+    assert!(usd_to_wei(0.0, eth_usd_price, 8).is_err());
+    assert!(usd_to_wei(100.0, U256::zero(), 8).is_err());
+}
@@ -0,0 +1,83 @@
+use ethers::types::{Address, U256};
+use tokencheck_backend::dex::onchain::shared::block_windows;
+use tokencheck_backend::dex::onchain::uniswap_v3_positions::{
+    in_range_position_holders, most_liquid_token_ids, UniswapV3Position,
+};
+
+fn position(
+    owner_byte: u8,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: u128,
+) -> UniswapV3Position {
+    UniswapV3Position {
+        token_id: U256::from(owner_byte),
+        owner: Address::repeat_byte(owner_byte),
+        tick_lower,
+        tick_upper,
+        liquidity,
+    }
+}
+
+#[test]
+fn test_position_range_includes_lower_tick_only() {
+    let position = position(1, -60, 60, 1_000);
+
+    assert!(position.is_in_range(-60));
+    assert!(position.is_in_range(0));
+    assert!(!position.is_in_range(60));
+    assert!(!position.is_in_range(-61));
+}
+
+#[test]
+fn test_only_in_range_positions_with_liquidity_are_holders() {
+    let positions = vec![
+        position(1, -600, 600, 1_000),
+        position(2, 600, 1_200, 5_000),
+        position(3, -600, 600, 0),
+        position(4, -120, 120, 3_000),
+    ];
+
+    let holders = in_range_position_holders(&positions, 10);
+
+    assert_eq!(holders.len(), 2);
+    assert_eq!(
+        holders[0].holder,
+        "0x0101010101010101010101010101010101010101"
+    );
+    assert_eq!(holders[0].quantity, U256::from(1_000));
+    assert_eq!(
+        holders[1].holder,
+        "0x0404040404040404040404040404040404040404"
+    );
+    assert_eq!(holders[1].quantity, U256::from(3_000));
+}
+
+#[test]
+fn test_block_windows_page_the_range() {
+    assert_eq!(
+        block_windows(100, 125, 10),
+        vec![(100, 109), (110, 119), (120, 125)]
+    );
+    assert_eq!(block_windows(100, 100, 10), vec![(100, 100)]);
+    assert!(block_windows(101, 100, 10).is_empty());
+}
+
+#[test]
+fn test_most_liquid_positions_are_kept_in_mint_order() {
+    let liquidity_added = vec![
+        (U256::from(1), U256::from(10)),
+        (U256::from(2), U256::from(500)),
+        (U256::from(3), U256::from(10)),
+        (U256::from(4), U256::from(1_000)),
+    ];
+
+    assert_eq!(
+        most_liquid_token_ids(&liquidity_added, 3),
+        vec![U256::from(1), U256::from(2), U256::from(4)]
+    );
+    assert_eq!(
+        most_liquid_token_ids(&liquidity_added, 10),
+        vec![U256::from(1), U256::from(2), U256::from(3), U256::from(4)]
+    );
+}
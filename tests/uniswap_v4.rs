@@ -0,0 +1,113 @@
+use ethers::abi::{decode, ParamType, Token};
+use ethers::types::Address;
+use tokencheck_backend::abi::uniswap_v4_quoter::PoolKey;
+use tokencheck_backend::dex::onchain::uniswap_v4::{
+    uniswap_v4_pool_id, UNISWAP_V4_DYNAMIC_FEE_FLAG,
+};
+use tokencheck_backend::token_check::anvil::buy_sell_uniswap_v4::{
+    encode_v4_exact_in_single_swap, SETTLE_ALL_ACTION, SWAP_EXACT_IN_SINGLE_ACTION,
+    TAKE_ALL_ACTION, V4_SWAP_COMMAND,
+};
+use tokencheck_backend::token_check::token_hook_check::{enabled_hook_permissions, TokenHookCheck};
+
+fn hook(low_bits: u16) -> Address {
+    let mut bytes = [0x11_u8; 20];
+    bytes[18..].copy_from_slice(&low_bits.to_be_bytes());
+    Address::from(bytes)
+}
+
+fn pool_key() -> PoolKey {
+    PoolKey {
+        currency_0: Address::zero(),
+        currency_1: Address::repeat_byte(0x22),
+        fee: 3000,
+        tick_spacing: 60,
+        hooks: Address::zero(),
+    }
+}
+
+#[test]
+fn test_hook_permissions_are_decoded_from_the_address() {
+    // beforeSwap (bit 7) and afterSwapReturnsDelta (bit 2), the top two bits are not permissions
+    let hook_address = hook(0xc000 | (1 << 7) | (1 << 2));
+
+    assert_eq!(
+        enabled_hook_permissions(hook_address),
+        vec!["beforeSwap", "afterSwapReturnsDelta"]
+    );
+
+    let hook_check = TokenHookCheck::from_pool(hook_address, UNISWAP_V4_DYNAMIC_FEE_FLAG);
+    assert!(hook_check.can_block_swaps);
+    assert!(hook_check.can_take_swap_deltas);
+    assert!(hook_check.has_dynamic_fee);
+    assert!(!hook_check.can_block_liquidity_removal);
+    assert!(hook_check.can_trap_traders());
+}
+
+#[test]
+fn test_liquidity_only_hook_cannot_trap_traders() {
+    // beforeAddLiquidity (bit 11) and beforeRemoveLiquidity (bit 9)
+    let hook_check = TokenHookCheck::from_pool(hook((1 << 11) | (1 << 9)), 3000);
+
+    assert!(hook_check.can_block_liquidity_removal);
+    assert!(!hook_check.has_dynamic_fee);
+    assert!(!hook_check.can_trap_traders());
+}
+
+#[test]
+fn test_pool_id_depends_on_the_hook() {
+    let mut hooked_pool_key = pool_key();
+    hooked_pool_key.hooks = hook(1 << 7);
+
+    assert_eq!(
+        uniswap_v4_pool_id(&pool_key()),
+        uniswap_v4_pool_id(&pool_key())
+    );
+    assert_ne!(
+        uniswap_v4_pool_id(&pool_key()),
+        uniswap_v4_pool_id(&hooked_pool_key)
+    );
+}
+
+#[test]
+fn test_exact_in_single_swap_encoding() {
+    let (commands, inputs) = encode_v4_exact_in_single_swap(&pool_key(), true, 1_000, 900);
+
+    assert_eq!(commands.to_vec(), vec![V4_SWAP_COMMAND]);
+    assert_eq!(inputs.len(), 1);
+
+    let decoded = decode(
+        &[
+            ParamType::Bytes,
+            ParamType::Array(Box::new(ParamType::Bytes)),
+        ],
+        &inputs[0],
+    )
+    .unwrap();
+    assert_eq!(
+        decoded[0],
+        Token::Bytes(vec![
+            SWAP_EXACT_IN_SINGLE_ACTION,
+            SETTLE_ALL_ACTION,
+            TAKE_ALL_ACTION
+        ])
+    );
+
+    let params = decoded[1].clone().into_array().unwrap();
+    assert_eq!(params.len(), 3);
+    // settle the input currency (native ETH) and take the output currency
+    let settle = decode(
+        &[ParamType::Address, ParamType::Uint(256)],
+        &params[1].clone().into_bytes().unwrap(),
+    )
+    .unwrap();
+    assert_eq!(settle[0], Token::Address(Address::zero()));
+    assert_eq!(settle[1], Token::Uint(1_000.into()));
+    let take = decode(
+        &[ParamType::Address, ParamType::Uint(256)],
+        &params[2].clone().into_bytes().unwrap(),
+    )
+    .unwrap();
+    assert_eq!(take[0], Token::Address(Address::repeat_byte(0x22)));
+    assert_eq!(take[1], Token::Uint(900.into()));
+}